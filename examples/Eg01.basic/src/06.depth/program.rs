@@ -39,10 +39,6 @@ pub struct DepthProcedure {
     desc_storage: GsDescriptorRepository,
     ubo_set     : DescriptorSet,
 
-    depth_attachment: GsDSAttachment,
-    #[allow(dead_code)]
-    image_storage   : GsImageRepository<Device>,
-
     command_pool   : GsCommandPool,
     command_buffers: Vec<GsCommandBuffer>,
 
@@ -84,16 +80,12 @@ impl DepthProcedure {
             DepthProcedure::buffers(&initializer, &vertex_data, &index_data, &ubo_data)
         }?;
 
-        let (depth_attachment, image_storage) = {
-            DepthProcedure::image(&initializer, screen_dimension)
-        }?;
-
         let (ubo_set, desc_storage) = {
             DepthProcedure::ubo(&initializer, &ubo_buffer)
         }?;
 
         let pipeline = {
-            DepthProcedure::pipelines(&initializer, &ubo_set, &depth_attachment)
+            DepthProcedure::pipelines(&initializer, &ubo_set)
         }?;
 
         let present_availables = {
@@ -109,7 +101,6 @@ impl DepthProcedure {
             buffer_storage, vertex_buffer, index_buffer, ubo_buffer,
             desc_storage, ubo_set,
             pipeline,
-            depth_attachment, image_storage,
             command_pool, command_buffers,
             camera,
             present_availables,
@@ -159,21 +150,6 @@ impl DepthProcedure {
         Ok((vertex_buffer, index_buffer, ubo_buffer, buffer_storage))
     }
 
-    fn image(initializer: &AssetInitializer, dimension: vkDim2D) -> GsResult<(GsDSAttachment, GsImageRepository<Device>)> {
-
-        // depth attachment image
-        let mut image_allocator = GsImageAllocator::new(initializer, ImageStorageType::DEVICE);
-
-        let depth_attachment_info = GsDSAttachment::new(dimension, DepthStencilImageFormat::Depth32Bit);
-        let image_index = image_allocator.assign(depth_attachment_info)?;
-
-        let image_distributor = image_allocator.allocate()?;
-        let depth_attachment = image_distributor.acquire(image_index);
-        let image_storage = image_distributor.into_repository();
-
-        Ok((depth_attachment, image_storage))
-    }
-
     fn ubo(initializer: &AssetInitializer, ubo_buffer: &GsUniformBuffer) -> GsResult<(DescriptorSet, GsDescriptorRepository)> {
 
         // descriptor
@@ -191,7 +167,7 @@ impl DepthProcedure {
         Ok((ubo_set, desc_storage))
     }
 
-    fn pipelines(initializer: &AssetInitializer, ubo_set: &DescriptorSet, depth_image: &GsDSAttachment) -> GsResult<GsPipeline<Graphics>> {
+    fn pipelines(initializer: &AssetInitializer, ubo_set: &DescriptorSet) -> GsResult<GsPipeline<Graphics>> {
 
         // shaders
         let vertex_shader = GsShaderCI::from_source(
@@ -212,7 +188,8 @@ impl DepthProcedure {
         let first_subpass = render_pass_builder.new_subpass();
 
         let color_attachment = RenderAttachmentCI::<Present>::new(initializer);
-        let depth_attachment = depth_image.attachment();
+        // the depth image is owned by the render pass, and recreated with the swapchain.
+        let depth_attachment = RenderAttachmentCI::swapchain_depth(DepthStencilImageFormat::Depth32Bit);
 
         render_pass_builder.add_attachment(color_attachment, first_subpass);
         render_pass_builder.add_attachment(depth_attachment, first_subpass);
@@ -302,8 +279,7 @@ impl GraphicsRoutine for DepthProcedure {
 
     fn reload_res(&mut self, initializer: AssetInitializer) -> GsResult<()> {
 
-        self.pipeline = DepthProcedure::pipelines(&initializer, &self.ubo_set, &self.depth_attachment)?;
-
+        // the framebuffers and depth image of the pipeline have been recreated with the swapchain.
         self.present_availables = DepthProcedure::sync_resources(&initializer, &self.pipeline)?;
 
         let (command_pool, command_buffers) = DepthProcedure::commands(&initializer, &self.pipeline, &self.vertex_buffer, &self.index_buffer, &self.ubo_set, self.index_data.len())?;
@@ -33,16 +33,11 @@ pub struct GltfModelViewer<T: ShaderInputDefinition> {
     desc_storage: GsDescriptorRepository,
     ubo_set: DescriptorSet,
 
-    depth_attachment: GsDSAttachment,
-    #[allow(dead_code)]
-    image_storage: GsImageRepository<Device>,
-
     pipeline: GsPipeline<Graphics>,
 
     command_pool   : GsCommandPool,
     command_buffers: Vec<GsCommandBuffer>,
 
-    paths: FilePathConstants,
     camera: GsFlightCamera,
 
     present_availables: Vec<GsSemaphore>,
@@ -78,12 +73,8 @@ impl<T: ShaderInputDefinition> GltfModelViewer<T> {
             Self::ubo(&initializer, &ubo_buffer, &dst_model)
         }?;
 
-        let (depth_attachment, image_storage) = {
-            Self::image(&initializer, screen_dimension)
-        }?;
-
         let pipeline = {
-            Self::pipelines(&initializer, &paths, &ubo_set, &dst_model, &depth_attachment)
+            Self::pipelines(&initializer, &paths, &ubo_set, &dst_model)
         }?;
 
         let present_availables = {
@@ -98,10 +89,9 @@ impl<T: ShaderInputDefinition> GltfModelViewer<T> {
             phantom_type: PhantomData,
             dst_model, model_repository,
             ubo_data, ubo_storage, ubo_buffer, ubo_set, desc_storage,
-            depth_attachment, image_storage,
             pipeline,
             command_pool, command_buffers,
-            paths, camera,
+            camera,
             present_availables,
         };
 
@@ -178,22 +168,7 @@ impl<T: ShaderInputDefinition> GltfModelViewer<T> {
         Ok((ubo_set, desc_storage))
     }
 
-    fn image(initializer: &AssetInitializer, dimension: vkDim2D) -> GsResult<(GsDSAttachment, GsImageRepository<Device>)> {
-
-        // depth attachment image
-        let mut image_allocator = GsImageAllocator::new(initializer, ImageStorageType::DEVICE);
-
-        let depth_attachment_info = GsDSAttachment::new(dimension, DepthStencilImageFormat::Depth32Bit);
-        let image_index = image_allocator.assign(depth_attachment_info)?;
-
-        let image_distributor = image_allocator.allocate()?;
-        let depth_attachment = image_distributor.acquire(image_index);
-        let image_storage = image_distributor.into_repository();
-
-        Ok((depth_attachment, image_storage))
-    }
-
-    fn pipelines(initializer: &AssetInitializer, paths: &FilePathConstants, ubo_set: &DescriptorSet, model: &GsglTFEntity, depth_image: &GsDSAttachment) -> GsResult<GsPipeline<Graphics>> {
+    fn pipelines(initializer: &AssetInitializer, paths: &FilePathConstants, ubo_set: &DescriptorSet, model: &GsglTFEntity) -> GsResult<GsPipeline<Graphics>> {
 
         // shaders
         let vertex_shader = GsShaderCI::from_source(GsPipelineStage::VERTEX, Path::new(paths.vertex_shader), None, "[Vertex Shader]");
@@ -206,7 +181,8 @@ impl<T: ShaderInputDefinition> GltfModelViewer<T> {
         let first_subpass = render_pass_builder.new_subpass();
 
         let color_attachment = RenderAttachmentCI::<Present>::new(initializer);
-        let depth_attachment = depth_image.attachment();
+        // the depth image is owned by the render pass, and recreated with the swapchain.
+        let depth_attachment = RenderAttachmentCI::swapchain_depth(DepthStencilImageFormat::Depth32Bit);
 
         render_pass_builder.add_attachment(color_attachment, first_subpass);
         render_pass_builder.add_attachment(depth_attachment, first_subpass);
@@ -296,8 +272,7 @@ impl<T: ShaderInputDefinition> GraphicsRoutine for GltfModelViewer<T> {
 
     fn reload_res(&mut self, initializer: AssetInitializer) -> GsResult<()> {
 
-        self.pipeline = Self::pipelines(&initializer, &self.paths, &self.ubo_set, &self.dst_model, &self.depth_attachment)?;
-
+        // the framebuffers and depth image of the pipeline have been recreated with the swapchain.
         self.present_availables = Self::sync_resources(&initializer, &self.pipeline)?;
 
         let (command_pool, command_buffers) = Self::commands(&initializer, &self.pipeline, &self.ubo_set, &self.dst_model)?;
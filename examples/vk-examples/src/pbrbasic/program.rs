@@ -37,8 +37,6 @@ pub struct VulkanExample {
     #[allow(dead_code)]
     model_repository: GsBufferRepository<Device>,
 
-    push_ranges : Vec<GsPushConstantRange>,
-
     ubo_matrices: GsUniformBuffer,
     ubo_params  : GsUniformBuffer,
     ubo_storage : GsBufferRepository<Host>,
@@ -49,10 +47,6 @@ pub struct VulkanExample {
     #[allow(dead_code)]
     desc_storage: GsDescriptorRepository,
 
-    depth_attachment: GsDSAttachment,
-    #[allow(dead_code)]
-    image_storage   : GsImageRepository<Device>,
-
     command_pool   : GsCommandPool,
     command_buffers: Vec<GsCommandBuffer>,
 
@@ -107,16 +101,12 @@ impl VulkanExample {
 
         let push_ranges = VulkanExample::push_constants()?;
 
-        let (depth_attachment, image_storage) = {
-            VulkanExample::image(&initializer, screen_dimension)
-        }?;
-
         let (ubo_set, desc_storage) = {
             VulkanExample::ubo(&initializer, &ubo_matrices, &ubo_params)
         }?;
 
         let pipeline = {
-            VulkanExample::pipelines(&initializer, &ubo_set, push_ranges.clone(), &depth_attachment)
+            VulkanExample::pipelines(&initializer, &ubo_set, push_ranges)
         }?;
 
         let present_availables = {
@@ -130,10 +120,9 @@ impl VulkanExample {
         let procedure = VulkanExample {
             ubo_data, lights,
             model_entity, model_repository,
-            ubo_matrices, ubo_params, push_ranges, ubo_storage,
+            ubo_matrices, ubo_params, ubo_storage,
             desc_storage, ubo_set,
             pipeline,
-            depth_attachment, image_storage,
             command_pool, command_buffers,
             camera, view_port, scissor,
             present_availables,
@@ -242,22 +231,7 @@ impl VulkanExample {
         Ok((ubo_set, desc_storage))
     }
 
-    fn image(initializer: &AssetInitializer, dimension: vkDim2D) -> GsResult<(GsDSAttachment, GsImageRepository<Device>)> {
-
-        // depth attachment image
-        let mut image_allocator = GsImageAllocator::new(initializer, ImageStorageType::DEVICE);
-
-        let depth_attachment_info = GsDSAttachment::new(dimension, DepthStencilImageFormat::Depth32Bit);
-        let image_index = image_allocator.assign(depth_attachment_info)?;
-
-        let image_distributor = image_allocator.allocate()?;
-        let depth_attachment = image_distributor.acquire(image_index);
-        let image_storage = image_distributor.into_repository();
-
-        Ok((depth_attachment, image_storage))
-    }
-
-    fn pipelines(initializer: &AssetInitializer, ubo_set: &DescriptorSet, ranges: Vec<GsPushConstantRange>, depth_image: &GsDSAttachment) -> GsResult<GsPipeline<Graphics>> {
+    fn pipelines(initializer: &AssetInitializer, ubo_set: &DescriptorSet, ranges: Vec<GsPushConstantRange>) -> GsResult<GsPipeline<Graphics>> {
 
         // shaders
         let vertex_shader = GsShaderCI::from_source(
@@ -280,7 +254,8 @@ impl VulkanExample {
         let color_attachment = RenderAttachmentCI::<Present>::new(initializer)
             .op(vk::AttachmentLoadOp::CLEAR, vk::AttachmentStoreOp::STORE)
             .clear_value(DEFAULT_CLEAR_COLOR.clone());
-        let depth_attachment = depth_image.attachment()
+        // the depth image is owned by the render pass, and recreated with the swapchain.
+        let depth_attachment = RenderAttachmentCI::swapchain_depth(DepthStencilImageFormat::Depth32Bit)
             .op(vk::AttachmentLoadOp::CLEAR, vk::AttachmentStoreOp::DONT_CARE);
 
         render_pass_builder.add_attachment(color_attachment, first_subpass);
@@ -418,7 +393,10 @@ impl GraphicsRoutine for VulkanExample {
 
     fn reload_res(&mut self, initializer: AssetInitializer) -> GsResult<()> {
 
-        self.pipeline = VulkanExample::pipelines(&initializer, &self.ubo_set, self.push_ranges.clone(), &self.depth_attachment)?;
+        // the framebuffers and depth image of the pipeline have been recreated with the swapchain.
+        let screen_dimension = initializer.screen_dimension();
+        self.view_port = CmdViewportInfo::from(screen_dimension);
+        self.scissor = CmdScissorInfo::from(screen_dimension);
 
         self.present_availables = VulkanExample::sync_resources(&initializer, &self.pipeline)?;
 
@@ -43,10 +43,9 @@ pub struct VulkanExample {
     desc_storage: GsDescriptorRepository,
 
     #[allow(dead_code)]
-    sample_image: GsCombinedImgSampler,
-    depth_attachment: GsDSAttachment,
+    sample_image : GsCombinedImgSampler,
     #[allow(dead_code)]
-    image_storage   : GsImageRepository<Device>,
+    image_storage: GsImageRepository<Device>,
 
     command_pool   : GsCommandPool,
     command_buffers: Vec<GsCommandBuffer>,
@@ -90,8 +89,8 @@ impl VulkanExample {
             VulkanExample::buffers(&initializer, &ubo_data)
         }?;
 
-        let (depth_attachment, sample_image, image_storage) = {
-            VulkanExample::image(&initializer, screen_dimension)
+        let (sample_image, image_storage) = {
+            VulkanExample::image(&initializer)
         }?;
 
         let (ubo_set, desc_storage) = {
@@ -99,7 +98,7 @@ impl VulkanExample {
         }?;
 
         let pipeline = {
-            VulkanExample::pipelines(&initializer, &ubo_set, &depth_attachment)
+            VulkanExample::pipelines(&initializer, &ubo_set)
         }?;
 
         let present_availables = {
@@ -116,7 +115,7 @@ impl VulkanExample {
             ubo_buffer, ubo_storage,
             desc_storage, ubo_set,
             pipeline,
-            sample_image, depth_attachment, image_storage,
+            sample_image, image_storage,
             command_pool, command_buffers,
             camera, view_port, scissor,
             present_availables,
@@ -184,14 +183,10 @@ impl VulkanExample {
         Ok((vertex_buffer, index_buffer, vertex_storage, ubo_buffer, ubo_storage))
     }
 
-    fn image(initializer: &AssetInitializer, dimension: vkDim2D) -> GsResult<(GsDSAttachment, GsCombinedImgSampler, GsImageRepository<Device>)> {
+    fn image(initializer: &AssetInitializer) -> GsResult<(GsCombinedImgSampler, GsImageRepository<Device>)> {
 
-        // depth attachment image.
         let mut image_allocator = GsImageAllocator::new(initializer, ImageStorageType::DEVICE);
 
-        let depth_attachment_info = GsDSAttachment::new(dimension, DepthStencilImageFormat::Depth32Bit);
-        let depth_image_index = image_allocator.assign(depth_attachment_info)?;
-
         // combined image sampler.
         let image_storage = ImageLoader::new(initializer).load_2d(Path::new(TEXTURE_PATH), GsImageFormat::default())?;
 
@@ -211,12 +206,11 @@ impl VulkanExample {
 
         let image_distributor = image_allocator.allocate()?;
 
-        let depth_attachment = image_distributor.acquire(depth_image_index);
         let sample_image = image_distributor.acquire(sample_image_index);
 
         let image_storage = image_distributor.into_repository();
 
-        Ok((depth_attachment, sample_image, image_storage))
+        Ok((sample_image, image_storage))
     }
 
     fn ubo(initializer: &AssetInitializer, ubo_buffer: &GsUniformBuffer, sample_image: &GsCombinedImgSampler) -> GsResult<(DescriptorSet, GsDescriptorRepository)> {
@@ -237,7 +231,7 @@ impl VulkanExample {
         Ok((ubo_set, desc_storage))
     }
 
-    fn pipelines(initializer: &AssetInitializer, ubo_set: &DescriptorSet, depth_image: &GsDSAttachment) -> GsResult<GsPipeline<Graphics>> {
+    fn pipelines(initializer: &AssetInitializer, ubo_set: &DescriptorSet) -> GsResult<GsPipeline<Graphics>> {
 
         // shaders
         let vertex_shader = GsShaderCI::from_source(GsPipelineStage::VERTEX, Path::new(VERTEX_SHADER_SOURCE_PATH), None, "[Vertex Shader]");
@@ -252,7 +246,8 @@ impl VulkanExample {
         let color_attachment = RenderAttachmentCI::<Present>::new(initializer)
             .op(vk::AttachmentLoadOp::CLEAR, vk::AttachmentStoreOp::STORE)
             .clear_value(DEFAULT_CLEAR_COLOR);
-        let depth_attachment = depth_image.attachment()
+        // the depth image is owned by the render pass, and recreated with the swapchain.
+        let depth_attachment = RenderAttachmentCI::swapchain_depth(DepthStencilImageFormat::Depth32Bit)
             .op(vk::AttachmentLoadOp::CLEAR, vk::AttachmentStoreOp::DONT_CARE);
 
         render_pass_builder.add_attachment(color_attachment, first_subpass);
@@ -346,7 +341,10 @@ impl GraphicsRoutine for VulkanExample {
 
     fn reload_res(&mut self, initializer: AssetInitializer) -> GsResult<()> {
 
-        self.pipeline = VulkanExample::pipelines(&initializer, &self.ubo_set, &self.depth_attachment)?;
+        // the framebuffers and depth image of the pipeline have been recreated with the swapchain.
+        let screen_dimension = initializer.screen_dimension();
+        self.view_port = CmdViewportInfo::from(screen_dimension);
+        self.scissor = CmdScissorInfo::from(screen_dimension);
 
         self.present_availables = VulkanExample::sync_resources(&initializer, &self.pipeline)?;
 
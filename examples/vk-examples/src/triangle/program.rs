@@ -41,10 +41,6 @@ pub struct VulkanExample {
     #[allow(dead_code)]
     desc_storage: GsDescriptorRepository,
 
-    depth_attachment: GsDSAttachment,
-    #[allow(dead_code)]
-    image_storage   : GsImageRepository<Device>,
-
     command_pool   : GsCommandPool,
     command_buffers: Vec<GsCommandBuffer>,
 
@@ -82,16 +78,12 @@ impl VulkanExample {
             VulkanExample::buffers(&initializer)
         }?;
 
-        let (depth_attachment, image_storage) = {
-            VulkanExample::image(&initializer, screen_dimension)
-        }?;
-
         let (ubo_set, desc_storage) = {
             VulkanExample::ubo(&initializer, &ubo_buffer)
         }?;
 
         let pipeline =  {
-            VulkanExample::pipelines(&initializer, &ubo_set, &depth_attachment)
+            VulkanExample::pipelines(&initializer, &ubo_set)
         }?;
 
         let present_availables = {
@@ -108,7 +100,6 @@ impl VulkanExample {
             ubo_buffer, ubo_storage,
             desc_storage, ubo_set,
             pipeline,
-            depth_attachment, image_storage,
             command_pool, command_buffers,
             camera, view_port, scissor,
             present_availables,
@@ -162,21 +153,6 @@ impl VulkanExample {
         Ok((vertex_buffer, index_buffer, vertex_storage, ubo_buffer, ubo_storage))
     }
 
-    fn image(initializer: &AssetInitializer, dimension: vkDim2D) -> GsResult<(GsDSAttachment, GsImageRepository<Device>)> {
-
-        // depth attachment image
-        let mut image_allocator = GsImageAllocator::new(initializer, ImageStorageType::DEVICE);
-
-        let depth_attachment_info = GsDSAttachment::new(dimension, DepthStencilImageFormat::Depth32Bit);
-        let image_index = image_allocator.assign(depth_attachment_info)?;
-
-        let image_distributor = image_allocator.allocate()?;
-        let depth_attachment = image_distributor.acquire(image_index);
-        let image_storage = image_distributor.into_repository();
-
-        Ok((depth_attachment, image_storage))
-    }
-
     fn ubo(initializer: &AssetInitializer, ubo_buffer: &GsUniformBuffer) -> GsResult<(DescriptorSet, GsDescriptorRepository)> {
 
         // descriptor
@@ -194,7 +170,7 @@ impl VulkanExample {
         Ok((ubo_set, desc_storage))
     }
 
-    fn pipelines(initializer: &AssetInitializer, ubo_set: &DescriptorSet, depth_image: &GsDSAttachment) -> GsResult<GsPipeline<Graphics>> {
+    fn pipelines(initializer: &AssetInitializer, ubo_set: &DescriptorSet) -> GsResult<GsPipeline<Graphics>> {
 
         // shaders
         let vertex_shader = GsShaderCI::from_source(GsPipelineStage::VERTEX, Path::new(VERTEX_SHADER_SOURCE_PATH), None, "[Vertex Shader]");
@@ -209,7 +185,8 @@ impl VulkanExample {
         let color_attachment = RenderAttachmentCI::<Present>::new(initializer)
             .op(vk::AttachmentLoadOp::CLEAR, vk::AttachmentStoreOp::STORE)
             .clear_value(vk::ClearValue { color: vk::ClearColorValue { float32: [0.0, 0.0, 0.2, 1.0] } });
-        let depth_attachment = depth_image.attachment()
+        // the depth image is owned by the render pass, and recreated with the swapchain.
+        let depth_attachment = RenderAttachmentCI::swapchain_depth(DepthStencilImageFormat::Depth32Bit)
             .op(vk::AttachmentLoadOp::CLEAR, vk::AttachmentStoreOp::DONT_CARE);
 
         render_pass_builder.add_attachment(color_attachment, first_subpass);
@@ -303,7 +280,10 @@ impl GraphicsRoutine for VulkanExample {
 
     fn reload_res(&mut self, initializer: AssetInitializer) -> GsResult<()> {
 
-        self.pipeline = VulkanExample::pipelines(&initializer, &self.ubo_set, &self.depth_attachment)?;
+        // the framebuffers and depth image of the pipeline have been recreated with the swapchain.
+        let screen_dimension = initializer.screen_dimension();
+        self.view_port = CmdViewportInfo::from(screen_dimension);
+        self.scissor = CmdScissorInfo::from(screen_dimension);
 
         self.present_availables = VulkanExample::sync_resources(&initializer, &self.pipeline)?;
 
@@ -1,7 +1,7 @@
 
 use crate::core::GsDevice;
 
-use crate::buffer::{ GsBuffer, BufferBlock };
+use crate::buffer::{ GsBuffer, GsBufferView, BufferBlock };
 use crate::buffer::allocator::memory::BufferAllocateInfos;
 use crate::buffer::allocator::types::BufferMemoryTypeAbs;
 use crate::buffer::traits::BufferInstance;
@@ -12,8 +12,10 @@ use crate::utils::phantom::Host;
 use crate::buffer::instance::{
     GsVertexBuffer, IVertex,
     GsIndexBuffer, IIndices,
+    GsInstanceBuffer, IInstance,
     GsUniformBuffer, IUniform,
     GsImgsrcBuffer, IImgSrc,
+    GsTexelBuffer, ITexel,
 };
 
 use crate::utils::allot::{ GsAssignIndex, GsDistributeApi, GsDistIntoRepository };
@@ -31,6 +33,7 @@ pub struct GsBufferDistributor<M>
     memory  : GsBufferMemory,
 
     buffers : Vec<GsBuffer>,
+    views   : Vec<Option<GsBufferView>>,
     spaces  : Vec<vkbytes>,
     offsets : Vec<vkbytes>,
 
@@ -61,6 +64,18 @@ impl<M> GsDistributeApi<IIndices, GsIndexBuffer, GsBufferRepository<M>> for GsBu
     }
 }
 
+impl<M> GsDistributeApi<IInstance, GsInstanceBuffer, GsBufferRepository<M>> for GsBufferDistributor<M>
+    where
+        M: BufferMemoryTypeAbs {
+
+    fn acquire(&self, index: GsAssignIndex<IInstance>) -> GsInstanceBuffer {
+
+        let repo_index = index.assign_index;
+        let buffer_block = self.gen_buffer_block(index.assign_index);
+        GsInstanceBuffer::build(buffer_block, index.take_info(), repo_index)
+    }
+}
+
 impl GsDistributeApi<IUniform, GsUniformBuffer, GsBufferRepository<Host>> for GsBufferDistributor<Host> {
 
     fn acquire(&self, index: GsAssignIndex<IUniform>) -> GsUniformBuffer {
@@ -71,6 +86,23 @@ impl GsDistributeApi<IUniform, GsUniformBuffer, GsBufferRepository<Host>> for Gs
     }
 }
 
+impl<M> GsDistributeApi<ITexel, GsTexelBuffer, GsBufferRepository<M>> for GsBufferDistributor<M>
+    where
+        M: BufferMemoryTypeAbs {
+
+    fn acquire(&self, index: GsAssignIndex<ITexel>) -> GsTexelBuffer {
+
+        let repo_index = index.assign_index;
+        let buffer_block = self.gen_buffer_block(index.assign_index);
+        let mut texel_buffer = GsTexelBuffer::build(buffer_block, index.take_info(), repo_index);
+
+        if let Some(ref view) = self.views[repo_index] {
+            texel_buffer.set_view(view.handle);
+        }
+        texel_buffer
+    }
+}
+
 impl<M> GsDistributeApi<IImgSrc, GsImgsrcBuffer, GsBufferRepository<M>> for GsBufferDistributor<M>
     where
         M: BufferMemoryTypeAbs {
@@ -89,7 +121,7 @@ impl<M> GsDistIntoRepository<GsBufferRepository<M>> for GsBufferDistributor<M>
 
     fn into_repository(self) -> GsBufferRepository<M> {
 
-        GsBufferRepository::store(self.phantom_type, self.device, self.buffers, self.memory, self.allot_infos)
+        GsBufferRepository::store(self.phantom_type, self.device, self.buffers, self.views, self.memory, self.allot_infos)
     }
 }
 
@@ -97,13 +129,13 @@ impl<M> GsBufferDistributor<M>
     where
         M: BufferMemoryTypeAbs {
 
-    pub(super) fn new(phantom_type: PhantomData<M>, device: GsDevice, memory: GsBufferMemory, buffers: Vec<GsBuffer>, spaces: Vec<vkbytes>, allot_infos: BufferAllocateInfos) -> GsBufferDistributor<M> {
+    pub(super) fn new(phantom_type: PhantomData<M>, device: GsDevice, memory: GsBufferMemory, buffers: Vec<GsBuffer>, views: Vec<Option<GsBufferView>>, spaces: Vec<vkbytes>, allot_infos: BufferAllocateInfos) -> GsBufferDistributor<M> {
 
         use crate::utils::memory::spaces_to_offsets;
         let offsets = spaces_to_offsets(&spaces);
 
         GsBufferDistributor {
-            phantom_type, device, memory, buffers, spaces, offsets, allot_infos,
+            phantom_type, device, memory, buffers, views, spaces, offsets, allot_infos,
         }
     }
 
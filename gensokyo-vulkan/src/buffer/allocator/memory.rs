@@ -89,6 +89,12 @@ impl GsMemoryType {
             | GsMemoryType::StagingMemory => {
                 Box::new(GsStagingMemory::allocate(device, size, filter)?) as GsBufferMemory
             },
+            | GsMemoryType::TransientMemory => {
+                unreachable!("Transient memory is only used by image attachments.")
+            },
+            | GsMemoryType::ReadbackMemory => {
+                unreachable!("Readback memory is only used by buffer reader.")
+            },
         };
 
         Ok(memory)
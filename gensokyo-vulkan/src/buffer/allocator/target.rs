@@ -2,6 +2,7 @@
 use crate::core::GsDevice;
 
 use crate::buffer::target::GsBuffer;
+use crate::buffer::view::BufferViewCI;
 use crate::buffer::instance::types::BufferCIApi;
 use crate::memory::{ MemoryFilter, MemoryDstEntity };
 use crate::error::{ VkResult, VkError };
@@ -27,6 +28,8 @@ pub struct GsBufferAllocator<M>
     buffers : Vec<GsBuffer>,
     /// The size of each buffer occupy.
     spaces  : Vec<vkbytes>,
+    /// The buffer views to create for each buffer after memory binding.
+    view_cis: Vec<Option<BufferViewCI>>,
 
     allot_infos: BufferAllocateInfos,
     memory_filter: MemoryFilter,
@@ -47,12 +50,13 @@ impl<M, I> GsAllocatorApi<I, GsBufferDistributor<M>> for GsBufferAllocator<M>
         }
 
         let mut info = info; // make it mutable.
-        info.check_limits(&self.device);
+        info.check_limits(&self.device)?;
 
         let buffer_ci = GsBuffer::new(info.estimate_size(), I::VK_FLAG);
         let buffer = buffer_ci.build(&self.device, self.storage_type)?;
         self.memory_filter.filter(&buffer)?;
 
+        let view_ci = info.view_ci();
         let dst_index = GsAssignIndex {
             convey_info: info.into_index(),
             assign_index: self.buffers.len(),
@@ -62,6 +66,7 @@ impl<M, I> GsAllocatorApi<I, GsBufferDistributor<M>> for GsBufferAllocator<M>
         let alignment_space = buffer.aligned_size();
 
         self.spaces.push(alignment_space);
+        self.view_cis.push(view_ci);
         self.buffers.push(buffer);
         self.allot_infos.push(alignment_space, buffer_ci);
 
@@ -97,11 +102,21 @@ impl<M> GsAllotIntoDistributor<GsBufferDistributor<M>> for GsBufferAllocator<M>
 
         memory_allocator.memory_map_if_need(&self.device)?;
 
+        // create buffer views after buffers have been bound to memory.
+        let mut views = Vec::with_capacity(self.view_cis.len());
+        for (view_ci, buffer) in self.view_cis.iter().zip(buffers_to_distribute.iter()) {
+            let view = match view_ci {
+                | Some(view_ci) => Some(view_ci.build(&self.device, buffer)?),
+                | None => None,
+            };
+            views.push(view);
+        }
+
         let (memory, allot_infos) = memory_allocator.take();
 
         let distributor = GsBufferDistributor::new(
             self.phantom_type,
-            self.device, memory, buffers_to_distribute, self.spaces, allot_infos
+            self.device, memory, buffers_to_distribute, views, self.spaces, allot_infos
         );
 
         Ok(distributor)
@@ -109,9 +124,9 @@ impl<M> GsAllotIntoDistributor<GsBufferDistributor<M>> for GsBufferAllocator<M>
 
     fn reset(&mut self) {
 
-        self.buffers.iter().for_each(|b| b.discard(&self.device));
         self.buffers.clear();
         self.spaces.clear();
+        self.view_cis.clear();
         self.memory_filter.reset();
     }
 }
@@ -128,8 +143,9 @@ impl<M> GsBufferAllocator<M>
 
             device  : device.clone(),
 
-            buffers: vec![],
-            spaces : vec![],
+            buffers : vec![],
+            spaces  : vec![],
+            view_cis: vec![],
 
             allot_infos: BufferAllocateInfos::new(),
             memory_filter: MemoryFilter::new(device, storage_type.memory_type()),
@@ -36,7 +36,8 @@ impl BufferMemoryTypeAbs for Host {
     const MEMORY_TYPE: GsMemoryType = GsMemoryType::HostMemory;
 
     fn complement_usage(&self, origin: vk::BufferUsageFlags) -> vk::BufferUsageFlags {
-        origin
+        // TRANSFER_SRC is required to read the data back by GsBufferReader.
+        origin | vk::BufferUsageFlags::TRANSFER_SRC
     }
 
     fn map_memory_if_need(&self, device: &GsDevice, mappable_memory: &mut MemoryMappable) -> VkResult<()> {
@@ -48,7 +49,7 @@ impl BufferMemoryTypeAbs for Cached {
     const MEMORY_TYPE: GsMemoryType = GsMemoryType::CachedMemory;
 
     fn complement_usage(&self, origin: vk::BufferUsageFlags) -> vk::BufferUsageFlags {
-        origin | vk::BufferUsageFlags::TRANSFER_DST
+        origin | vk::BufferUsageFlags::TRANSFER_DST | vk::BufferUsageFlags::TRANSFER_SRC
     }
 }
 
@@ -56,7 +57,7 @@ impl BufferMemoryTypeAbs for Device {
     const MEMORY_TYPE: GsMemoryType = GsMemoryType::DeviceMemory;
 
     fn complement_usage(&self, origin: vk::BufferUsageFlags) -> vk::BufferUsageFlags {
-        origin | vk::BufferUsageFlags::TRANSFER_DST
+        origin | vk::BufferUsageFlags::TRANSFER_DST | vk::BufferUsageFlags::TRANSFER_SRC
     }
 }
 
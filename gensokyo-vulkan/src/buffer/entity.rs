@@ -46,6 +46,7 @@ impl BufferCopiable for BufferBlock {
 
         BufferFullCopyInfo {
             handle: self.handle,
+            offset: 0,
             size  : self.size,
         }
     }
@@ -1,6 +1,9 @@
 
 use ash::vk;
 
+use crate::core::GsDevice;
+use crate::core::physical::DeviceExtensionType;
+
 use crate::buffer::entity::BufferBlock;
 use crate::buffer::instance::types::BufferCIApi;
 use crate::buffer::traits::{ BufferInstance, BufferCopiable, BufferFullCopyInfo };
@@ -8,21 +11,70 @@ use crate::buffer::traits::{ BufferInstance, BufferCopiable, BufferFullCopyInfo
 use crate::memory::transfer::MemoryDataDelegate;
 use crate::memory::MemoryWritePtr;
 
-use crate::error::VkResult;
+use crate::error::{ VkResult, VkError };
 use crate::types::{ vkuint, vkbytes };
 
 use std::mem;
 
+/// The integer type of each element in index buffer.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GsIndexType {
+    /// Require `DeviceExtensionType::IndexTypeUint8` to be enabled.
+    Uint8,
+    Uint16,
+    Uint32,
+}
+
+impl GsIndexType {
+
+    /// Return the smallest index type that can store `max_index`, the maximum index value in index buffer.
+    ///
+    /// The maximum value of each type is reserved for primitive restart, so it's never used as a vertex index.
+    /// `GsIndexType::Uint8` is only returned if `is_uint8_enable` is true, which means `VK_EXT_index_type_uint8` is enabled.
+    pub fn fit(max_index: vkuint, is_uint8_enable: bool) -> GsIndexType {
+
+        if is_uint8_enable && max_index < (::std::u8::MAX as vkuint) {
+            GsIndexType::Uint8
+        } else if max_index < (::std::u16::MAX as vkuint) {
+            GsIndexType::Uint16
+        } else {
+            GsIndexType::Uint32
+        }
+    }
+
+    /// The size in bytes of one index element.
+    pub fn size(&self) -> vkbytes {
+        match self {
+            | GsIndexType::Uint8  => mem::size_of::<u8>()  as vkbytes,
+            | GsIndexType::Uint16 => mem::size_of::<u16>() as vkbytes,
+            | GsIndexType::Uint32 => mem::size_of::<u32>() as vkbytes,
+        }
+    }
+}
+
+impl From<GsIndexType> for vk::IndexType {
+
+    fn from(typ: GsIndexType) -> vk::IndexType {
+        match typ {
+            // VK_INDEX_TYPE_UINT8_EXT is not provided by ash yet.
+            | GsIndexType::Uint8  => vk::IndexType::from_raw(1_000_265_000),
+            | GsIndexType::Uint16 => vk::IndexType::UINT16,
+            | GsIndexType::Uint32 => vk::IndexType::UINT32,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IndicesBufferCI {
 
     indices_count: vkuint,
-    indices_type: vk::IndexType,
+    indices_type: GsIndexType,
 }
 
 impl IndicesBufferCI {
 
-    pub fn set_indices_type(&mut self, typ: vk::IndexType) {
+    /// Set the integer type of index data. Default is `GsIndexType::Uint32`.
+    pub fn set_indices_type(&mut self, typ: GsIndexType) {
         self.indices_type = typ;
     }
 }
@@ -33,14 +85,7 @@ impl BufferCIApi for IndicesBufferCI {
     const VK_FLAG: vk::BufferUsageFlags = vk::BufferUsageFlags::INDEX_BUFFER;
 
     fn estimate_size(&self) -> vkbytes {
-
-        let indices_type_size = match self.indices_type {
-            | vk::IndexType::UINT16 => mem::size_of::<u16>() as vkbytes,
-            | vk::IndexType::UINT32 => mem::size_of::<u32>() as vkbytes,
-            | _ => unreachable!(),
-        };
-
-        indices_type_size * (self.indices_count as vkbytes)
+        self.indices_type.size() * (self.indices_count as vkbytes)
     }
 
     fn into_index(self) -> IIndices {
@@ -50,18 +95,27 @@ impl BufferCIApi for IndicesBufferCI {
             indices_count: self.indices_count,
         }
     }
+
+    fn check_limits(&mut self, device: &GsDevice) -> VkResult<()> {
+
+        if self.indices_type == GsIndexType::Uint8 && device.phys.extensions.is_enable(DeviceExtensionType::IndexTypeUint8) == false {
+            return Err(VkError::device("VK_EXT_index_type_uint8 must be enabled to use 8-bit index buffer."))
+        }
+
+        Ok(())
+    }
 }
 
 pub struct IIndices {
 
-    indices_type: vk::IndexType,
+    indices_type: GsIndexType,
     indices_count: vkuint,
 }
 
 pub struct GsIndexBuffer {
 
     indices_count: vkuint,
-    indices_type: vk::IndexType,
+    indices_type: GsIndexType,
 
     block: BufferBlock,
     repository_index: usize,
@@ -97,12 +151,16 @@ impl GsIndexBuffer {
 
         IndicesBufferCI {
             indices_count: indices_count as vkuint,
-            indices_type: vk::IndexType::UINT32,
+            indices_type: GsIndexType::Uint32,
         }
     }
 
     pub(crate) fn render_info(&self) -> (vk::Buffer, vk::IndexType) {
-        (self.block.handle, self.indices_type)
+        (self.block.handle, self.indices_type.into())
+    }
+
+    pub fn indices_type(&self) -> GsIndexType {
+        self.indices_type
     }
 
     pub fn total_count(&self) -> vkuint {
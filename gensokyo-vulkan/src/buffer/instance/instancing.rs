@@ -0,0 +1,135 @@
+
+use ash::vk;
+
+use crate::buffer::entity::BufferBlock;
+use crate::buffer::traits::{ BufferInstance, BufferCopiable, BufferFullCopyInfo, VertexBindable };
+use crate::buffer::instance::types::BufferCIApi;
+
+use crate::memory::transfer::MemoryDataDelegate;
+use crate::memory::MemoryWritePtr;
+
+use crate::error::VkResult;
+use crate::types::vkbytes;
+
+#[derive(Debug, Clone)]
+pub struct InstanceBufferCI {
+
+    instance_size: vkbytes,
+    instance_count: usize,
+    /// The number of copies of the instance data, usually equal to the count of frames in flight.
+    frame_count: usize,
+}
+
+impl InstanceBufferCI {
+
+    /// Keep `count` copies of the instance data in the buffer, so that the data of one frame can be updated while other frames are still in use by GPU.
+    ///
+    /// Default is 1.
+    pub fn set_frame_count(&mut self, count: usize) {
+        debug_assert!(count > 0, "The frame count of instance buffer must be greater than 0.");
+        self.frame_count = count;
+    }
+}
+
+impl BufferCIApi for InstanceBufferCI {
+    type IConveyor = IInstance;
+
+    const VK_FLAG: vk::BufferUsageFlags = vk::BufferUsageFlags::VERTEX_BUFFER;
+
+    fn estimate_size(&self) -> vkbytes {
+        self.frame_size() * (self.frame_count as vkbytes)
+    }
+
+    fn into_index(self) -> IInstance {
+
+        IInstance {
+            frame_size    : self.frame_size(),
+            frame_count   : self.frame_count,
+            instance_count: self.instance_count,
+        }
+    }
+}
+
+impl InstanceBufferCI {
+
+    fn frame_size(&self) -> vkbytes {
+        (self.instance_count as vkbytes) * self.instance_size
+    }
+}
+
+pub struct IInstance {
+
+    frame_size: vkbytes,
+    frame_count: usize,
+    instance_count: usize,
+}
+
+#[derive(Default)]
+pub struct GsInstanceBuffer {
+
+    frame_size: vkbytes,
+    frame_count: usize,
+    instance_count: usize,
+
+    block: BufferBlock,
+    repository_index: usize,
+}
+
+impl BufferInstance for GsInstanceBuffer {
+    type InfoType = IInstance;
+
+    fn build(block: BufferBlock, info: Self::InfoType, repository_index: usize) -> Self {
+
+        GsInstanceBuffer {
+            frame_size    : info.frame_size,
+            frame_count   : info.frame_count,
+            instance_count: info.instance_count,
+            block, repository_index,
+        }
+    }
+
+    fn acquire_write_ptr(&self, agency: &mut Box<dyn MemoryDataDelegate>) -> VkResult<MemoryWritePtr> {
+        agency.acquire_write_ptr(&self.block, self.repository_index)
+    }
+}
+
+impl BufferCopiable for GsInstanceBuffer {
+
+    fn full_copy(&self) -> BufferFullCopyInfo {
+        self.block.full_copy()
+    }
+}
+
+impl VertexBindable for GsInstanceBuffer {
+
+    fn binding_handle(&self) -> vk::Buffer {
+        self.block.handle
+    }
+}
+
+impl GsInstanceBuffer {
+
+    /// `instance_size` is the size of the data of each instance.
+    ///
+    /// `instance_count` is the maximum count of instances to draw in one frame.
+    pub fn new(instance_size: vkbytes, instance_count: usize) -> InstanceBufferCI {
+        InstanceBufferCI { instance_size, instance_count, frame_count: 1 }
+    }
+
+    /// Return the offset in bytes of the instance data for `frame_index`.
+    ///
+    /// Use this value both for `GsBufferDataUpdater::update_range()` and `GsCmdGraphicsApi::bind_vertex_buffers_offset()`.
+    pub fn frame_offset(&self, frame_index: usize) -> vkbytes {
+
+        debug_assert!(frame_index < self.frame_count, "The frame index of instance buffer is out of range.");
+        self.frame_size * (frame_index as vkbytes)
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    pub fn instance_count(&self) -> usize {
+        self.instance_count
+    }
+}
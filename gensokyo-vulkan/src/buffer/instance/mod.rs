@@ -1,6 +1,8 @@
 
 pub use self::imgsrc::{ GsImgsrcBuffer, IImgSrc, ImgSrcBufferCI };
-pub use self::index::{ GsIndexBuffer, IIndices, IndicesBufferCI };
+pub use self::instancing::{ GsInstanceBuffer, IInstance, InstanceBufferCI };
+pub use self::index::{ GsIndexBuffer, IIndices, IndicesBufferCI, GsIndexType };
+pub use self::texel::{ GsTexelBuffer, ITexel, TexelBufferCI, TexelBufferUsage, UniformTexel, StorageTexel };
 pub use self::uniform::{ GsUniformBuffer, IUniform, UniformBufferCI };
 pub use self::vertex::{ GsVertexBuffer, IVertex, VertexBufferCI };
 
@@ -8,5 +10,7 @@ pub mod types;
 
 mod imgsrc;
 mod index;
+mod instancing;
+mod texel;
 mod uniform;
 mod vertex;
@@ -0,0 +1,198 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+use crate::buffer::entity::BufferBlock;
+use crate::buffer::view::{ GsBufferView, BufferViewCI };
+use crate::buffer::instance::types::BufferCIApi;
+use crate::buffer::traits::{ BufferInstance, BufferCopiable, BufferFullCopyInfo };
+
+use crate::descriptor::binding::DescriptorMeta;
+use crate::descriptor::binding::{ DescriptorBindingBufInfo, DescriptorBindingBufTgt };
+use crate::descriptor::{ GsDescriptorType, BufferDescriptorType };
+
+use crate::memory::transfer::MemoryDataDelegate;
+use crate::memory::MemoryWritePtr;
+
+use crate::types::format::Format;
+use crate::types::{ vkuint, vkbytes };
+use crate::error::{ VkResult, VkError };
+
+use std::marker::PhantomData;
+
+/// The way a texel buffer is accessed in shader.
+pub trait TexelBufferUsage {
+
+    const VK_FLAG: vk::BufferUsageFlags;
+    const DESCRIPTOR_TYPE: BufferDescriptorType;
+    /// The format feature that the buffer format must support.
+    const FORMAT_FEATURE: vk::FormatFeatureFlags;
+}
+
+/// Read-only texel buffer(`samplerBuffer` in GLSL).
+pub struct UniformTexel;
+/// Read-write texel buffer(`imageBuffer` in GLSL).
+pub struct StorageTexel;
+
+impl TexelBufferUsage for UniformTexel {
+
+    const VK_FLAG: vk::BufferUsageFlags = vk::BufferUsageFlags::UNIFORM_TEXEL_BUFFER;
+    const DESCRIPTOR_TYPE: BufferDescriptorType = BufferDescriptorType::TexelUniformBuffer;
+    const FORMAT_FEATURE: vk::FormatFeatureFlags = vk::FormatFeatureFlags::UNIFORM_TEXEL_BUFFER;
+}
+
+impl TexelBufferUsage for StorageTexel {
+
+    const VK_FLAG: vk::BufferUsageFlags = vk::BufferUsageFlags::STORAGE_TEXEL_BUFFER;
+    const DESCRIPTOR_TYPE: BufferDescriptorType = BufferDescriptorType::TexelStorageBuffer;
+    const FORMAT_FEATURE: vk::FormatFeatureFlags = vk::FormatFeatureFlags::STORAGE_TEXEL_BUFFER;
+}
+
+pub struct TexelBufferCI<T>
+    where
+        T: TexelBufferUsage {
+
+    phantom_type: PhantomData<T>,
+
+    descriptor: DescriptorMeta,
+    view: BufferViewCI,
+    /// the total size of the texel data.
+    size: vkbytes,
+}
+
+impl GsTexelBuffer {
+
+    /// Prepare to create a Uniform Texel Buffer.
+    ///
+    /// `format` must be included in `PhysicalFormatsConfig::query_formats` so that its support can be checked.
+    ///
+    /// `size` is the total size of the texel data in bytes.
+    pub fn uniform(binding: vkuint, format: Format, size: vkbytes) -> TexelBufferCI<UniformTexel> {
+        TexelBufferCI::new(binding, format, size)
+    }
+
+    /// Prepare to create a Storage Texel Buffer.
+    ///
+    /// `size` is the total size of the texel data in bytes.
+    pub fn storage(binding: vkuint, format: Format, size: vkbytes) -> TexelBufferCI<StorageTexel> {
+        TexelBufferCI::new(binding, format, size)
+    }
+}
+
+impl<T> TexelBufferCI<T>
+    where
+        T: TexelBufferUsage {
+
+    fn new(binding: vkuint, format: Format, size: vkbytes) -> TexelBufferCI<T> {
+
+        TexelBufferCI {
+            phantom_type: PhantomData,
+            descriptor: DescriptorMeta {
+                binding,
+                descriptor_type: GsDescriptorType::Buffer(T::DESCRIPTOR_TYPE),
+            },
+            view: GsBufferView::new(format),
+            size,
+        }
+    }
+}
+
+impl<T> BufferCIApi for TexelBufferCI<T>
+    where
+        T: TexelBufferUsage {
+    type IConveyor = ITexel;
+
+    const VK_FLAG: vk::BufferUsageFlags = T::VK_FLAG;
+
+    fn estimate_size(&self) -> vkbytes {
+        self.size
+    }
+
+    fn into_index(self) -> ITexel {
+
+        ITexel {
+            descriptor: self.descriptor,
+            format: self.view.format(),
+            size  : self.size,
+        }
+    }
+
+    fn check_limits(&mut self, device: &GsDevice) -> VkResult<()> {
+
+        let is_format_support = device.phys.formats.query_format_buffers(self.view.format(), T::FORMAT_FEATURE)?;
+        if is_format_support == false {
+            return Err(VkError::other(format!("{:?} is not supported for texel buffer on this device.", self.view.format())))
+        }
+
+        Ok(())
+    }
+
+    fn view_ci(&self) -> Option<BufferViewCI> {
+        Some(self.view.clone())
+    }
+}
+
+pub struct ITexel {
+
+    descriptor: DescriptorMeta,
+    format: Format,
+    size: vkbytes,
+}
+
+pub struct GsTexelBuffer {
+
+    itexel: ITexel,
+    view: vk::BufferView,
+
+    block: BufferBlock,
+    repository_index: usize,
+}
+
+impl BufferInstance for GsTexelBuffer {
+    type InfoType = ITexel;
+
+    fn build(block: BufferBlock, info: Self::InfoType, repository_index: usize) -> Self {
+
+        GsTexelBuffer {
+            itexel: info,
+            // view will be set by distributor.
+            view: vk::BufferView::null(),
+            block, repository_index,
+        }
+    }
+
+    fn acquire_write_ptr(&self, agency: &mut Box<dyn MemoryDataDelegate>) -> VkResult<MemoryWritePtr> {
+        agency.acquire_write_ptr(&self.block, self.repository_index)
+    }
+}
+
+impl DescriptorBindingBufTgt for GsTexelBuffer {
+
+    fn binding_info(&self) -> DescriptorBindingBufInfo {
+
+        DescriptorBindingBufInfo {
+            meta: self.itexel.descriptor.clone(),
+            buffer_handle: self.block.handle,
+            element_size : self.itexel.size,
+            texel_view   : Some(self.view),
+        }
+    }
+}
+
+impl BufferCopiable for GsTexelBuffer {
+
+    fn full_copy(&self) -> BufferFullCopyInfo {
+        self.block.full_copy()
+    }
+}
+
+impl GsTexelBuffer {
+
+    pub(crate) fn set_view(&mut self, view: vk::BufferView) {
+        self.view = view;
+    }
+
+    pub fn format(&self) -> Format {
+        self.itexel.format
+    }
+}
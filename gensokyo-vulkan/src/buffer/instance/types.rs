@@ -2,7 +2,9 @@
 use ash::vk;
 
 use crate::core::GsDevice;
+use crate::buffer::view::BufferViewCI;
 use crate::memory::types::GsMemoryType;
+use crate::error::VkResult;
 use crate::types::vkbytes;
 
 pub trait BufferCIApi: Sized {
@@ -18,8 +20,14 @@ pub trait BufferCIApi: Sized {
 
     fn into_index(self) -> Self::IConveyor;
 
-    fn check_limits(&mut self, _device: &GsDevice) {
+    fn check_limits(&mut self, _device: &GsDevice) -> VkResult<()> {
         // Default implementation is empty.
+        Ok(())
+    }
+
+    /// Return the configuration of buffer view if this buffer need one(such as texel buffer).
+    fn view_ci(&self) -> Option<BufferViewCI> {
+        None
     }
 }
 
@@ -31,18 +39,24 @@ fn check_buffer_usage(memory_type: GsMemoryType, buffer_flag: vk::BufferUsageFla
                 vk::BufferUsageFlags::VERTEX_BUFFER,
                 vk::BufferUsageFlags::INDEX_BUFFER,
                 vk::BufferUsageFlags::UNIFORM_BUFFER,
+                vk::BufferUsageFlags::UNIFORM_TEXEL_BUFFER,
+                vk::BufferUsageFlags::STORAGE_TEXEL_BUFFER,
             ].contains(&buffer_flag)
         },
         | GsMemoryType::CachedMemory  => {
             [
                 vk::BufferUsageFlags::VERTEX_BUFFER,
                 vk::BufferUsageFlags::INDEX_BUFFER,
+                vk::BufferUsageFlags::UNIFORM_TEXEL_BUFFER,
+                vk::BufferUsageFlags::STORAGE_TEXEL_BUFFER,
             ].contains(&buffer_flag)
         },
         | GsMemoryType::DeviceMemory  => {
             [
                 vk::BufferUsageFlags::VERTEX_BUFFER,
                 vk::BufferUsageFlags::INDEX_BUFFER,
+                vk::BufferUsageFlags::UNIFORM_TEXEL_BUFFER,
+                vk::BufferUsageFlags::STORAGE_TEXEL_BUFFER,
             ].contains(&buffer_flag)
         },
         | GsMemoryType::StagingMemory => {
@@ -53,5 +67,8 @@ fn check_buffer_usage(memory_type: GsMemoryType, buffer_flag: vk::BufferUsageFla
                 vk::BufferUsageFlags::TRANSFER_SRC,
             ].contains(&buffer_flag)
         },
+        | GsMemoryType::TransientMemory => {
+            false
+        },
     }
 }
@@ -105,8 +105,9 @@ impl BufferCIApi for UniformBufferCI {
     }
 
     // Handle uniform buffer particularly.
-    fn check_limits(&mut self, device: &GsDevice) {
+    fn check_limits(&mut self, device: &GsDevice) -> VkResult<()> {
         self.set_alignment(device);
+        Ok(())
     }
 }
 
@@ -152,6 +153,7 @@ impl DescriptorBindingBufTgt for GsUniformBuffer {
             meta: self.iuniform.descriptor.clone(),
             buffer_handle: self.block.handle,
             element_size : self.element_size(),
+            texel_view   : None,
         }
     }
 }
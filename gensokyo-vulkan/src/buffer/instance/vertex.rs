@@ -2,7 +2,7 @@
 use ash::vk;
 
 use crate::buffer::entity::BufferBlock;
-use crate::buffer::traits::{ BufferInstance, BufferCopiable, BufferFullCopyInfo };
+use crate::buffer::traits::{ BufferInstance, BufferCopiable, BufferFullCopyInfo, VertexBindable };
 use crate::buffer::instance::types::BufferCIApi;
 
 use crate::memory::transfer::MemoryDataDelegate;
@@ -62,13 +62,16 @@ impl BufferCopiable for GsVertexBuffer {
     }
 }
 
+impl VertexBindable for GsVertexBuffer {
+
+    fn binding_handle(&self) -> vk::Buffer {
+        self.block.handle
+    }
+}
+
 impl GsVertexBuffer {
 
     pub fn new(vertex_size: vkbytes, vertex_count: usize) -> VertexBufferCI {
         VertexBufferCI { vertex_size, vertex_count }
     }
-
-    pub(crate) fn render_info(&self) -> vk::Buffer {
-        self.block.handle
-    }
 }
@@ -1,14 +1,16 @@
 
 pub use self::target::GsBuffer;
 pub use self::entity::{ BufferBlock, BufferSlice };
-pub use self::traits::{ BufferInstance, BufferCopiable, BufferFullCopyInfo, BufferCopyRanges };
+pub use self::traits::{ BufferInstance, BufferCopiable, BufferFullCopyInfo, BufferCopyRanges, VertexBindable };
 pub use self::repository::GsBufferRepository;
+pub use self::view::{ GsBufferView, BufferViewCI };
 
 mod target;
 mod entity;
 mod traits;
 mod barrier;
 mod repository;
+mod view;
 
 pub mod instance;
 pub mod allocator;
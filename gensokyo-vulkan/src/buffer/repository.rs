@@ -2,6 +2,7 @@
 use crate::core::GsDevice;
 
 use crate::buffer::target::GsBuffer;
+use crate::buffer::view::GsBufferView;
 use crate::buffer::allocator::BufferAllocateInfos;
 use crate::buffer::allocator::types::BufferMemoryTypeAbs;
 
@@ -21,6 +22,7 @@ pub struct GsBufferRepository<M>
 
     device  : GsDevice,
     buffers : Vec<GsBuffer>,
+    views   : Vec<Option<GsBufferView>>,
     memory  : GsBufferMemory,
 
     allocate_infos: BufferAllocateInfos,
@@ -30,13 +32,13 @@ impl<M> GsBufferRepository<M>
     where
         M: BufferMemoryTypeAbs {
 
-    pub(crate) fn store(phantom_type: PhantomData<M>, device: GsDevice, buffers: Vec<GsBuffer>, memory: GsBufferMemory, allocate_infos: BufferAllocateInfos) -> GsBufferRepository<M> {
+    pub(crate) fn store(phantom_type: PhantomData<M>, device: GsDevice, buffers: Vec<GsBuffer>, views: Vec<Option<GsBufferView>>, memory: GsBufferMemory, allocate_infos: BufferAllocateInfos) -> GsBufferRepository<M> {
 
         GsBufferRepository {
             phantom_type,
             device, memory,
 
-            buffers,
+            buffers, views,
             allocate_infos,
         }
     }
@@ -54,7 +56,9 @@ impl<M> GsBufferRepository<M>
             },
             | GsMemoryType::StagingMemory
             | GsMemoryType::CachedMemory
-            | GsMemoryType::DeviceMemory => {
+            | GsMemoryType::DeviceMemory
+            | GsMemoryType::TransientMemory
+            | GsMemoryType::ReadbackMemory => {
                 return Err(VkError::device("This type of memory is not support to use updater."))
             },
         }
@@ -67,7 +71,9 @@ impl<M> Drop for GsBufferRepository<M>
 
     fn drop(&mut self) {
 
-        self.buffers.iter().for_each(|buffer| buffer.discard(&self.device));
+        // views and buffers must be released before their memory.
+        self.views.clear();
+        self.buffers.clear();
 
         self.memory.discard(&self.device);
     }
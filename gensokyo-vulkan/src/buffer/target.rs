@@ -3,6 +3,7 @@ use ash::vk;
 use ash::version::DeviceV1_0;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
 
 use crate::buffer::allocator::types::BufferMemoryTypeAbs;
 use crate::memory::MemoryDstEntity;
@@ -16,6 +17,8 @@ pub struct GsBuffer {
 
     pub(crate) handle: vk::Buffer,
     requirement: vk::MemoryRequirements,
+
+    device: GsDevice,
 }
 
 impl GsBuffer {
@@ -41,16 +44,16 @@ impl GsBuffer {
             device.logic.handle.get_buffer_memory_requirements(handle)
         };
 
-        let buffer = GsBuffer { handle, requirement };
+        let buffer = GsBuffer { handle, requirement, device: device.clone() };
         Ok(buffer)
     }
+}
 
-    /// Destroy vk::Buffer object in Vulkan.
-    pub fn discard(&self, device: &GsDevice) {
+impl Drop for GsBuffer {
 
-        unsafe {
-            device.logic.handle.destroy_buffer(self.handle, None);
-        }
+    /// The vk::Buffer object will be destroyed when device no longer uses it.
+    fn drop(&mut self) {
+        self.device.logic.defer_destroy(DestroyHandle::Buffer(self.handle));
     }
 }
 
@@ -23,14 +23,23 @@ pub trait BufferCopiable: Sized {
     fn copy_split_ranges(&self, range_count: usize) -> BufferCopyRanges {
 
         let full_range = self.full_copy();
-        BufferCopyRanges::from_stride(full_range.handle, full_range.size / range_count as vkbytes, range_count)
+        BufferCopyRanges::from_stride(full_range.handle, full_range.offset, full_range.size / range_count as vkbytes, range_count)
     }
 }
 
+/// The buffer which can be bound as the vertex input source in draw command.
+pub trait VertexBindable {
+
+    fn binding_handle(&self) -> vk::Buffer;
+}
+
+#[derive(Debug, Clone)]
 pub struct BufferFullCopyInfo {
 
     /// `handle` is the handle of buffer whose data is copied from or copy to.
     pub(crate) handle: vk::Buffer,
+    /// `offset` is the starting offset in bytes from the start of the buffer.
+    pub(crate) offset: vkbytes,
     /// If this is the buffer for data source, `size` is the number of bytes to copy.
     ///
     /// If this is the buffer for data destination, `size` will be ignored.
@@ -62,11 +71,11 @@ pub struct BufferCopyRanges {
 
 impl BufferCopyRanges {
 
-    pub fn from_stride(handle: vk::Buffer, stride: vkbytes, stride_count: usize) -> BufferCopyRanges {
+    pub fn from_stride(handle: vk::Buffer, base_offset: vkbytes, stride: vkbytes, stride_count: usize) -> BufferCopyRanges {
 
         let mut offsets = Vec::with_capacity(stride_count);
         for i in 0..(stride_count as vkbytes) {
-            offsets.push(i * stride);
+            offsets.push(base_offset + i * stride);
         }
 
         BufferCopyRanges { handle, offsets }
@@ -0,0 +1,88 @@
+
+use ash::vk;
+use ash::version::DeviceV1_0;
+
+use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
+
+use crate::buffer::target::GsBuffer;
+use crate::types::format::Format;
+use crate::types::vkbytes;
+
+use crate::error::{ VkResult, VkError };
+
+use std::ptr;
+
+/// Wrapper class for vk::BufferView object.
+pub struct GsBufferView {
+
+    pub(crate) handle: vk::BufferView,
+    device: GsDevice,
+}
+
+impl GsBufferView {
+
+    pub fn new(format: Format) -> BufferViewCI {
+
+        BufferViewCI {
+            format,
+            offset: 0,
+            range : vk::WHOLE_SIZE,
+        }
+    }
+
+}
+
+impl Drop for GsBufferView {
+
+    fn drop(&mut self) {
+        self.device.logic.defer_destroy(DestroyHandle::BufferView(self.handle));
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BufferViewCI {
+
+    format: Format,
+    offset: vkbytes,
+    range : vkbytes,
+}
+
+impl BufferViewCI {
+
+    /// Set the range of buffer the view can access. Default is the whole buffer.
+    pub fn with_range(mut self, offset: vkbytes, range: vkbytes) -> BufferViewCI {
+
+        self.offset = offset;
+        self.range  = range;
+        self
+    }
+
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Generate a vk::BufferView object. The `buffer` must have been bound to memory before calling this function.
+    pub fn build(&self, device: &GsDevice, buffer: &GsBuffer) -> VkResult<GsBufferView> {
+
+        let view_ci = vk::BufferViewCreateInfo {
+            s_type: vk::StructureType::BUFFER_VIEW_CREATE_INFO,
+            p_next: ptr::null(),
+            // flags is reserved for future use in API version 1.1.82.
+            flags : vk::BufferViewCreateFlags::empty(),
+            buffer: buffer.handle,
+            format: self.format.into(),
+            offset: self.offset,
+            range : self.range,
+        };
+
+        let handle = unsafe {
+            device.logic.handle.create_buffer_view(&view_ci, None)
+                .or(Err(VkError::create("vk::BufferView")))?
+        };
+
+        let view = GsBufferView { handle, device: device.clone() };
+        Ok(view)
+    }
+}
@@ -1,5 +1,10 @@
 
+use ash::vk;
+use ash::version::DeviceV1_0;
+
 use crate::command::record::{ GsCmdRecorder, GsVkCommandType };
+use crate::command::traits::IntoVKBarrier;
+use crate::image::ImageBarrierCI;
 use crate::utils::phantom::Compute;
 
 impl GsVkCommandType for Compute {
@@ -7,9 +12,19 @@ impl GsVkCommandType for Compute {
 }
 
 pub trait GsCmdComputeApi {
-    // Yet Empty...
+
+    fn image_pipeline_barrier(&self, src_stage: vk::PipelineStageFlags, dst_stage: vk::PipelineStageFlags, dependencies: vk::DependencyFlags, image_barriers: Vec<ImageBarrierCI>) -> &Self;
 }
 
 impl GsCmdComputeApi for GsCmdRecorder<Compute> {
-    // Yet Empty...
+
+    fn image_pipeline_barrier(&self, src_stage: vk::PipelineStageFlags, dst_stage: vk::PipelineStageFlags, dependencies: vk::DependencyFlags, image_barriers: Vec<ImageBarrierCI>) -> &Self {
+
+        let barriers: Vec<vk::ImageMemoryBarrier> = image_barriers.into_iter()
+            .map(|b| b.into_barrier()).collect();
+
+        unsafe {
+            self.device.logic.handle.cmd_pipeline_barrier(self.cmd_handle, src_stage, dst_stage, dependencies, &[], &[], &barriers);
+        } self
+    }
 }
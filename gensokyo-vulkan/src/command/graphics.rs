@@ -4,11 +4,14 @@ use ash::version::DeviceV1_0;
 
 use crate::command::record::{ GsCmdRecorder, GsVkCommandType };
 use crate::command::infos::{ CmdViewportInfo, CmdScissorInfo, CmdDepthBiasInfo, CmdDepthBoundInfo };
-use crate::command::traits::CmdPipelineAbs;
+use crate::command::traits::{ CmdPipelineAbs, IntoVKBarrier };
 
 use crate::pipeline::target::{ GsPipelineStage, GsVkPipelineType };
+use crate::pipeline::state::viewport::{ ViewportInfo, ScissorInfo };
 use crate::descriptor::DescriptorSet;
-use crate::buffer::instance::{ GsVertexBuffer, GsIndexBuffer };
+use crate::buffer::instance::GsIndexBuffer;
+use crate::buffer::VertexBindable;
+use crate::image::ImageBarrierCI;
 use crate::utils::phantom::Graphics;
 use crate::types::{ vkuint, vksint, vkfloat, vkbytes };
 
@@ -25,11 +28,34 @@ impl GsCmdGraphicsApi for GsCmdRecorder<Graphics> {
         let begin_info = pipeline.render_pass().begin_info(framebuffer_index);
         unsafe {
             self.device.logic.handle.cmd_begin_render_pass(self.cmd_handle, &begin_info, self.cmd_usage.contents());
-        } self
+        }
+
+        // cover the whole framebuffer with the dynamic viewports and scissors, which can be overwritten by `set_viewport` or `set_scissor` later.
+        let dynamics = pipeline.viewport_dynamics();
+        if dynamics.count > 0 {
+            let dimension = pipeline.render_pass().dimension();
+
+            if dynamics.is_viewport_dynamic {
+                let ports = vec![ViewportInfo::from(dimension).0; dynamics.count as usize];
+                unsafe {
+                    self.device.logic.handle.cmd_set_viewport(self.cmd_handle, 0, &ports);
+                }
+            }
+
+            if dynamics.is_scissor_dynamic {
+                let scissors = vec![ScissorInfo::from(dimension).0; dynamics.count as usize];
+                unsafe {
+                    self.device.logic.handle.cmd_set_scissor(self.cmd_handle, 0, &scissors);
+                }
+            }
+        }
+
+        self
     }
 
     /// Set the viewport dynamically.
-    /// Before using this function, the `ViewportStateType::Dynamic` or `ViewportStateType::DynamicViewportFixedScissor` must be set to ViewportState in pipeline creation(by calling `GraphicsPipelineConfig::setup_viewport()`).
+    /// The viewport is dynamic by default, and `begin_render_pass` has set it to cover the whole framebuffer.
+    /// If `GfxPipelineConfig::reset_viewport()` is called during pipeline creation, `ViewportStateType::Dynamic` or `ViewportStateType::DynamicViewportFixedScissor` must be used.
     ///
     /// `first_viewport` is the index of the first viewport whose parameters are updated by the command.
     ///
@@ -44,7 +70,8 @@ impl GsCmdGraphicsApi for GsCmdRecorder<Graphics> {
     }
 
     /// Set the scissor rectangles dynamically.
-    /// Before using this function, the `ViewportStateType::Dynamic` or `ViewportStateType::FixedViewportDynamicScissor` must be set to ViewportState in pipeline creation(by calling `GraphicsPipelineConfig::setup_viewport()`).
+    /// The scissor is dynamic by default, and `begin_render_pass` has set it to cover the whole framebuffer.
+    /// If `GfxPipelineConfig::reset_viewport()` is called during pipeline creation, `ViewportStateType::Dynamic` or `ViewportStateType::FixedViewportDynamicScissor` must be used.
     ///
     /// `first_scissor` is the index of the first scissor whose state is updated by the command.
     ///
@@ -146,21 +173,26 @@ impl GsCmdGraphicsApi for GsCmdRecorder<Graphics> {
         } self
     }
 
+    /// Bind the whole `buffers` to vertex input bindings, starting at `first_binding`.
+    ///
     /// `first_binding` is correspond to `vk::VertexInputBindingDescription.binding` value.
-    fn bind_vertex_buffers(&self, first_binding: vkuint, buffers: &[&GsVertexBuffer]) -> &Self {
+    fn bind_vertex_buffers(&self, first_binding: vkuint, buffers: &[&dyn VertexBindable]) -> &Self {
 
-        let mut handles = vec![];
-        let mut offsets = vec![];
+        let offsets = vec![0; buffers.len()];
+        self.bind_vertex_buffers_offset(first_binding, buffers, &offsets)
+    }
 
-        for block in buffers.into_iter() {
+    /// Bind `buffers` to vertex input bindings, starting at `first_binding`.
+    ///
+    /// `offsets` is the starting offset in bytes of each buffer, and its length must be equal to the length of `buffers`.
+    fn bind_vertex_buffers_offset(&self, first_binding: vkuint, buffers: &[&dyn VertexBindable], offsets: &[vkbytes]) -> &Self {
 
-            handles.push(block.render_info());
-            // TODO: Add configuration for offset parameter.
-            offsets.push(0);
-        }
+        debug_assert_eq!(buffers.len(), offsets.len(), "The count of vertex buffers and offsets must be equal.");
 
+        let handles: Vec<vk::Buffer> = buffers.iter()
+            .map(|b| b.binding_handle()).collect();
         unsafe {
-            self.device.logic.handle.cmd_bind_vertex_buffers(self.cmd_handle, first_binding, &handles, &offsets);
+            self.device.logic.handle.cmd_bind_vertex_buffers(self.cmd_handle, first_binding, &handles, offsets);
         } self
     }
 
@@ -200,6 +232,28 @@ impl GsCmdGraphicsApi for GsCmdRecorder<Graphics> {
         } self
     }
 
+    /// Insert image memory barriers outside of render pass.
+    ///
+    /// It is usually used to synchronize the writes of compute shader with the following shader reads.
+    fn image_pipeline_barrier(&self, src_stage: vk::PipelineStageFlags, dst_stage: vk::PipelineStageFlags, dependencies: vk::DependencyFlags, image_barriers: Vec<ImageBarrierCI>) -> &Self {
+
+        let barriers: Vec<vk::ImageMemoryBarrier> = image_barriers.into_iter()
+            .map(|b| b.into_barrier()).collect();
+
+        unsafe {
+            self.device.logic.handle.cmd_pipeline_barrier(self.cmd_handle, src_stage, dst_stage, dependencies, &[], &[], &barriers);
+        } self
+    }
+
+    /// Transition to the next subpass of the render pass.
+    ///
+    /// The pipelines used in the next subpass should be created with its subpass index by `GfxPipelineConfig::reset_subpass()`.
+    fn next_subpass(&self) -> &Self {
+        unsafe {
+            self.device.logic.handle.cmd_next_subpass(self.cmd_handle, self.cmd_usage.contents());
+        } self
+    }
+
     fn end_render_pass(&self) -> &Self {
         // Ending the render pass will add an implicit barrier transitioning the frame buffer color attachment vk::IMAGE_LAYOUT_PRESENT_SRC_KHR for presenting it to the windowing system.
         unsafe {
@@ -234,7 +288,9 @@ pub trait GsCmdGraphicsApi {
 
     fn bind_pipeline(&self) -> &Self;
 
-    fn bind_vertex_buffers(&self, first_binding: vkuint, buffers: &[&GsVertexBuffer]) -> &Self;
+    fn bind_vertex_buffers(&self, first_binding: vkuint, buffers: &[&dyn VertexBindable]) -> &Self;
+
+    fn bind_vertex_buffers_offset(&self, first_binding: vkuint, buffers: &[&dyn VertexBindable], offsets: &[vkbytes]) -> &Self;
 
     fn bind_index_buffer(&self, buffer: &GsIndexBuffer, offset: vkbytes) -> &Self;
 
@@ -246,5 +302,9 @@ pub trait GsCmdGraphicsApi {
 
     fn draw_indexed(&self, index_count: vkuint, instance_count: vkuint, first_index: vkuint, vertex_offset: vksint, first_instance: vkuint) -> &Self;
 
+    fn image_pipeline_barrier(&self, src_stage: vk::PipelineStageFlags, dst_stage: vk::PipelineStageFlags, dependencies: vk::DependencyFlags, image_barriers: Vec<ImageBarrierCI>) -> &Self;
+
+    fn next_subpass(&self) -> &Self;
+
     fn end_render_pass(&self) -> &Self;
 }
@@ -44,7 +44,7 @@ impl<T> GsCmdRecorder<T>
             device: device.clone(),
             cmd_handle: command.handle,
             cmd_usage : command.usage,
-            pipeline_handle: pipeline.pipeline().clone(),
+            pipeline_handle: pipeline.pipeline(),
             pipeline_layout: pipeline.layout().clone(),
         }
     }
@@ -102,7 +102,7 @@ impl<T> GsCmdRecorder<T> where T: GsVkCommandType {
 impl GsCmdRecorder<Graphics> {
 
     pub fn switch_pipeline(&mut self, new_pipeline: &impl CmdPipelineAbs) {
-        self.pipeline_handle = new_pipeline.pipeline().clone();
+        self.pipeline_handle = new_pipeline.pipeline();
         self.pipeline_layout = new_pipeline.layout().clone();
     }
 }
@@ -110,7 +110,7 @@ impl GsCmdRecorder<Graphics> {
 impl GsCmdRecorder<Compute> {
 
     pub fn switch_pipeline(&mut self, new_pipeline: &impl CmdPipelineAbs) {
-        self.pipeline_handle = new_pipeline.pipeline().clone();
+        self.pipeline_handle = new_pipeline.pipeline();
         self.pipeline_layout = new_pipeline.layout().clone();
     }
 }
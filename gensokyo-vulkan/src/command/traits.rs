@@ -2,6 +2,7 @@
 use ash::vk;
 
 use crate::pipeline::pass::GsRenderPass;
+use crate::pipeline::state::viewport::ViewportDynamics;
 
 pub trait IntoVKBarrier: Sized {
     type BarrierType;
@@ -12,6 +13,8 @@ pub trait IntoVKBarrier: Sized {
 pub trait CmdPipelineAbs {
 
     fn layout(&self)   -> &vk::PipelineLayout;
-    fn pipeline(&self) -> &vk::Pipeline;
+    fn pipeline(&self) -> vk::Pipeline;
     fn render_pass(&self) -> &GsRenderPass;
+    /// Whether the viewport and scissor of this pipeline are set during command recording.
+    fn viewport_dynamics(&self) -> ViewportDynamics;
 }
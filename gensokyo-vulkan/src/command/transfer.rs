@@ -6,6 +6,7 @@ use crate::command::record::{ GsCmdRecorder, GsVkCommandType };
 use crate::command::traits::IntoVKBarrier;
 use crate::image::ImageBarrierCI;
 use crate::utils::phantom::Transfer;
+use crate::types::vkuint;
 
 impl GsVkCommandType for Transfer {
     // Empty...
@@ -55,6 +56,31 @@ impl GsCmdTransferApi for GsCmdRecorder<Transfer> {
     }
 }
 
+impl GsCmdRecorder<Transfer> {
+
+    /// Record a compute dispatch into the transfer command buffer.
+    ///
+    /// It's used to process the resources during uploading(such as generating mipmaps), without another submission.
+    /// The queue family of transfer queue must support compute operations, which is checked before the image requiring it is created.
+    pub(crate) fn dispatch_compute(&self, pipeline: vk::Pipeline, layout: vk::PipelineLayout, set: vk::DescriptorSet, push_constants: &[u8], group_count: [vkuint; 3]) -> &Self {
+
+        debug_assert!({
+            let family_index = self.device.logic.transfer_queue().queue().family_index;
+            self.device.phys.families.is_queue_support_capability(family_index, vk::QueueFlags::COMPUTE)
+        }, "The queue family of transfer queue does not support compute operations.");
+
+        unsafe {
+            let device = &self.device.logic.handle;
+            device.cmd_bind_pipeline(self.cmd_handle, vk::PipelineBindPoint::COMPUTE, pipeline);
+            device.cmd_bind_descriptor_sets(self.cmd_handle, vk::PipelineBindPoint::COMPUTE, layout, 0, &[set], &[]);
+            if push_constants.is_empty() == false {
+                device.cmd_push_constants(self.cmd_handle, layout, vk::ShaderStageFlags::COMPUTE, 0, push_constants);
+            }
+            device.cmd_dispatch(self.cmd_handle, group_count[0], group_count[1], group_count[2]);
+        } self
+    }
+}
+
 pub trait GsCmdTransferApi {
 
     fn copy_buf2buf(&self, src_buffer_handle: vk::Buffer, dst_buffer_handle: vk::Buffer, regions: &[vk::BufferCopy]) -> &Self;
@@ -3,7 +3,7 @@ use ash::vk;
 use ash::version::InstanceV1_0;
 
 use crate::core::instance::GsInstance;
-use crate::core::physical::GsPhysicalDevice;
+use crate::core::physical::{ GsPhysicalDevice, DeviceExtensionType, IndexTypeUint8FeaturesEXT };
 use crate::core::device::device::{ DeviceConfig, GsLogicalDevice };
 use crate::core::device::enums::{ PrefabQueuePriority, DeviceQueueIndex, QueueRequestStrategy };
 use crate::core::device::queue::{ GsGraphicsQueue, GsPresentQueue, GsTransferQueue };
@@ -14,6 +14,7 @@ use crate::error::{ VkResult, VkError };
 use crate::utils::cast;
 
 use std::ptr;
+use std::os::raw::c_void;
 
 pub struct LogicalDeviceBuilder<'a> {
 
@@ -76,10 +77,25 @@ impl<'a> LogicalDeviceBuilder<'a> {
         let enable_layer_names = cast::cstrings2ptrs(&self.instance.enable_layer_names);
         let enable_extension_names = cast::cstrings2ptrs(self.physical.extensions.borrow_enable_extensions());
 
+        // The features of device extensions are chained in the p_next of vk::DeviceCreateInfo.
+        let mut extension_features: *mut c_void = ptr::null_mut();
+
+        let mut indexing_features = self.physical.features.enable_indexing_features();
+        if let Some(ref mut indexing_features) = indexing_features {
+            indexing_features.p_next = extension_features;
+            extension_features = indexing_features as *mut vk::PhysicalDeviceDescriptorIndexingFeaturesEXT as *mut c_void;
+        }
+
+        let mut index_uint8_features = IndexTypeUint8FeaturesEXT::enable();
+        if self.physical.extensions.is_enable(DeviceExtensionType::IndexTypeUint8) {
+            index_uint8_features.p_next = extension_features;
+            extension_features = &mut index_uint8_features as *mut IndexTypeUint8FeaturesEXT as *mut c_void;
+        }
+
         // Create the logical device.
         let device_create_info = vk::DeviceCreateInfo {
             s_type                     : vk::StructureType::DEVICE_CREATE_INFO,
-            p_next                     : ptr::null(),
+            p_next                     : extension_features as *const c_void,
             // flags is reserved for future use in API version 1.1.82.
             flags                      : vk::DeviceCreateFlags::empty(),
             queue_create_info_count    : queue_cis.len() as _,
@@ -0,0 +1,183 @@
+
+use ash::vk;
+use ash::version::DeviceV1_0;
+
+use crate::core::device::destroy::DestroyHandle;
+use crate::error::VkResult;
+use crate::types::vkuint;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The cache of `vk::DescriptorSetLayout` and `vk::PipelineLayout` objects on a device.
+///
+/// Identical layouts share the same handle, so that the descriptor sets allocated from different allocators
+/// are compatible with the pipelines built elsewhere. Each handle is reference counted,
+/// and it is destroyed after all its owners have released it.
+pub(crate) struct GsLayoutCache {
+
+    set_layouts     : LayoutTable<SetLayoutKey, vk::DescriptorSetLayout>,
+    pipeline_layouts: LayoutTable<PipelineLayoutKey, vk::PipelineLayout>,
+}
+
+/// The key identifying a descriptor set layout by its create flags and bindings.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub(crate) struct SetLayoutKey {
+
+    flags: vk::Flags,
+    bindings: Vec<SetLayoutBindingKey>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct SetLayoutBindingKey {
+
+    binding: vkuint,
+    descriptor_type: i32,
+    count: vkuint,
+    stages: vk::Flags,
+    binding_flags: vk::Flags,
+}
+
+/// The key identifying a pipeline layout by its descriptor set layouts and push constant ranges.
+///
+/// The set layouts are identified by their contents instead of their handles,
+/// since the handle of a destroyed set layout may be reused by another one.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct PipelineLayoutKey {
+
+    set_layouts: Vec<SetLayoutKey>,
+    push_constants: Vec<(vk::Flags, vkuint, vkuint)>,
+}
+
+struct LayoutTable<K, H> {
+
+    /// the cached handle and its reference count.
+    entries: HashMap<K, (H, usize)>,
+}
+
+impl SetLayoutKey {
+
+    /// `binding_flags` is either empty or contains the flags of each binding in `bindings`.
+    pub fn new(flags: vk::DescriptorSetLayoutCreateFlags, bindings: &[vk::DescriptorSetLayoutBinding], binding_flags: &[vk::DescriptorBindingFlagsEXT]) -> SetLayoutKey {
+
+        let mut binding_keys: Vec<SetLayoutBindingKey> = bindings.iter().enumerate()
+            .map(|(i, binding)| SetLayoutBindingKey {
+                binding: binding.binding,
+                descriptor_type: binding.descriptor_type.as_raw(),
+                count  : binding.descriptor_count,
+                stages : binding.stage_flags.as_raw(),
+                binding_flags: binding_flags.get(i).map_or(0, |flags| flags.as_raw()),
+            }).collect();
+
+        // the order of bindings does not affect the layout.
+        binding_keys.sort_by_key(|key| key.binding);
+
+        SetLayoutKey {
+            flags: flags.as_raw(),
+            bindings: binding_keys,
+        }
+    }
+}
+
+impl PipelineLayoutKey {
+
+    pub fn new(set_layouts: &[SetLayoutKey], push_constants: &[vk::PushConstantRange]) -> PipelineLayoutKey {
+
+        let mut range_keys: Vec<(vk::Flags, vkuint, vkuint)> = push_constants.iter()
+            .map(|range| (range.stage_flags.as_raw(), range.offset, range.size))
+            .collect();
+
+        // the order of push constant ranges does not affect the layout, but the order of set layouts does.
+        range_keys.sort();
+
+        PipelineLayoutKey {
+            set_layouts: set_layouts.to_vec(),
+            push_constants: range_keys,
+        }
+    }
+}
+
+impl<K, H> LayoutTable<K, H> where K: Eq + Hash + Clone, H: Copy + PartialEq {
+
+    fn new() -> LayoutTable<K, H> {
+        LayoutTable { entries: HashMap::new() }
+    }
+
+    fn acquire(&mut self, key: K, create: impl FnOnce() -> VkResult<H>) -> VkResult<H> {
+
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.1 += 1;
+            return Ok(entry.0)
+        }
+
+        let handle = create()?;
+        self.entries.insert(key, (handle, 1));
+
+        Ok(handle)
+    }
+
+    /// Return true if the handle is no longer referenced by any owner, or it does not come from this table.
+    fn release(&mut self, handle: H) -> bool {
+
+        let released_key = match self.entries.iter_mut().find(|(_, entry)| entry.0 == handle) {
+            | Some((key, entry)) => {
+                entry.1 -= 1;
+                if entry.1 > 0 { return false }
+                key.clone()
+            },
+            | None => return true,
+        };
+
+        self.entries.remove(&released_key);
+        true
+    }
+
+    fn drain(&mut self) -> Vec<H> {
+        self.entries.drain().map(|(_, entry)| entry.0).collect()
+    }
+}
+
+impl GsLayoutCache {
+
+    pub fn new() -> GsLayoutCache {
+
+        GsLayoutCache {
+            set_layouts     : LayoutTable::new(),
+            pipeline_layouts: LayoutTable::new(),
+        }
+    }
+
+    /// Return the cached descriptor set layout of `key`, or create it by `create` if it does not exist.
+    pub fn acquire_set_layout(&mut self, key: SetLayoutKey, create: impl FnOnce() -> VkResult<vk::DescriptorSetLayout>) -> VkResult<vk::DescriptorSetLayout> {
+        self.set_layouts.acquire(key, create)
+    }
+
+    /// Return the cached pipeline layout of `key`, or create it by `create` if it does not exist.
+    pub fn acquire_pipeline_layout(&mut self, key: PipelineLayoutKey, create: impl FnOnce() -> VkResult<vk::PipelineLayout>) -> VkResult<vk::PipelineLayout> {
+        self.pipeline_layouts.acquire(key, create)
+    }
+
+    /// Release one reference of the layout, and return true if the handle should be destroyed.
+    pub fn release(&mut self, handle: DestroyHandle) -> bool {
+
+        match handle {
+            | DestroyHandle::DescriptorSetLayout(layout) => self.set_layouts.release(layout),
+            | DestroyHandle::PipelineLayout(layout) => self.pipeline_layouts.release(layout),
+            | _ => true,
+        }
+    }
+
+    /// Destroy all the layouts remaining in the cache. The device must be idle.
+    pub fn discard(&mut self, device: &ash::Device) {
+
+        unsafe {
+            for layout in self.pipeline_layouts.drain() {
+                device.destroy_pipeline_layout(layout, None);
+            }
+
+            for layout in self.set_layouts.drain() {
+                device.destroy_descriptor_set_layout(layout, None);
+            }
+        }
+    }
+}
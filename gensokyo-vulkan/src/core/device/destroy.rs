@@ -0,0 +1,90 @@
+
+use ash::vk;
+use ash::version::DeviceV1_0;
+
+use std::collections::VecDeque;
+
+/// The Vulkan handles waiting to be destroyed.
+#[derive(Debug, Clone, Copy)]
+pub enum DestroyHandle {
+    Buffer(vk::Buffer),
+    BufferView(vk::BufferView),
+    Image(vk::Image),
+    ImageView(vk::ImageView),
+    Sampler(vk::Sampler),
+    Memory(vk::DeviceMemory),
+    Pipeline(vk::Pipeline),
+    PipelineLayout(vk::PipelineLayout),
+    RenderPass(vk::RenderPass),
+    Framebuffer(vk::Framebuffer),
+    DescriptorPool(vk::DescriptorPool),
+    DescriptorSetLayout(vk::DescriptorSetLayout),
+}
+
+impl DestroyHandle {
+
+    unsafe fn destroy(&self, device: &ash::Device) {
+        match self {
+            | DestroyHandle::Buffer(handle)              => device.destroy_buffer(*handle, None),
+            | DestroyHandle::BufferView(handle)          => device.destroy_buffer_view(*handle, None),
+            | DestroyHandle::Image(handle)               => device.destroy_image(*handle, None),
+            | DestroyHandle::ImageView(handle)           => device.destroy_image_view(*handle, None),
+            | DestroyHandle::Sampler(handle)             => device.destroy_sampler(*handle, None),
+            | DestroyHandle::Memory(handle)              => device.free_memory(*handle, None),
+            | DestroyHandle::Pipeline(handle)            => device.destroy_pipeline(*handle, None),
+            | DestroyHandle::PipelineLayout(handle)      => device.destroy_pipeline_layout(*handle, None),
+            | DestroyHandle::RenderPass(handle)          => device.destroy_render_pass(*handle, None),
+            | DestroyHandle::Framebuffer(handle)         => device.destroy_framebuffer(*handle, None),
+            | DestroyHandle::DescriptorPool(handle)      => device.destroy_descriptor_pool(*handle, None),
+            | DestroyHandle::DescriptorSetLayout(handle) => device.destroy_descriptor_set_layout(*handle, None),
+        }
+    }
+}
+
+/// A queue delaying the destruction of Vulkan handles until the device no longer uses them.
+///
+/// Each handle is stamped with the frame serial when it is pushed. The frame serial increases every time the fence of a frame in flight is waited,
+/// so a handle pushed at serial `S` is safe to destroy once the serial reaches `S + frames_in_flight`.
+pub(crate) struct GsDestroyQueue {
+
+    /// the count of frames whose fence have been waited.
+    frame_serial: u64,
+    pendings: VecDeque<(u64, DestroyHandle)>,
+}
+
+impl GsDestroyQueue {
+
+    pub fn new() -> GsDestroyQueue {
+
+        GsDestroyQueue {
+            frame_serial: 0,
+            pendings: VecDeque::new(),
+        }
+    }
+
+    pub fn push(&mut self, handle: DestroyHandle) {
+        self.pendings.push_back((self.frame_serial, handle));
+    }
+
+    /// Advance the frame serial and destroy the handles no longer in use by any frame in flight.
+    pub fn advance_frame(&mut self, device: &ash::Device, frames_in_flight: usize) {
+
+        self.frame_serial += 1;
+
+        while let Some(&(serial, handle)) = self.pendings.front() {
+            if serial + (frames_in_flight as u64) <= self.frame_serial {
+                unsafe { handle.destroy(device); }
+                self.pendings.pop_front();
+            } else {
+                break
+            }
+        }
+    }
+
+    /// Destroy all the pending handles immediately. The device must be idle when calling this function.
+    pub fn flush(&mut self, device: &ash::Device) {
+
+        self.pendings.drain(..)
+            .for_each(|(_, handle)| unsafe { handle.destroy(device) });
+    }
+}
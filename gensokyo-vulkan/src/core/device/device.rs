@@ -8,13 +8,19 @@ use crate::core::GsDevice;
 use crate::core::device::enums::{ DeviceQueueIdentifier, QueueRequestStrategy };
 use crate::core::device::queue::{ GsGraphicsQueue, GsPresentQueue, GsTransferQueue, GsTransfer };
 use crate::core::device::queue::{ GsQueue, QueueSubmitBundle };
+use crate::core::device::destroy::{ GsDestroyQueue, DestroyHandle };
+use crate::core::device::cache::GsLayoutCache;
+use crate::core::swapchain::{ SwapchainDependent, GsSwapchainDependents };
 
 use crate::sync::GsFence;
+use crate::memory::GsStagingPool;
+use crate::image::instance::GsDownsampleCache;
 
 use crate::error::{ VkResult, VkError };
 use crate::types::vklint;
 
 use std::ptr;
+use std::sync::{ Arc, Mutex, MutexGuard };
 
 pub struct GsLogicalDevice {
 
@@ -23,6 +29,18 @@ pub struct GsLogicalDevice {
     graphics_queue: GsGraphicsQueue,
     present_queue : GsPresentQueue,
     transfer_queue: GsTransferQueue,
+
+    /// the lock to externally synchronize the access to `vk::Queue` objects(queue submission, presentation and device waiting).
+    queue_lock: Mutex<()>,
+    destroy_queue: Mutex<GsDestroyQueue>,
+    /// the staging buffers shared by all the data uploading on this device.
+    staging_pool : Mutex<GsStagingPool>,
+    /// the descriptor set layouts and pipeline layouts shared by identical layout creations.
+    layout_cache : Mutex<GsLayoutCache>,
+    /// the sampler, layouts and pipelines used to generate mipmaps by compute shader.
+    downsample_cache: Mutex<GsDownsampleCache>,
+    /// the objects which are recreated after the swapchain is resized.
+    swapchain_dependents: Mutex<GsSwapchainDependents>,
 }
 
 impl GsLogicalDevice {
@@ -34,6 +52,12 @@ impl GsLogicalDevice {
             graphics_queue: graphics,
             present_queue : present,
             transfer_queue: transfer,
+            queue_lock   : Mutex::new(()),
+            destroy_queue: Mutex::new(GsDestroyQueue::new()),
+            staging_pool : Mutex::new(GsStagingPool::new()),
+            layout_cache : Mutex::new(GsLayoutCache::new()),
+            downsample_cache: Mutex::new(GsDownsampleCache::new()),
+            swapchain_dependents: Mutex::new(GsSwapchainDependents::new()),
         }
     }
 
@@ -93,6 +117,7 @@ impl GsLogicalDevice {
             .and_then(|f| Some(f.handle))
             .unwrap_or(vk::Fence::null());
 
+        let _guard = self.queue_guard();
         unsafe {
             self.handle.queue_submit(queue.handle, &[submit_info], fence)
                 .or(Err(VkError::device("Failed to submit command to device.")))?
@@ -101,15 +126,54 @@ impl GsLogicalDevice {
         Ok(())
     }
 
+    /// Wait the device to become idle, and then destroy all the handles in the deferred destruction queue.
     pub fn wait_idle(&self) -> VkResult<()> {
-        unsafe {
-            self.handle.device_wait_idle()
-                .or(Err(VkError::device("Device failed to wait idle.")))
+
+        {
+            let _guard = self.queue_guard();
+            unsafe {
+                self.handle.device_wait_idle()
+                    .or(Err(VkError::device("Device failed to wait idle.")))?;
+            }
         }
+
+        self.destroy_queue.lock().unwrap().flush(&self.handle);
+        Ok(())
+    }
+
+    /// Push a handle to the deferred destruction queue. It will be destroyed when no frame in flight uses it.
+    pub fn defer_destroy(&self, handle: DestroyHandle) {
+        self.destroy_queue.lock().unwrap().push(handle);
+    }
+
+    /// Release a descriptor set layout or pipeline layout acquired from the layout cache.
+    ///
+    /// The layout is pushed to the deferred destruction queue once none of its owners uses it.
+    pub fn release_layout(&self, handle: DestroyHandle) {
+
+        let is_unused = self.layout_cache.lock().unwrap().release(handle);
+        if is_unused {
+            self.defer_destroy(handle);
+        }
+    }
+
+    /// Notify the device that the fence of a frame in flight has been waited, so that the handles no longer in use can be destroyed.
+    ///
+    /// `frames_in_flight` is the count of frames that may be processed by device at the same time.
+    pub fn frame_complete(&self, frames_in_flight: usize) {
+        self.destroy_queue.lock().unwrap().advance_frame(&self.handle, frames_in_flight);
     }
 
     pub fn discard(&self) {
 
+        // the downsample objects are pushed to the deferred destruction queue, which is flushed when the device becomes idle.
+        self.downsample_cache.lock().unwrap().discard(self);
+
+        // all resources must be released before destroying the device.
+        let _ = self.wait_idle();
+        self.staging_pool.lock().unwrap().discard(&self.handle);
+        self.layout_cache.lock().unwrap().discard(&self.handle);
+
         unsafe {
             self.graphics_queue.discard();
             self.present_queue.discard();
@@ -142,6 +206,36 @@ impl GsLogicalDevice {
     pub(crate) fn transfer_queue(&self) -> &GsTransferQueue {
         &self.transfer_queue
     }
+
+    pub(crate) fn downsample_cache(&self) -> MutexGuard<GsDownsampleCache> {
+        self.downsample_cache.lock().unwrap()
+    }
+
+    pub(crate) fn staging_pool(&self) -> MutexGuard<GsStagingPool> {
+        self.staging_pool.lock().unwrap()
+    }
+
+    pub(crate) fn layout_cache(&self) -> MutexGuard<GsLayoutCache> {
+        self.layout_cache.lock().unwrap()
+    }
+
+    /// Track `dependent`, so that it is recreated when the swapchain is resized.
+    pub(crate) fn track_swapchain_dependent<D>(&self, dependent: &Arc<D>)
+        where
+            D: SwapchainDependent + 'static {
+
+        let dependent: Arc<dyn SwapchainDependent> = dependent.clone();
+        self.swapchain_dependents.lock().unwrap().track(Arc::downgrade(&dependent));
+    }
+
+    pub(crate) fn swapchain_dependents(&self) -> Vec<Arc<dyn SwapchainDependent>> {
+        self.swapchain_dependents.lock().unwrap().alive()
+    }
+
+    /// Acquire the lock of device queues. Any call to `vk::Queue` must be made while holding the returned guard.
+    pub(crate) fn queue_guard(&self) -> MutexGuard<()> {
+        self.queue_lock.lock().unwrap()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -4,11 +4,15 @@ pub use self::builder::LogicalDeviceBuilder;
 pub use self::device::DeviceConfig;
 pub use self::enums::QueueRequestStrategy;
 pub use self::enums::DeviceQueueIdentifier;
+pub use self::destroy::DestroyHandle;
+pub(crate) use self::cache::{ SetLayoutKey, PipelineLayoutKey };
 
 pub mod queue;
 
 mod builder;
 mod device;
 mod enums;
+mod destroy;
+mod cache;
 
 
@@ -12,9 +12,10 @@ use crate::command::{ GsCommandBuffer, CmdBufferUsage };
 use crate::sync::GsFence;
 
 use crate::error::{ VkResult, VkError };
-use crate::types::vklint;
+use crate::types::{ vkuint, vklint };
 
 use std::ptr;
+use std::sync::Mutex;
 
 pub struct GsTransferQueue {
 
@@ -50,11 +51,15 @@ impl GsTransferQueue {
 
     pub fn transfer(&self, device: &GsDevice) -> VkResult<GsTransfer> {
 
+        // make sign to false, since the fence will be reset whenever transfer start.
+        let fence = GsFence::create(device, false)?;
+
         let transfer = GsTransfer {
             device: device.clone(),
+            pool: self.pool.acquire(&device.logic.handle)?,
             command_buffers: vec![],
-            // make sign to false, since the fence will be reset whenever transfer start.
-            fence: GsFence::create(device, false)?,
+            fence,
+            is_pending: false,
             transfer_wait_time: self.transfer_wait_time,
         };
         Ok(transfer)
@@ -64,8 +69,14 @@ impl GsTransferQueue {
 pub struct GsTransfer {
 
     device: GsDevice,
+    /// the command pool used only by this transfer, so that the transfer can be recorded and submitted on any thread.
+    ///
+    /// It's returned to the transfer queue when this transfer is dropped.
+    pool: vk::CommandPool,
     fence: GsFence,
     command_buffers: Vec<GsCommandBuffer>,
+    /// whether the submitted commands have not been waited yet.
+    is_pending: bool,
     transfer_wait_time: vklint,
 }
 
@@ -74,8 +85,7 @@ impl GsTransfer {
     pub fn commands(&self, count: usize) -> VkResult<Vec<GsCommandBuffer>> {
 
         // just use a single primary command buffer for transfer.
-        let transfer_queue = self.device.logic.transfer_queue();
-        let commands = transfer_queue.pool.allocate(&self.device, count)?;
+        let commands = TransferCommandPool::allocate(&self.device, self.pool, count)?;
         Ok(commands)
     }
 
@@ -86,8 +96,7 @@ impl GsTransfer {
 
     pub fn command(&self) -> VkResult<GsCommandBuffer> {
 
-        let transfer_queue = self.device.logic.transfer_queue();
-        let mut commands = transfer_queue.pool.allocate(&self.device, 1)?;
+        let mut commands = TransferCommandPool::allocate(&self.device, self.pool, 1)?;
         Ok(commands.pop().unwrap())
     }
 
@@ -101,6 +110,19 @@ impl GsTransfer {
             return Ok(())
         }
 
+        self.submit()?;
+        self.wait()
+    }
+
+    /// Submit the committed commands to transfer queue without waiting for their completion.
+    ///
+    /// `GsTransfer::wait()` must be called before the resources used in these commands are released.
+    pub fn submit(&mut self) -> VkResult<()> {
+
+        if self.command_buffers.is_empty() {
+            return Ok(())
+        }
+
         self.fence.reset()?;
 
         let submit_commands: Vec<vk::CommandBuffer> = collect_handle!(self.command_buffers);
@@ -119,35 +141,92 @@ impl GsTransfer {
 
         let transfer_queue = self.device.logic.transfer_queue();
 
+        let _guard = self.device.logic.queue_guard();
         unsafe {
             self.device.logic.handle.queue_submit(transfer_queue.queue.handle, &[submit_info], self.fence.handle)
                 .or(Err(VkError::device("Failed to submit command to device.")))?
         };
+        self.is_pending = true;
+
+        Ok(())
+    }
+
+    /// Wait for the submitted commands to complete, and then free their command buffers.
+    pub fn wait(&mut self) -> VkResult<()> {
+
+        if self.is_pending == false {
+            return Ok(())
+        }
 
         self.fence.wait(self.transfer_wait_time)?;
-        transfer_queue.pool.free(&self.device, &self.command_buffers);
+        self.is_pending = false;
+
+        TransferCommandPool::free(&self.device, self.pool, &self.command_buffers);
         self.command_buffers.clear();
 
         Ok(())
     }
+
+    /// The fence that will be signaled when the submitted commands complete.
+    pub fn fence(&self) -> &GsFence {
+        &self.fence
+    }
 }
 
+impl Drop for GsTransfer {
+
+    fn drop(&mut self) {
+
+        // the command pool must not be reset while its command buffers are still executing.
+        if self.is_pending && self.wait().is_err() {
+            // leak the command pool rather than destroy it in use.
+            return
+        }
+
+        self.device.logic.transfer_queue().pool.recycle(&self.device.logic.handle, self.pool);
+    }
+}
+
+/// The command pools used to record transfer commands.
+///
+/// Since `vk::CommandPool` must be externally synchronized, each `GsTransfer` records its commands with its own command pool.
+/// The pools of dropped transfers are kept here and reused by the later transfers.
 struct TransferCommandPool {
 
-    handle: vk::CommandPool,
+    family_index: vkuint,
+    idle_handles: Mutex<Vec<vk::CommandPool>>,
 }
 
 impl TransferCommandPool {
 
     fn setup(device: &ash::Device, queue: &GsQueue) -> VkResult<TransferCommandPool> {
 
+        let pool = TransferCommandPool {
+            family_index: queue.family_index,
+            idle_handles: Mutex::new(Vec::new()),
+        };
+
+        // create a command pool in advance.
+        let handle = pool.acquire(device)?;
+        pool.idle_handles.lock().unwrap().push(handle);
+
+        Ok(pool)
+    }
+
+    /// Take an idle command pool, or create one if there is no idle pool.
+    fn acquire(&self, device: &ash::Device) -> VkResult<vk::CommandPool> {
+
+        if let Some(handle) = self.idle_handles.lock().unwrap().pop() {
+            return Ok(handle)
+        }
+
         let command_pool_ci = vk::CommandPoolCreateInfo {
             s_type: vk::StructureType::COMMAND_POOL_CREATE_INFO,
             p_next: ptr::null(),
             // TODO: Consider CommandPoolFlag::ResetCommandBufferBit.
             // the command buffer will be short-live, so use TransientBit.
             flags: vk::CommandPoolCreateFlags::TRANSIENT,
-            queue_family_index: queue.family_index,
+            queue_family_index: self.family_index,
         };
 
         let handle = unsafe {
@@ -155,16 +234,26 @@ impl TransferCommandPool {
                 .or(Err(VkError::create("Command Pool")))?
         };
 
-        let pool = TransferCommandPool { handle };
-        Ok(pool)
+        Ok(handle)
+    }
+
+    /// Reset the command pool of a finished transfer, and keep it for the later transfers.
+    fn recycle(&self, device: &ash::Device, handle: vk::CommandPool) {
+
+        unsafe {
+            match device.reset_command_pool(handle, vk::CommandPoolResetFlags::empty()) {
+                | Ok(_) => self.idle_handles.lock().unwrap().push(handle),
+                | Err(_) => device.destroy_command_pool(handle, None),
+            }
+        }
     }
 
-    fn allocate(&self, device: &GsDevice, count: usize) -> VkResult<Vec<GsCommandBuffer>> {
+    fn allocate(device: &GsDevice, pool: vk::CommandPool, count: usize) -> VkResult<Vec<GsCommandBuffer>> {
 
         let allocate_info = vk::CommandBufferAllocateInfo {
             s_type: vk::StructureType::COMMAND_BUFFER_ALLOCATE_INFO,
             p_next: ptr::null(),
-            command_pool: self.handle,
+            command_pool: pool,
             level: vk::CommandBufferLevel::PRIMARY,
             command_buffer_count: count as _,
         };
@@ -182,19 +271,23 @@ impl TransferCommandPool {
         Ok(buffers)
     }
 
-    fn free(&self, device: &GsDevice, buffers_to_free: &[GsCommandBuffer]) {
+    fn free(device: &GsDevice, pool: vk::CommandPool, buffers_to_free: &[GsCommandBuffer]) {
 
         let buffer_handles: Vec<vk::CommandBuffer> = collect_handle!(buffers_to_free);
 
         unsafe {
-            device.logic.handle.free_command_buffers(self.handle, &buffer_handles);
+            device.logic.handle.free_command_buffers(pool, &buffer_handles);
         }
     }
 
     fn discard(&self, device: &GsLogicalDevice) {
 
-        unsafe {
-            device.handle.destroy_command_pool(self.handle, None);
+        // all the transfers have been dropped before the device is destroyed.
+        let handles = self.idle_handles.lock().unwrap();
+        for &handle in handles.iter() {
+            unsafe {
+                device.handle.destroy_command_pool(handle, None);
+            }
         }
     }
 }
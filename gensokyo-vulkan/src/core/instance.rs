@@ -24,6 +24,8 @@ pub struct GsInstance {
     pub(crate) entry: ash::Entry,
     /// an array to store the names of vulkan layers enabled in instance creation.
     pub(crate) enable_layer_names: Vec<CString>,
+    /// the version of Vulkan requested in instance creation.
+    pub(crate) api_version: vkuint,
 }
 
 /// An enum type indicates all support extensions for `vk::Instance`.
@@ -84,6 +86,7 @@ impl GsInstance {
 
         let instance = GsInstance {
             entry, handle, enable_layer_names,
+            api_version: config.api_version,
         };
 
         Ok(instance)
@@ -10,10 +10,33 @@ pub mod swapchain;
 mod platforms;
 
 
-pub type GsDevice = ::std::rc::Rc<GsVirtualDevice>;
+/// The shared reference to Vulkan device, which is `Send + Sync` so that it can be passed to worker threads.
+pub type GsDevice = ::std::sync::Arc<GsVirtualDevice>;
 
 pub struct GsVirtualDevice {
 
     pub logic: self::device::GsLogicalDevice,
     pub phys : self::physical::GsPhysicalDevice,
 }
+
+/// Compile-time check that the engine objects can be shared between threads.
+#[allow(dead_code)]
+fn assert_thread_safety() {
+
+    use crate::buffer::allocator::{ GsBufferAllocator, GsBufferDistributor };
+    use crate::buffer::GsBufferRepository;
+    use crate::image::allocator::{ GsImageAllocator, GsImageDistributor };
+    use crate::image::GsImageRepository;
+    use crate::utils::phantom::{ Host, Device };
+
+    fn is_send_sync<T: Send + Sync>() {}
+
+    is_send_sync::<GsDevice>();
+    is_send_sync::<self::swapchain::GsChain>();
+    is_send_sync::<GsBufferAllocator<Host>>();
+    is_send_sync::<GsBufferDistributor<Host>>();
+    is_send_sync::<GsBufferRepository<Host>>();
+    is_send_sync::<GsImageAllocator<Device>>();
+    is_send_sync::<GsImageDistributor<Device>>();
+    is_send_sync::<GsImageRepository<Device>>();
+}
@@ -8,6 +8,8 @@ use crate::utils::cast;
 use crate::error::{ VkResult, VkError };
 
 use std::ffi::CString;
+use std::os::raw::c_void;
+use std::ptr;
 
 pub(crate) struct PhysicalExtension {
 
@@ -18,6 +20,18 @@ pub(crate) struct PhysicalExtension {
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum DeviceExtensionType {
     Swapchain,
+    /// VK_EXT_index_type_uint8, allow the use of 8-bit index buffer.
+    IndexTypeUint8,
+    /// VK_KHR_maintenance2, allow the image views to have usages unsupported by the image format(`vk::ImageCreateFlags::EXTENDED_USAGE`).
+    ///
+    /// It's required by `MipmapMethod::ComputeDownsample` for sRGB formats.
+    Maintenance2,
+    /// VK_KHR_maintenance3, required by VK_EXT_descriptor_indexing.
+    Maintenance3,
+    /// VK_EXT_descriptor_indexing, allow the use of partially bound and update-after-bind descriptor arrays.
+    ///
+    /// VK_KHR_maintenance3 is enabled together with this extension.
+    DescriptorIndexing,
 }
 
 impl DeviceExtensionType {
@@ -29,10 +43,51 @@ impl DeviceExtensionType {
                 // ash::extensions::Swapchain::name()
                 CString::new("VK_KHR_swapchain").unwrap()
             },
+            | DeviceExtensionType::IndexTypeUint8 => {
+                CString::new("VK_EXT_index_type_uint8").unwrap()
+            },
+            | DeviceExtensionType::Maintenance2 => {
+                CString::new("VK_KHR_maintenance2").unwrap()
+            },
+            | DeviceExtensionType::Maintenance3 => {
+                CString::new("VK_KHR_maintenance3").unwrap()
+            },
+            | DeviceExtensionType::DescriptorIndexing => {
+                CString::new("VK_EXT_descriptor_indexing").unwrap()
+            },
+        }
+    }
+
+    /// The other device extensions which must be enabled together with this extension.
+    fn dependencies(&self) -> &'static [DeviceExtensionType] {
+        match self {
+            | DeviceExtensionType::DescriptorIndexing => &[DeviceExtensionType::Maintenance3],
+            | _ => &[],
         }
     }
 }
 
+impl PhysicalExtensionConfig {
+
+    /// Return the names of the required extensions, including their dependencies.
+    fn require_extension_names(&self) -> Vec<CString> {
+
+        let mut names: Vec<CString> = Vec::with_capacity(self.require_extensions.len());
+
+        for extension in self.require_extensions.iter() {
+            for dependency in extension.dependencies().iter().chain(Some(extension)) {
+
+                let name = dependency.name();
+                if names.contains(&name) == false {
+                    names.push(name);
+                }
+            }
+        }
+
+        names
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PhysicalExtensionConfig {
 
@@ -60,6 +115,33 @@ impl PhysicalExtension {
 
         &self.enable_extensions
     }
+
+    pub fn is_enable(&self, extension: DeviceExtensionType) -> bool {
+
+        self.enable_extensions.contains(&extension.name())
+    }
+}
+
+/// The mirror of `VkPhysicalDeviceIndexTypeUint8FeaturesEXT`, which is not provided by ash yet.
+#[repr(C)]
+pub(crate) struct IndexTypeUint8FeaturesEXT {
+
+    s_type: vk::StructureType,
+    pub p_next: *mut c_void,
+    index_type_uint8: vk::Bool32,
+}
+
+impl IndexTypeUint8FeaturesEXT {
+
+    pub fn enable() -> IndexTypeUint8FeaturesEXT {
+
+        IndexTypeUint8FeaturesEXT {
+            // VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT.
+            s_type: vk::StructureType::from_raw(1_000_265_000),
+            p_next: ptr::null_mut(),
+            index_type_uint8: vk::TRUE,
+        }
+    }
 }
 
 impl PhysicalInspectProperty for PhysicalExtension {
@@ -69,8 +151,7 @@ impl PhysicalInspectProperty for PhysicalExtension {
 
         if config.require_extensions.is_empty() { return true }
 
-        let require_extension_names: Vec<CString> = config.require_extensions.iter()
-            .map(|e| e.name()).collect();
+        let require_extension_names = config.require_extension_names();
         let available_extensions: Vec<CString> = self.handles.iter()
             .map(|e| cast::chars2cstring(&e.extension_name)).collect();
 
@@ -84,7 +165,6 @@ impl PhysicalInspectProperty for PhysicalExtension {
 
     fn set(&mut self, config: &Self::ConfigType) {
 
-        self.enable_extensions = config.require_extensions.iter()
-            .map(|e| e.name()).collect();
+        self.enable_extensions = config.require_extension_names();
     }
 }
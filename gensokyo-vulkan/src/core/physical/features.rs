@@ -1,39 +1,178 @@
 
 use ash::vk;
-use ash::version::InstanceV1_0;
+use ash::vk_make_version;
+use ash::version::{ InstanceV1_0, InstanceV1_1 };
 
 use crate::core::instance::GsInstance;
 use crate::core::physical::config::PhysicalInspectProperty;
+use crate::types::vkuint;
+
+use std::cmp::min;
+use std::ptr;
 
 pub(crate) struct PhysicalFeatures {
 
     availables: vk::PhysicalDeviceFeatures,
     enables   : vk::PhysicalDeviceFeatures,
+
+    indexing_availables: DescriptorIndexingFeatures,
+    indexing_enables   : DescriptorIndexingFeatures,
+    /// the max number of update-after-bind sampled images in a descriptor set, or 0 if descriptor indexing is not queried.
+    indexing_max_sampled_images: vkuint,
 }
 
 #[derive(Debug, Clone)]
 pub struct PhysicalFeatureConfig {
 
     pub require_features: vk::PhysicalDeviceFeatures,
+    /// Require the features of VK_EXT_descriptor_indexing used by bindless texture array.
+    ///
+    /// The VK_EXT_descriptor_indexing extension must be required at the same time,
+    /// and the `api_version` of instance must be 1.1 or higher to query these features.
+    pub require_descriptor_indexing: bool,
+}
+
+/// The subset of `vk::PhysicalDeviceDescriptorIndexingFeaturesEXT` used by bindless texture array.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct DescriptorIndexingFeatures {
+
+    shader_sampled_image_array_non_uniform_indexing: vk::Bool32,
+    descriptor_binding_sampled_image_update_after_bind: vk::Bool32,
+    descriptor_binding_update_unused_while_pending: vk::Bool32,
+    descriptor_binding_partially_bound: vk::Bool32,
+    descriptor_binding_variable_descriptor_count: vk::Bool32,
+    runtime_descriptor_array: vk::Bool32,
 }
 
 impl PhysicalFeatures {
 
-    pub fn query(instance: &GsInstance, physical_device: vk::PhysicalDevice) -> PhysicalFeatures {
+    pub fn query(instance: &GsInstance, physical_device: vk::PhysicalDevice, config: &PhysicalFeatureConfig) -> PhysicalFeatures {
 
         let available_features = unsafe {
             instance.handle.get_physical_device_features(physical_device)
         };
 
+        // vkGetPhysicalDeviceFeatures2 is only available in Vulkan 1.1, so query it only when it is required.
+        // the api version of instance has been checked by `PhysicalInspector`, and the devices below Vulkan 1.1 are skipped here.
+        let device_api_version = unsafe {
+            instance.handle.get_physical_device_properties(physical_device).api_version
+        };
+
+        let (indexing_availables, indexing_max_sampled_images) = if config.require_descriptor_indexing && device_api_version >= vk_make_version!(1, 1, 0) {
+            (DescriptorIndexingFeatures::query(instance, physical_device), query_indexing_max_sampled_images(instance, physical_device))
+        } else {
+            (DescriptorIndexingFeatures::default(), 0)
+        };
+
         PhysicalFeatures {
             availables: available_features,
             enables: Default::default(),
+            indexing_availables,
+            indexing_enables: Default::default(),
+            indexing_max_sampled_images,
         }
     }
 
     pub fn enable_features(&self) -> &vk::PhysicalDeviceFeatures {
         &self.enables
     }
+
+    /// Return the descriptor indexing features to chain in `vk::DeviceCreateInfo`, or None if they are not required.
+    pub fn enable_indexing_features(&self) -> Option<vk::PhysicalDeviceDescriptorIndexingFeaturesEXT> {
+
+        if self.is_descriptor_indexing_enable() {
+            Some(self.indexing_enables.to_vk())
+        } else {
+            None
+        }
+    }
+
+    pub fn is_descriptor_indexing_enable(&self) -> bool {
+        self.indexing_enables.is_all_support()
+    }
+
+    pub fn indexing_max_sampled_images(&self) -> vkuint {
+        self.indexing_max_sampled_images
+    }
+}
+
+/// Query the max number of sampled images in an update-after-bind descriptor set, which is also limited by the number in one shader stage.
+fn query_indexing_max_sampled_images(instance: &GsInstance, physical_device: vk::PhysicalDevice) -> vkuint {
+
+    let mut indexing_properties = vk::PhysicalDeviceDescriptorIndexingPropertiesEXT {
+        s_type: vk::StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES_EXT,
+        p_next: ptr::null_mut(),
+        ..Default::default()
+    };
+
+    let mut properties2 = vk::PhysicalDeviceProperties2 {
+        s_type: vk::StructureType::PHYSICAL_DEVICE_PROPERTIES_2,
+        p_next: &mut indexing_properties as *mut vk::PhysicalDeviceDescriptorIndexingPropertiesEXT as _,
+        properties: Default::default(),
+    };
+
+    unsafe {
+        instance.handle.get_physical_device_properties2(physical_device, &mut properties2);
+    }
+
+    min(indexing_properties.max_descriptor_set_update_after_bind_sampled_images,
+        indexing_properties.max_per_stage_descriptor_update_after_bind_sampled_images)
+}
+
+impl DescriptorIndexingFeatures {
+
+    fn query(instance: &GsInstance, physical_device: vk::PhysicalDevice) -> DescriptorIndexingFeatures {
+
+        let mut indexing_features = vk::PhysicalDeviceDescriptorIndexingFeaturesEXT {
+            s_type: vk::StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT,
+            p_next: ptr::null_mut(),
+            ..Default::default()
+        };
+
+        let mut features2 = vk::PhysicalDeviceFeatures2 {
+            s_type: vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            p_next: &mut indexing_features as *mut vk::PhysicalDeviceDescriptorIndexingFeaturesEXT as _,
+            features: Default::default(),
+        };
+
+        unsafe {
+            instance.handle.get_physical_device_features2(physical_device, &mut features2);
+        }
+
+        DescriptorIndexingFeatures {
+            shader_sampled_image_array_non_uniform_indexing   : indexing_features.shader_sampled_image_array_non_uniform_indexing,
+            descriptor_binding_sampled_image_update_after_bind: indexing_features.descriptor_binding_sampled_image_update_after_bind,
+            descriptor_binding_update_unused_while_pending    : indexing_features.descriptor_binding_update_unused_while_pending,
+            descriptor_binding_partially_bound                : indexing_features.descriptor_binding_partially_bound,
+            descriptor_binding_variable_descriptor_count      : indexing_features.descriptor_binding_variable_descriptor_count,
+            runtime_descriptor_array                          : indexing_features.runtime_descriptor_array,
+        }
+    }
+
+    fn is_all_support(&self) -> bool {
+
+        self.shader_sampled_image_array_non_uniform_indexing == vk::TRUE
+            && self.descriptor_binding_sampled_image_update_after_bind == vk::TRUE
+            && self.descriptor_binding_update_unused_while_pending == vk::TRUE
+            && self.descriptor_binding_partially_bound == vk::TRUE
+            && self.descriptor_binding_variable_descriptor_count == vk::TRUE
+            && self.runtime_descriptor_array == vk::TRUE
+    }
+
+    fn to_vk(&self) -> vk::PhysicalDeviceDescriptorIndexingFeaturesEXT {
+
+        vk::PhysicalDeviceDescriptorIndexingFeaturesEXT {
+            s_type: vk::StructureType::PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES_EXT,
+            p_next: ptr::null_mut(),
+            shader_sampled_image_array_non_uniform_indexing   : self.shader_sampled_image_array_non_uniform_indexing,
+            descriptor_binding_sampled_image_update_after_bind: self.descriptor_binding_sampled_image_update_after_bind,
+            descriptor_binding_update_unused_while_pending    : self.descriptor_binding_update_unused_while_pending,
+            descriptor_binding_partially_bound                : self.descriptor_binding_partially_bound,
+            descriptor_binding_variable_descriptor_count      : self.descriptor_binding_variable_descriptor_count,
+            runtime_descriptor_array                          : self.runtime_descriptor_array,
+            ..Default::default()
+        }
+    }
 }
 
 //impl PhysicalInspectProperty for PhyscialFeatures {
@@ -73,12 +212,20 @@ macro_rules! impl_physical_features {
                     }
                 )*
 
+                if config.require_descriptor_indexing && self.indexing_availables.is_all_support() == false {
+                    return false
+                }
+
                 true
             }
 
             fn set(&mut self, config: &Self::ConfigType) {
 
                 self.enables = config.require_features;
+
+                if config.require_descriptor_indexing {
+                    self.indexing_enables = self.indexing_availables;
+                }
             }
         }
     };
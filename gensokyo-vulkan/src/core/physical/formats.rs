@@ -47,7 +47,6 @@ impl PhysicalFormats {
         Ok(format_properties.optimal_tiling_features.contains(query_optimal))
     }
 
-    #[allow(dead_code)]
     pub fn query_format_buffers(&self, format: Format, query_buffers: vk::FormatFeatureFlags) -> VkResult<bool> {
 
         let format_properties = self.query_format(format)?;
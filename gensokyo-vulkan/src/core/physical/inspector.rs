@@ -1,4 +1,5 @@
 
+use ash::vk_make_version;
 use ash::version::InstanceV1_0;
 
 use crate::core::instance::GsInstance;
@@ -31,6 +32,11 @@ impl PhysicalInspector {
 
     pub fn inspect(&self, instance: &GsInstance, surface: &GsSurface) -> VkResult<GsPhysicalDevice> {
 
+        // the descriptor indexing features are queried by `vkGetPhysicalDeviceFeatures2`, which is only available in Vulkan 1.1.
+        if self.config.features.require_descriptor_indexing && instance.api_version < vk_make_version!(1, 1, 0) {
+            return Err(VkError::other("VK_EXT_descriptor_indexing requires the api version of instance to be 1.1 or higher."))
+        }
+
         let alternative_devices = unsafe {
             instance.handle.enumerate_physical_devices()
                 .or(Err(VkError::query("Physical Device")))?
@@ -54,7 +60,7 @@ impl PhysicalInspector {
                 continue
             }
 
-            let mut features = PhysicalFeatures::query(instance, physical_device);
+            let mut features = PhysicalFeatures::query(instance, physical_device, &self.config.features);
             if features.inspect(&self.config.features) {
                 features.set(&self.config.features)
             } else {
@@ -20,3 +20,5 @@ mod extension;
 mod formats;
 
 mod config;
+
+pub(crate) use self::extension::IndexTypeUint8FeaturesEXT;
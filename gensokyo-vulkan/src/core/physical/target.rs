@@ -8,6 +8,8 @@ use crate::core::physical::extension::PhysicalExtension;
 use crate::core::physical::memory::PhysicalMemory;
 use crate::core::physical::formats::PhysicalFormats;
 
+use crate::types::vkuint;
+
 pub struct GsPhysicalDevice {
 
     pub(crate) handle: vk::PhysicalDevice,
@@ -26,6 +28,37 @@ impl GsPhysicalDevice {
         &self.properties.handle.limits
     }
 
+    /// Return the largest sample count not greater than `desired`, which is supported by both color and depth attachments of framebuffer.
+    pub fn clamp_sample_count(&self, desired: vk::SampleCountFlags) -> vk::SampleCountFlags {
+
+        let supported_counts = self.limits().framebuffer_color_sample_counts & self.limits().framebuffer_depth_sample_counts;
+
+        let candidates = [
+            vk::SampleCountFlags::TYPE_64,
+            vk::SampleCountFlags::TYPE_32,
+            vk::SampleCountFlags::TYPE_16,
+            vk::SampleCountFlags::TYPE_8,
+            vk::SampleCountFlags::TYPE_4,
+            vk::SampleCountFlags::TYPE_2,
+        ];
+
+        candidates.iter()
+            .filter(|&&count| count.as_raw() <= desired.as_raw())
+            .find(|&&count| supported_counts.contains(count))
+            .cloned()
+            .unwrap_or(vk::SampleCountFlags::TYPE_1)
+    }
+
+    /// Check if the features of VK_EXT_descriptor_indexing required by bindless texture array are enabled.
+    pub fn is_descriptor_indexing_enable(&self) -> bool {
+        self.features.is_descriptor_indexing_enable()
+    }
+
+    /// Return the max number of sampled images in an update-after-bind descriptor set, or 0 if descriptor indexing is not required.
+    pub fn indexing_max_sampled_images(&self) -> vkuint {
+        self.features.indexing_max_sampled_images()
+    }
+
     pub fn discard(&self) {
         // No method for delete physical device.
         // leave it empty
@@ -16,6 +16,7 @@ use crate::types::{ vkuint, vklint, vkDim2D };
 use crate::types::format::Format;
 
 use std::ptr;
+use std::sync::Mutex;
 
 /// Wrapper class for `vk::SwapchainKHR` object.
 pub struct GsSwapchain {
@@ -31,7 +32,9 @@ pub struct GsSwapchain {
     #[allow(dead_code)]
     images: Vec<GsImage>,
     /// the corresponding image views associated with the presentable images created by swapchain.
-    views: Vec<GsImageView>,
+    ///
+    /// They are taken out in `discard` method, since they must be destroyed before the swapchain.
+    views: Mutex<Vec<GsImageView>>,
     /// the format of presentable images.
     format: Format,
     /// the dimension of presentable images.
@@ -68,7 +71,8 @@ impl GsSwapchain {
         }
 
         let result = GsSwapchain {
-            handle, loader, images, views, format, extent, image_count, image_acquire_time
+            handle, loader, images, format, extent, image_count, image_acquire_time,
+            views: Mutex::new(views),
         };
         Ok(result)
     }
@@ -129,6 +133,7 @@ impl GsSwapchain {
             p_results           : ptr::null_mut(),
         };
 
+        let _guard = device.logic.queue_guard();
         let is_sub_optimal = unsafe {
             self.loader.queue_present(device.logic.queue_handle_by_identifier(queue).handle, &present_info)
                 .or(Err(VkError::swapchain_sync(SwapchainSyncError::Unknown)))?
@@ -144,10 +149,12 @@ impl GsSwapchain {
     /// Destroy the `vk::SwapchainKHR` object.
     ///
     /// The application must not destroy `vk::SwapchainKHR` until after completion of all outstanding operations on images that were acquired from the `vk::SwapchainKHR`.
-    pub fn discard(&self, device: &GsDevice) {
+    pub fn discard(&self) {
 
-        // destroy all the presentable images created by this swapchain.
-        self.views.iter().for_each(|v| v.discard(device));
+        // the image views of presentable images must be destroyed before the images, which are owned by swapchain.
+        for view in self.views.lock().unwrap().drain(..) {
+            view.destroy_immediately();
+        }
 
         // destroy the swapchain itself.
         unsafe {
@@ -155,6 +162,18 @@ impl GsSwapchain {
         }
     }
 
+    /// Recreate the framebuffers, depth attachments and fixed-viewport pipelines which were built for the previous swapchain on `device`.
+    ///
+    /// Call this function after this swapchain replaces the previous one, while the device is idle.
+    pub fn resize_dependents(&self, device: &GsDevice) -> VkResult<()> {
+
+        for dependent in device.logic.swapchain_dependents().iter() {
+            dependent.resize(device, self)?;
+        }
+
+        Ok(())
+    }
+
     // TODO: Remove the following function.
     pub fn format(&self) -> Format {
         self.format
@@ -170,7 +189,7 @@ impl GsSwapchain {
     }
     /// Get the handle of specific image view of swapchain.
     pub fn view_at(&self, index: usize) -> vk::ImageView {
-        self.views[index].handle
+        self.views.lock().unwrap()[index].handle
     }
 }
 
@@ -0,0 +1,51 @@
+
+use crate::core::GsDevice;
+use crate::core::swapchain::GsSwapchain;
+use crate::error::VkResult;
+
+use std::sync::{ Arc, Weak };
+
+/// The object whose content depends on the dimension of swapchain, such as the framebuffers built for swapchain images.
+pub(crate) trait SwapchainDependent: Send + Sync {
+
+    /// Recreate the content of this object for the recreated `chain`.
+    ///
+    /// This function is called when the device is idle.
+    fn resize(&self, device: &GsDevice, chain: &GsSwapchain) -> VkResult<()>;
+}
+
+/// The objects depending on the dimension of swapchain, which are created on a device.
+///
+/// The objects are tracked by weak references, so that they stop being tracked once their owners drop them.
+pub(crate) struct GsSwapchainDependents {
+
+    dependents: Vec<Weak<dyn SwapchainDependent>>,
+}
+
+impl GsSwapchainDependents {
+
+    pub fn new() -> GsSwapchainDependents {
+        GsSwapchainDependents { dependents: vec![] }
+    }
+
+    pub fn track(&mut self, dependent: Weak<dyn SwapchainDependent>) {
+        self.dependents.push(dependent);
+    }
+
+    /// Return the objects still in use, and stop tracking the dropped ones.
+    pub fn alive(&mut self) -> Vec<Arc<dyn SwapchainDependent>> {
+
+        let mut alive = Vec::with_capacity(self.dependents.len());
+        self.dependents.retain(|dependent| {
+            match dependent.upgrade() {
+                | Some(dependent) => {
+                    alive.push(dependent);
+                    true
+                },
+                | None => false,
+            }
+        });
+
+        alive
+    }
+}
@@ -1,10 +1,12 @@
 
-use std::rc::Rc;
-pub type GsChain = Rc<self::chain::GsSwapchain>;
+use std::sync::Arc;
+pub type GsChain = Arc<self::chain::GsSwapchain>;
 
 pub use self::chain::{ GsSwapchain, SwapchainConfig, SwapchainSyncError };
 pub use self::builder::SwapchainBuilder;
+pub(crate) use self::dependent::{ SwapchainDependent, GsSwapchainDependents };
 
 mod chain;
 mod builder;
 mod support;
+mod dependent;
@@ -55,6 +55,7 @@ impl GsAllotIntoDistributor<GsDescriptorDistributor> for GsDescriptorAllocator {
         for pool_size in pool_sizes.iter() {
             pool_info.add_pool_size(pool_size.0, pool_size.1);
         }
+        pool_info.set_pool_size_max(self.set_configs.len() as _);
         let pool = pool_info.build(&self.device)?;
 
         // descriptor layout
@@ -23,10 +23,19 @@ pub struct DescriptorBindingBufInfo {
     pub element_size: vkbytes,
     /// the handle of buffer where the descriptor data stores.
     pub buffer_handle: vk::Buffer,
+    /// the buffer view for texel buffer descriptor. Set it to None for other buffer descriptor.
+    pub texel_view: Option<vk::BufferView>,
+}
+
+/// The data referred by the `vk::WriteDescriptorSet` of buffer descriptor.
+pub struct DescriptorBufWriteContent {
+
+    buffers: Vec<vk::DescriptorBufferInfo>,
+    texel_views: Vec<vk::BufferView>,
 }
 
 impl DescriptorBindingCI for DescriptorBindingBufInfo {
-    type DescriptorWriteType = VKWrapperPair<Vec<vk::DescriptorBufferInfo>, vk::WriteDescriptorSet>;
+    type DescriptorWriteType = VKWrapperPair<DescriptorBufWriteContent, vk::WriteDescriptorSet>;
 
     fn meta_mirror(&self) -> DescriptorMetaMirror {
         self.meta.clone().into()
@@ -34,14 +43,24 @@ impl DescriptorBindingCI for DescriptorBindingBufInfo {
 
     fn write_info(&self, set: &GsDescriptorSet) -> Self::DescriptorWriteType {
 
-        let contents = vec![
-            vk::DescriptorBufferInfo {
-                buffer: self.buffer_handle,
-                offset: 0,
-                // TODO: check maxUniformBufferRange or maxStorageBufferRange in physical device limit.
-                range: self.element_size,
-            }
-        ];
+        let contents = DescriptorBufWriteContent {
+            buffers: vec![
+                vk::DescriptorBufferInfo {
+                    buffer: self.buffer_handle,
+                    offset: 0,
+                    // TODO: check maxUniformBufferRange or maxStorageBufferRange in physical device limit.
+                    range: self.element_size,
+                }
+            ],
+            texel_views: self.texel_view.iter().cloned().collect(),
+        };
+
+        // texel buffer descriptor reads p_texel_buffer_view, and other buffer descriptor reads p_buffer_info.
+        let (p_buffer_info, p_texel_buffer_view) = if contents.texel_views.is_empty() {
+            (contents.buffers.as_ptr(), ptr::null())
+        } else {
+            (ptr::null(), contents.texel_views.as_ptr())
+        };
 
         let write_set = vk::WriteDescriptorSet {
             s_type: vk::StructureType::WRITE_DESCRIPTOR_SET,
@@ -52,8 +71,8 @@ impl DescriptorBindingCI for DescriptorBindingBufInfo {
             descriptor_count   : 1,
             descriptor_type    : self.meta.descriptor_type.into(),
             p_image_info       : ptr::null(),
-            p_buffer_info      : contents.as_ptr(),
-            p_texel_buffer_view: ptr::null(),
+            p_buffer_info,
+            p_texel_buffer_view,
         };
 
         VKWrapperPair {
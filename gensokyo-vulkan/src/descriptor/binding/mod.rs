@@ -1,6 +1,6 @@
 
-pub use self::traits::{ DescriptorBindingCI, DescriptorMeta, DescriptorArrayMeta };
-pub use self::buffer::{ DescriptorBindingBufTgt, DescriptorBindingBufInfo };
+pub use self::traits::{ DescriptorBindingCI, DescriptorMeta, DescriptorArrayMeta, DescriptorMetaMirror };
+pub use self::buffer::{ DescriptorBindingBufTgt, DescriptorBindingBufInfo, DescriptorBufWriteContent };
 pub use self::image::{ DescriptorBindingImgTgt, DescriptorBindingImgInfo };
 pub use self::image::{ DescriptorBindingImgArrayTgt, DescriptorBindingImgArrayInfo, ImgArrayBinding };
 
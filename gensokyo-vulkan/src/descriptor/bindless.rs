@@ -0,0 +1,177 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+
+use crate::descriptor::pool::GsDescriptorPool;
+use crate::descriptor::layout::GsDescriptorSetLayout;
+use crate::descriptor::set::{ GsDescriptorSet, DescriptorSet };
+use crate::descriptor::types::{ GsDescriptorType, ImageDescriptorType };
+use crate::descriptor::binding::{ DescriptorBindingCI, DescriptorBindingImgTgt, DescriptorMetaMirror };
+
+use crate::pipeline::target::GsPipelineStage;
+use crate::error::{ VkResult, VkError };
+use crate::types::vkuint;
+
+/// The binding index of the texture array in the descriptor set of `BindlessTextureTable`.
+pub const BINDLESS_TEXTURE_BINDING: vkuint = 0;
+
+const BINDLESS_DESCRIPTOR_TYPE: GsDescriptorType = GsDescriptorType::Image(ImageDescriptorType::CombinedImageSampler);
+
+/// A large array of combined image samplers in a single descriptor set, indexed by shader at runtime.
+///
+/// Each registered texture is assigned a stable index in the array, which is usually passed to shader through push constants,
+/// so that all the materials can share one descriptor set, like the following example shader snippet:
+///
+/// layout (set = 1, binding = 0) uniform sampler2D textures[];
+///
+/// layout (push_constant) uniform Material { uint albedo_index; } material;
+///
+/// vec4 albedo = texture(textures[nonuniformEXT(material.albedo_index)], uv);
+///
+/// The binding is partially bound and update-after-bind, so textures can be registered while the set is bound in pending command buffers.
+/// It requires the VK_EXT_descriptor_indexing extension and its features to be enabled on device.
+pub struct BindlessTextureTable {
+
+    device: GsDevice,
+    pool  : GsDescriptorPool,
+    set   : DescriptorSet,
+
+    /// the number of descriptors in the array.
+    capacity: vkuint,
+    /// the first index that has never been assigned.
+    next_index: vkuint,
+    /// the indices released by `release` method, which are reused before `next_index`.
+    free_indices: Vec<vkuint>,
+}
+
+impl BindlessTextureTable {
+
+    /// Create a texture table with room for `capacity` textures, whose `set` value in shader code is `set_index`.
+    ///
+    /// `capacity` must be greater than 0, and not exceed `maxDescriptorSetUpdateAfterBindSampledImages` limit of the device.
+    pub fn create(device: &GsDevice, capacity: vkuint, set_index: usize, stage: GsPipelineStage) -> VkResult<BindlessTextureTable> {
+
+        if device.phys.is_descriptor_indexing_enable() == false {
+            return Err(VkError::unsupported("Descriptor Indexing"))
+        }
+
+        let max_capacity = device.phys.indexing_max_sampled_images();
+        if capacity == 0 || capacity > max_capacity {
+            return Err(VkError::other(format!("The capacity({}) of bindless texture table must be in range 1..={}.", capacity, max_capacity)))
+        }
+
+        let mut pool_info = GsDescriptorPool::new(vk::DescriptorPoolCreateFlags::UPDATE_AFTER_BIND_EXT);
+        pool_info.add_pool_size(BINDLESS_DESCRIPTOR_TYPE, capacity);
+        pool_info.set_pool_size_max(1);
+        let pool = pool_info.build(device)?;
+
+        let mut layout_info = GsDescriptorSetLayout::new(1);
+        layout_info.set_flags(vk::DescriptorSetLayoutCreateFlags::UPDATE_AFTER_BIND_POOL_EXT);
+
+        let binding_meta = DescriptorMetaMirror {
+            binding: BINDLESS_TEXTURE_BINDING,
+            count  : capacity,
+            descriptor_type: BINDLESS_DESCRIPTOR_TYPE,
+        };
+        let binding_flags = vk::DescriptorBindingFlagsEXT::PARTIALLY_BOUND
+            | vk::DescriptorBindingFlagsEXT::VARIABLE_DESCRIPTOR_COUNT
+            | vk::DescriptorBindingFlagsEXT::UPDATE_AFTER_BIND
+            | vk::DescriptorBindingFlagsEXT::UPDATE_UNUSED_WHILE_PENDING;
+        layout_info.add_indexing_binding(binding_meta, stage.0, binding_flags);
+
+        let layout = layout_info.build(device)?;
+
+        let handle = match pool.allocate_variable(device, &layout, capacity) {
+            | Ok(handle) => handle,
+            | Err(e) => {
+                layout.discard(device);
+                return Err(e)
+            },
+        };
+
+        let set = DescriptorSet::new(&GsDescriptorSet::new(handle, layout), set_index);
+
+        let table = BindlessTextureTable {
+            device: device.clone(),
+            pool, set, capacity,
+            next_index: 0,
+            free_indices: Vec::new(),
+        };
+        Ok(table)
+    }
+
+    /// Write `texture` to a free slot of the table, and return its index in the texture array.
+    ///
+    /// The index stays valid until it is released.
+    pub fn register(&mut self, texture: &impl DescriptorBindingImgTgt) -> VkResult<vkuint> {
+
+        let index = if let Some(index) = self.free_indices.pop() {
+            index
+        } else if self.next_index < self.capacity {
+            self.next_index += 1;
+            self.next_index - 1
+        } else {
+            return Err(VkError::other(format!("Bindless texture table is full (capacity: {}).", self.capacity)))
+        };
+
+        if let Err(e) = self.replace(index, texture) {
+            self.free_indices.push(index);
+            return Err(e)
+        }
+
+        Ok(index)
+    }
+
+    /// Point the slot at `index` to `texture`.
+    ///
+    /// The slot must not be accessed by the pending command buffers.
+    pub fn replace(&self, index: vkuint, texture: &impl DescriptorBindingImgTgt) -> VkResult<()> {
+
+        if index >= self.next_index {
+            return Err(VkError::other(format!("Bindless texture index {} has not been assigned.", index)))
+        }
+
+        let info = texture.binding_info();
+        if info.meta.descriptor_type != BINDLESS_DESCRIPTOR_TYPE {
+            return Err(VkError::other(format!("Bindless texture table only accepts combined image sampler, but found {:?}.", info.meta.descriptor_type)))
+        }
+
+        let write_set = GsDescriptorSet::new(self.set.handle, self.set.layout.clone());
+        let mut write_pair = info.write_info(&write_set);
+        write_pair.info.dst_binding = BINDLESS_TEXTURE_BINDING;
+        write_pair.info.dst_array_element = index;
+
+        self.device.logic.update_descriptor_sets(&vec![write_pair.info]);
+
+        Ok(())
+    }
+
+    /// Return the slot at `index` to the table, so that it can be assigned to another texture.
+    ///
+    /// Release the index only after the frames referencing it have completed.
+    pub fn release(&mut self, index: vkuint) {
+
+        debug_assert!(index < self.next_index);
+        debug_assert!(self.free_indices.contains(&index) == false);
+
+        self.free_indices.push(index);
+    }
+
+    /// The descriptor set containing the texture array, which is bound by `bind_descriptor_sets`.
+    pub fn descriptor_set(&self) -> &DescriptorSet {
+        &self.set
+    }
+
+    pub fn capacity(&self) -> vkuint {
+        self.capacity
+    }
+}
+
+impl Drop for BindlessTextureTable {
+
+    // the pool is released in its drop func.
+    fn drop(&mut self) {
+        self.set.layout.discard(&self.device);
+    }
+}
@@ -0,0 +1,190 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+
+use crate::descriptor::pool::GsDescriptorPool;
+use crate::descriptor::set::{ GsDescriptorSet, DescriptorSet, DescriptorSetConfig };
+use crate::descriptor::layout::GsDescriptorSetLayout;
+use crate::descriptor::types::GsDescriptorType;
+
+use crate::utils::wrapper::VKWrapperInfo;
+use crate::error::{ VkResult, VkError };
+use crate::types::vkuint;
+
+use std::collections::HashMap;
+
+/// A growable allocator of descriptor sets, which creates a new descriptor pool whenever the existing pools are exhausted.
+///
+/// Unlike `GsDescriptorAllocator`, the descriptor sets can be allocated at any time from an existing `GsDescriptorSetLayout`,
+/// such as when new materials are loaded during the running of a scene.
+///
+/// The chain does not own the layouts of its descriptor sets, so the layouts must outlive the sets allocated from them.
+pub struct GsDescriptorPoolChain {
+
+    device: GsDevice,
+    flags : vk::DescriptorPoolCreateFlags,
+
+    /// the number of descriptor sets each pool can hold.
+    sets_per_pool: vkuint,
+    /// the number of descriptors of each type required by a descriptor set in average.
+    set_ratios: HashMap<GsDescriptorType, vkuint>,
+
+    pools: Vec<GsDescriptorPool>,
+    /// the index of the first pool that may still have free space.
+    active: usize,
+    /// record which pool each descriptor set is allocated from.
+    owners: HashMap<vk::DescriptorSet, usize>,
+}
+
+impl GsDescriptorPoolChain {
+
+    pub fn create(device: &GsDevice, sets_per_pool: vkuint) -> GsDescriptorPoolChain {
+
+        GsDescriptorPoolChain {
+            device: device.clone(),
+            flags : vk::DescriptorPoolCreateFlags::empty(),
+
+            sets_per_pool,
+            set_ratios: HashMap::new(),
+
+            pools : vec![],
+            active: 0,
+            owners: HashMap::new(),
+        }
+    }
+
+    /// Set the flags of the pools created later.
+    ///
+    /// `vk::DescriptorPoolCreateFlags::FREE_DESCRIPTOR_SET` is required to return individual set by `free` method.
+    pub fn with_flags(&mut self, flags: vk::DescriptorPoolCreateFlags) {
+        self.flags = flags;
+    }
+
+    /// Reserve `count` descriptors of `desc_type` for each descriptor set in the pools created later.
+    pub fn add_pool_ratio(&mut self, desc_type: GsDescriptorType, count: vkuint) {
+
+        let ratio = self.set_ratios.entry(desc_type).or_insert(0);
+        *ratio += count;
+    }
+
+    /// Reserve the descriptors required by the sets of `config` in the pools created later.
+    ///
+    /// If several configs are added, each set reserves the descriptors of all of them,
+    /// so it is better to add the config that requires the most descriptors only.
+    pub fn add_config_ratio(&mut self, config: &DescriptorSetConfig) {
+        config.add_pool_size(&mut self.set_ratios);
+    }
+
+    /// Allocate a descriptor set of `layout`, whose `set` value in shader code is `set_index`.
+    pub fn allocate(&mut self, layout: &GsDescriptorSetLayout, set_index: usize) -> VkResult<DescriptorSet> {
+
+        while self.active < self.pools.len() {
+
+            match self.pools[self.active].allocate_single(&self.device, layout) {
+                | Ok(handle) => {
+                    return Ok(self.record_set(handle, layout, set_index))
+                },
+                | Err(vk::Result::ERROR_OUT_OF_POOL_MEMORY)
+                | Err(vk::Result::ERROR_FRAGMENTED_POOL) => {
+                    // try the next pool.
+                    self.active += 1;
+                },
+                | Err(_) => {
+                    return Err(VkError::device("Failed to allocate Descriptor Set."))
+                },
+            }
+        }
+
+        // all pools are exhausted, so chain a new pool.
+        let pool = self.create_pool()?;
+        let handle = pool.allocate_single(&self.device, layout)
+            .or(Err(VkError::device("Failed to allocate Descriptor Set.")))?;
+
+        self.pools.push(pool);
+        self.active = self.pools.len() - 1;
+
+        Ok(self.record_set(handle, layout, set_index))
+    }
+
+    /// Allocate a descriptor set of `layout`, and write the bindings of `config` to it.
+    pub fn allocate_with(&mut self, layout: &GsDescriptorSetLayout, config: &DescriptorSetConfig, set_index: usize) -> VkResult<DescriptorSet> {
+
+        let set = self.allocate(layout, set_index)?;
+
+        let mut buffer_write_infos = VKWrapperInfo::new();
+        let mut  image_write_infos = VKWrapperInfo::new();
+
+        let write_set = GsDescriptorSet::new(set.handle, layout.clone());
+        config.add_write_set(&write_set, &mut buffer_write_infos, &mut image_write_infos);
+
+        if buffer_write_infos.is_empty() == false {
+            self.device.logic.update_descriptor_sets(buffer_write_infos.borrow_info());
+        }
+
+        if image_write_infos.is_empty() == false {
+            self.device.logic.update_descriptor_sets(image_write_infos.borrow_info());
+        }
+
+        Ok(set)
+    }
+
+    /// Return the descriptor set to the pool it was allocated from.
+    ///
+    /// The set must not be used by any pending command buffer.
+    pub fn free(&mut self, set: &DescriptorSet) -> VkResult<()> {
+
+        if self.flags.contains(vk::DescriptorPoolCreateFlags::FREE_DESCRIPTOR_SET) == false {
+            return Err(VkError::other("Descriptor Pool must be created with FREE_DESCRIPTOR_SET flag to free individual Descriptor Set."))
+        }
+
+        let pool_index = self.owners.remove(&set.handle)
+            .ok_or(VkError::other("The Descriptor Set is not allocated from this Descriptor Pool Chain."))?;
+
+        self.pools[pool_index].free(&self.device, &[set.handle]);
+        // the pool has space again.
+        self.active = ::std::cmp::min(self.active, pool_index);
+
+        Ok(())
+    }
+
+    /// Return all the descriptor sets back to the pools, which is usually called at the beginning of each frame.
+    ///
+    /// All the descriptor sets allocated from this chain become invalid, and none of them may be used by any pending command buffer.
+    pub fn reset(&mut self) -> VkResult<()> {
+
+        for pool in self.pools.iter() {
+            pool.reset(&self.device)?;
+        }
+
+        self.owners.clear();
+        self.active = 0;
+
+        Ok(())
+    }
+
+    /// The number of descriptor pools in the chain.
+    pub fn pool_count(&self) -> usize {
+        self.pools.len()
+    }
+
+    fn create_pool(&self) -> VkResult<GsDescriptorPool> {
+
+        let mut pool_info = GsDescriptorPool::new(self.flags);
+        pool_info.set_pool_size_max(self.sets_per_pool);
+
+        for (&desc_type, &count) in self.set_ratios.iter() {
+            pool_info.add_pool_size(desc_type, count * self.sets_per_pool);
+        }
+
+        pool_info.build(&self.device)
+    }
+
+    fn record_set(&mut self, handle: vk::DescriptorSet, layout: &GsDescriptorSetLayout, set_index: usize) -> DescriptorSet {
+
+        self.owners.insert(handle, self.active);
+
+        let set = GsDescriptorSet::new(handle, layout.clone());
+        DescriptorSet::new(&set, set_index)
+    }
+}
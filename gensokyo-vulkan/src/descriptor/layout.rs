@@ -3,9 +3,12 @@ use ash::vk;
 use ash::version::DeviceV1_0;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
+use crate::core::device::SetLayoutKey;
 
-use crate::descriptor::binding::DescriptorBindingCI;
+use crate::descriptor::binding::{ DescriptorBindingCI, DescriptorMetaMirror };
 use crate::error::{ VkResult, VkError };
+use crate::types::vkuint;
 
 use std::ptr;
 
@@ -13,6 +16,9 @@ pub struct DescriptorSetLayoutCI {
 
     flags   : vk::DescriptorSetLayoutCreateFlags,
     bindings: Vec<vk::DescriptorSetLayoutBinding>,
+    metas   : Vec<DescriptorMetaMirror>,
+    /// the VK_EXT_descriptor_indexing flags of each binding.
+    binding_flags: Vec<vk::DescriptorBindingFlagsEXT>,
 }
 
 impl GsDescriptorSetLayout {
@@ -21,6 +27,8 @@ impl GsDescriptorSetLayout {
         DescriptorSetLayoutCI {
             flags: vk::DescriptorSetLayoutCreateFlags::empty(),
             bindings: Vec::with_capacity(binding_count),
+            metas   : Vec::with_capacity(binding_count),
+            binding_flags: Vec::with_capacity(binding_count),
         }
     }
 }
@@ -29,7 +37,18 @@ impl DescriptorSetLayoutCI {
 
     pub fn add_binding(&mut self, info: &impl DescriptorBindingCI, stages: vk::ShaderStageFlags) {
 
-        let meta = info.meta_mirror();
+        self.push_binding(info.meta_mirror(), stages, vk::DescriptorBindingFlagsEXT::empty());
+    }
+
+    /// Add a binding with the flags of VK_EXT_descriptor_indexing, such as partially bound or update-after-bind binding.
+    ///
+    /// The descriptor indexing features must be enabled on device.
+    pub fn add_indexing_binding(&mut self, meta: DescriptorMetaMirror, stages: vk::ShaderStageFlags, flags: vk::DescriptorBindingFlagsEXT) {
+
+        self.push_binding(meta, stages, flags);
+    }
+
+    fn push_binding(&mut self, meta: DescriptorMetaMirror, stages: vk::ShaderStageFlags, flags: vk::DescriptorBindingFlagsEXT) {
 
         let binding = vk::DescriptorSetLayoutBinding {
             // binding is the binding number of this entry and corresponds to a resource of the same binding number in the shader stages.
@@ -48,6 +67,8 @@ impl DescriptorSetLayoutCI {
         };
 
         self.bindings.push(binding);
+        self.metas.push(meta);
+        self.binding_flags.push(flags);
     }
 
     pub fn set_flags(&mut self, flags: vk::DescriptorSetLayoutCreateFlags) {
@@ -56,20 +77,41 @@ impl DescriptorSetLayoutCI {
 
     pub fn build(&self, device: &GsDevice) -> VkResult<GsDescriptorSetLayout> {
 
+        // the binding flags are chained only if any of them is set, so that VK_EXT_descriptor_indexing is not required by the other layouts.
+        let binding_flags_info = vk::DescriptorSetLayoutBindingFlagsCreateInfoEXT {
+            s_type: vk::StructureType::DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO_EXT,
+            p_next: ptr::null(),
+            binding_count   : self.binding_flags.len() as _,
+            p_binding_flags : self.binding_flags.as_ptr(),
+        };
+
+        let is_indexing_layout = self.binding_flags.iter().any(|flags| flags.is_empty() == false);
+        let p_next = if is_indexing_layout {
+            &binding_flags_info as *const vk::DescriptorSetLayoutBindingFlagsCreateInfoEXT as _
+        } else {
+            ptr::null()
+        };
+
         let layout_info = vk::DescriptorSetLayoutCreateInfo {
             s_type: vk::StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO,
-            p_next: ptr::null(),
+            p_next,
             flags : self.flags,
             binding_count: self.bindings.len() as _,
             p_bindings   : self.bindings.as_ptr(),
         };
 
-        let handle = unsafe {
+        // identical layouts share the same handle through the layout cache of device.
+        let cache_key = SetLayoutKey::new(self.flags, &self.bindings, &self.binding_flags);
+        let handle = device.logic.layout_cache().acquire_set_layout(cache_key.clone(), || unsafe {
             device.logic.handle.create_descriptor_set_layout(&layout_info, None)
-                .or(Err(VkError::create("Descriptor Set Layout")))?
-        };
+                .or(Err(VkError::create("Descriptor Set Layout")))
+        })?;
 
-        let set_layout = GsDescriptorSetLayout { handle };
+        let set_layout = GsDescriptorSetLayout {
+            handle,
+            key: cache_key,
+            bindings: self.metas.clone(),
+        };
         Ok(set_layout)
     }
 }
@@ -78,13 +120,21 @@ impl DescriptorSetLayoutCI {
 pub struct GsDescriptorSetLayout {
 
     pub(crate) handle: vk::DescriptorSetLayout,
+    /// the contents identifying this layout in the layout cache of device.
+    pub(crate) key: SetLayoutKey,
+    /// the meta data of each binding in this layout, used to validate the descriptor writes.
+    pub(crate) bindings: Vec<DescriptorMetaMirror>,
 }
 
 impl GsDescriptorSetLayout {
 
+    /// Release this layout. The handle is destroyed once all the identical layouts have been discarded.
     pub fn discard(&self, device: &GsDevice) {
-        unsafe {
-            device.logic.handle.destroy_descriptor_set_layout(self.handle, None);
-        }
+        device.logic.release_layout(DestroyHandle::DescriptorSetLayout(self.handle));
+    }
+
+    /// Return the meta data of `binding` declared in this layout.
+    pub(crate) fn binding_meta(&self, binding: vkuint) -> Option<&DescriptorMetaMirror> {
+        self.bindings.iter().find(|meta| meta.binding == binding)
     }
 }
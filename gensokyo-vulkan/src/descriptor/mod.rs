@@ -4,6 +4,9 @@ pub use self::set::{ GsDescriptorSet, DescriptorSetConfig, DescriptorSet };
 pub use self::pool::{ GsDescriptorPool, DescriptorPoolCI };
 pub use self::types::{ GsDescriptorType, BufferDescriptorType, ImageDescriptorType };
 pub use self::repository::GsDescriptorRepository;
+pub use self::chain::GsDescriptorPoolChain;
+pub use self::updater::DescriptorSetUpdater;
+pub use self::bindless::{ BindlessTextureTable, BINDLESS_TEXTURE_BINDING };
 
 pub mod allocator;
 pub mod binding;
@@ -13,3 +16,6 @@ mod layout;
 mod set;
 mod types;
 mod repository;
+mod chain;
+mod updater;
+mod bindless;
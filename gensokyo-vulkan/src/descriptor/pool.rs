@@ -5,6 +5,7 @@ use ash::version::DeviceV1_0;
 use gsma::collect_handle;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
 
 use crate::descriptor::set::GsDescriptorSet;
 use crate::descriptor::layout::GsDescriptorSetLayout;
@@ -37,7 +38,9 @@ impl GsDescriptorPool {
 
 impl DescriptorPoolCI {
 
-    #[allow(dead_code)]
+    /// Set the maximum number of descriptor sets that can be allocated from the pool.
+    ///
+    /// If it is not set, the total count of descriptors in the pool is used, since each set consumes at least one descriptor.
     pub fn set_pool_size_max(&mut self, max_size: vkuint) {
         self.max_sets = max_size;
     }
@@ -52,7 +55,11 @@ impl DescriptorPoolCI {
 
     pub fn build(&self, device: &GsDevice) -> VkResult<GsDescriptorPool> {
 
-        let max_sets = if self.max_sets == 0 { self.pool_sizes.len() as _ } else { self.max_sets };
+        let max_sets = if self.max_sets == 0 {
+            self.pool_sizes.iter().fold(0, |sum, size| sum + size.descriptor_count)
+        } else {
+            self.max_sets
+        };
 
         let info = vk::DescriptorPoolCreateInfo {
             s_type: vk::StructureType::DESCRIPTOR_POOL_CREATE_INFO,
@@ -68,15 +75,15 @@ impl DescriptorPoolCI {
                 .or(Err(VkError::create("Descriptor Pool")))?
         };
 
-        let descriptor_pool = GsDescriptorPool { handle };
+        let descriptor_pool = GsDescriptorPool { handle, device: device.clone() };
         Ok(descriptor_pool)
     }
 }
 
-#[derive(Default)]
 pub struct GsDescriptorPool {
 
     handle: vk::DescriptorPool,
+    device: GsDevice,
 }
 
 impl GsDescriptorPool {
@@ -106,10 +113,90 @@ impl GsDescriptorPool {
         Ok(sets)
     }
 
-    pub fn discard(&self, device: &GsDevice) {
+    /// Allocate a single descriptor set of `layout` from this pool.
+    ///
+    /// The raw result is returned, so that the caller can tell whether the pool is exhausted.
+    pub(crate) fn allocate_single(&self, device: &GsDevice, layout: &GsDescriptorSetLayout) -> Result<vk::DescriptorSet, vk::Result> {
+
+        let allocate_info = vk::DescriptorSetAllocateInfo {
+            s_type: vk::StructureType::DESCRIPTOR_SET_ALLOCATE_INFO,
+            p_next: ptr::null(),
+            descriptor_pool: self.handle,
+            descriptor_set_count: 1,
+            p_set_layouts       : &layout.handle,
+        };
+
+        let handles = unsafe {
+            device.logic.handle.allocate_descriptor_sets(&allocate_info)?
+        };
+
+        Ok(handles[0])
+    }
+
+    /// Allocate a single descriptor set of `layout`, whose variable-sized binding contains `variable_count` descriptors.
+    ///
+    /// The last binding of `layout` must be created with `vk::DescriptorBindingFlagsEXT::VARIABLE_DESCRIPTOR_COUNT`.
+    pub(crate) fn allocate_variable(&self, device: &GsDevice, layout: &GsDescriptorSetLayout, variable_count: vkuint) -> VkResult<vk::DescriptorSet> {
+
+        let variable_info = vk::DescriptorSetVariableDescriptorCountAllocateInfoEXT {
+            s_type: vk::StructureType::DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO_EXT,
+            p_next: ptr::null(),
+            descriptor_set_count: 1,
+            p_descriptor_counts : &variable_count,
+        };
+
+        let allocate_info = vk::DescriptorSetAllocateInfo {
+            s_type: vk::StructureType::DESCRIPTOR_SET_ALLOCATE_INFO,
+            p_next: &variable_info as *const vk::DescriptorSetVariableDescriptorCountAllocateInfoEXT as _,
+            descriptor_pool: self.handle,
+            descriptor_set_count: 1,
+            p_set_layouts       : &layout.handle,
+        };
+
+        let handles = unsafe {
+            device.logic.handle.allocate_descriptor_sets(&allocate_info)
+                .or(Err(VkError::device("Failed to allocate Descriptor Set.")))?
+        };
+
+        Ok(handles[0])
+    }
+
+    /// Return the descriptor sets to this pool.
+    ///
+    /// The pool must be created with `vk::DescriptorPoolCreateFlags::FREE_DESCRIPTOR_SET`.
+    pub(crate) fn free(&self, device: &GsDevice, sets: &[vk::DescriptorSet]) {
+
+        unsafe {
+            device.logic.handle.free_descriptor_sets(self.handle, sets);
+        }
+    }
+
+    /// Give up the ownership of the vk::DescriptorPool object, whose destruction is then managed by the returned handle.
+    pub(crate) fn into_destroy_handle(mut self) -> DestroyHandle {
+
+        let handle = DestroyHandle::DescriptorPool(self.handle);
+        self.handle = vk::DescriptorPool::null();
+        handle
+    }
+
+    /// Return all the descriptor sets allocated from this pool back to it.
+    pub(crate) fn reset(&self, device: &GsDevice) -> VkResult<()> {
 
         unsafe {
-            device.logic.handle.destroy_descriptor_pool(self.handle, None);
+            device.logic.handle.reset_descriptor_pool(self.handle, vk::DescriptorPoolResetFlags::empty())
+                .or(Err(VkError::device("Failed to reset Descriptor Pool.")))
+        }
+    }
+}
+
+impl Drop for GsDescriptorPool {
+
+    /// The vk::DescriptorPool object will be destroyed when device no longer uses it.
+    /// All the descriptor sets allocated from this pool are freed together.
+    fn drop(&mut self) {
+
+        if self.handle != vk::DescriptorPool::null() {
+            self.device.logic.defer_destroy(DestroyHandle::DescriptorPool(self.handle));
         }
     }
 }
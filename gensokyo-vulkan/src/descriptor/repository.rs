@@ -27,8 +27,7 @@ impl Drop for GsDescriptorRepository {
 
     fn drop(&mut self) {
 
-        self.pool.discard(&self.device);
-
+        // the pool is released in its drop func.
         self.sets.iter().for_each(|set| set.discard(&self.device));
     }
 }
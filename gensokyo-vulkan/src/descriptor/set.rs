@@ -5,7 +5,7 @@ use crate::core::GsDevice;
 
 use crate::descriptor::types::GsDescriptorType;
 use crate::descriptor::layout::{ GsDescriptorSetLayout, DescriptorSetLayoutCI };
-use crate::descriptor::binding::{ DescriptorBindingBufInfo, DescriptorBindingBufTgt };
+use crate::descriptor::binding::{ DescriptorBindingBufInfo, DescriptorBindingBufTgt, DescriptorBufWriteContent };
 use crate::descriptor::binding::{ DescriptorBindingImgInfo, DescriptorBindingImgTgt };
 use crate::descriptor::binding::{ DescriptorBindingImgArrayInfo, DescriptorBindingImgArrayTgt };
 
@@ -135,7 +135,7 @@ impl DescriptorSetConfig {
     }
 
     pub(super) fn add_write_set(&self, set: &GsDescriptorSet,
-        buffers: &mut VKWrapperInfo<Vec<vk::DescriptorBufferInfo>, vk::WriteDescriptorSet>,
+        buffers: &mut VKWrapperInfo<DescriptorBufWriteContent, vk::WriteDescriptorSet>,
         images : &mut VKWrapperInfo<Vec<vk::DescriptorImageInfo>,  vk::WriteDescriptorSet>
     ) {
 
@@ -195,4 +195,9 @@ impl DescriptorSet {
     pub fn set_index(&self) -> usize {
         self.set_index.clone()
     }
+
+    /// The layout of this descriptor set, which can be used to allocate more sets of the same layout.
+    pub fn layout(&self) -> &GsDescriptorSetLayout {
+        &self.layout
+    }
 }
@@ -30,6 +30,8 @@ pub enum ImageDescriptorType {
     SampledImage,
     /// StorageImage specifies a storage image descriptor.
     StorageImage,
+    /// InputAttachment specifies an input attachment descriptor, which is read by `subpassLoad` in fragment shader.
+    InputAttachment,
 }
 
 impl From<ImageDescriptorType> for vk::DescriptorType {
@@ -41,6 +43,7 @@ impl From<ImageDescriptorType> for vk::DescriptorType {
             | ImageDescriptorType::CombinedImageSampler => vk::DescriptorType::COMBINED_IMAGE_SAMPLER,
             | ImageDescriptorType::SampledImage         => vk::DescriptorType::SAMPLED_IMAGE,
             | ImageDescriptorType::StorageImage         => vk::DescriptorType::STORAGE_IMAGE,
+            | ImageDescriptorType::InputAttachment      => vk::DescriptorType::INPUT_ATTACHMENT,
         }
     }
 }
@@ -0,0 +1,107 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+
+use crate::descriptor::set::{ GsDescriptorSet, DescriptorSet };
+use crate::descriptor::binding::{ DescriptorBindingCI, DescriptorMetaMirror, DescriptorBufWriteContent };
+use crate::descriptor::binding::{ DescriptorBindingBufTgt, DescriptorBindingImgTgt, DescriptorBindingImgArrayTgt };
+
+use crate::utils::wrapper::VKWrapperInfo;
+use crate::error::{ VkResult, VkError };
+use crate::types::vkuint;
+
+/// Rewrite the bindings of an existing descriptor set.
+///
+/// Each write is validated against the layout of the set when it is added, and all the writes are submitted together by `update` method.
+///
+/// Updating a descriptor set invalidates the command buffers it has been bound to,
+/// so call `update` between frames, after the fence of the frame using this set has been signaled.
+pub struct DescriptorSetUpdater {
+
+    set: GsDescriptorSet,
+
+    buffers: VKWrapperInfo<DescriptorBufWriteContent, vk::WriteDescriptorSet>,
+    images : VKWrapperInfo<Vec<vk::DescriptorImageInfo>, vk::WriteDescriptorSet>,
+}
+
+impl DescriptorSetUpdater {
+
+    pub fn new(set: &DescriptorSet) -> DescriptorSetUpdater {
+
+        DescriptorSetUpdater {
+            set: GsDescriptorSet::new(set.handle, set.layout.clone()),
+            buffers: VKWrapperInfo::new(),
+            images : VKWrapperInfo::new(),
+        }
+    }
+
+    /// Point the buffer binding of `bind_target` to its buffer.
+    pub fn write_buffer(&mut self, bind_target: &impl DescriptorBindingBufTgt) -> VkResult<()> {
+
+        let info = bind_target.binding_info();
+        self.validate(info.meta_mirror(), 0)?;
+
+        self.buffers.push(info.write_info(&self.set));
+        Ok(())
+    }
+
+    /// Point the image(or sampler) binding of `bind_target` to its image view and sampler.
+    pub fn write_image(&mut self, bind_target: &impl DescriptorBindingImgTgt) -> VkResult<()> {
+        self.write_image_element(bind_target, 0)
+    }
+
+    /// Point the `array_element` element of an image(or sampler) array binding to the image view and sampler of `bind_target`.
+    ///
+    /// The binding index and descriptor type are taken from `bind_target`.
+    pub fn write_image_element(&mut self, bind_target: &impl DescriptorBindingImgTgt, array_element: vkuint) -> VkResult<()> {
+
+        let info = bind_target.binding_info();
+        self.validate(info.meta_mirror(), array_element)?;
+
+        let mut write_pair = info.write_info(&self.set);
+        write_pair.info.dst_array_element = array_element;
+
+        self.images.push(write_pair);
+        Ok(())
+    }
+
+    /// Rewrite all the elements of an image array binding.
+    pub fn write_image_array(&mut self, bind_target: &impl DescriptorBindingImgArrayTgt) -> VkResult<()> {
+
+        let info = bind_target.binding_info();
+        self.validate(info.meta_mirror(), 0)?;
+
+        self.images.push(info.write_info(&self.set));
+        Ok(())
+    }
+
+    /// Submit all the writes to the descriptor set.
+    pub fn update(self, device: &GsDevice) {
+
+        if self.buffers.is_empty() == false {
+            device.logic.update_descriptor_sets(self.buffers.borrow_info());
+        }
+
+        if self.images.is_empty() == false {
+            device.logic.update_descriptor_sets(self.images.borrow_info());
+        }
+    }
+
+    /// Check the write of `meta` starting at `array_element` matches the binding declared in layout.
+    fn validate(&self, meta: DescriptorMetaMirror, array_element: vkuint) -> VkResult<()> {
+
+        let declared = self.set.layout.binding_meta(meta.binding)
+            .ok_or_else(|| VkError::other(format!("Binding {} is not declared in the layout of Descriptor Set.", meta.binding)))?;
+
+        if declared.descriptor_type != meta.descriptor_type {
+            return Err(VkError::other(format!("Descriptor type of binding {} mismatches its layout: expect {:?}, but found {:?}.", meta.binding, declared.descriptor_type, meta.descriptor_type)))
+        }
+
+        if array_element + meta.count > declared.count {
+            return Err(VkError::other(format!("Descriptor write at binding {} is out of range: write {} element(s) from {}, but the binding contains {} element(s).", meta.binding, meta.count, array_element, declared.count)))
+        }
+
+        Ok(())
+    }
+}
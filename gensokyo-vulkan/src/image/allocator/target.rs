@@ -13,12 +13,14 @@ use crate::image::instance::traits::{ IImageConveyor, ImageInstanceInfoDesc };
 use crate::image::instance::sampler::{ GsSampler, SamplerCI };
 use crate::image::instance::sampler::{ GsSamplerArray, SamplerArrayCI };
 use crate::image::instance::depth::DSImageBarrierBundle;
+use crate::image::instance::storage::StorageImageBarrierBundle;
+use crate::image::instance::color::ColorImageBarrierBundle;
 use crate::image::instance::sampler::GsSamplerMirror;
 use crate::image::allocator::types::ImageMemoryTypeAbs;
 use crate::image::allocator::distributor::GsImageDistributor;
 
 use crate::memory::{ MemoryFilter, MemoryDstEntity };
-use crate::memory::transfer::DataCopyer;
+use crate::memory::transfer::GsUploadBatch;
 
 use crate::error::{ VkResult, VkError };
 use crate::utils::allot::{ GsAssignIndex, GsAllocatorApi, GsAllotIntoDistributor };
@@ -27,8 +29,6 @@ use crate::types::{ vkuint, vkbytes, vkDim3D };
 use std::collections::{ HashMap, HashSet };
 use std::marker::PhantomData;
 
-// TODO: Currently not support multi imageview for an image.
-
 pub struct GsImageAllocator<M>
     where
         M: ImageMemoryTypeAbs {
@@ -51,7 +51,7 @@ impl<M, I> GsAllocatorApi<I, GsImageDistributor<M>> for GsImageAllocator<M>
 
     type AssignResult = VkResult<GsAssignIndex<<I as ImageCISpecificApi>::IConveyor>>;
 
-    fn assign(&mut self, ci: I) -> Self::AssignResult {
+    fn assign(&mut self, mut ci: I) -> Self::AssignResult {
 
         // confirm if the physical device support image requirement.
         // TODO: Add option to disable check in release mode.
@@ -115,7 +115,32 @@ impl<M> GsAllotIntoDistributor<GsImageDistributor<M>> for GsImageAllocator<M>
     where
         M: ImageMemoryTypeAbs {
 
-    fn allocate(mut self) -> VkResult<GsImageDistributor<M>> {
+    fn allocate(self) -> VkResult<GsImageDistributor<M>> {
+
+        let mut batch = GsUploadBatch::new(&self.device)?;
+        let distributor = self.allocate_with(&mut batch)?;
+
+        // execute image barrier transition.
+        batch.finish()?;
+
+        Ok(distributor)
+    }
+
+    fn reset(&mut self) {
+
+        self.image_infos.clear();
+        self.memory_filter.reset();
+    }
+}
+
+impl<M> GsImageAllocator<M>
+    where
+        M: ImageMemoryTypeAbs {
+
+    /// Allocate memory for the images, and record their data uploading, mipmap generation and layout transitions into `batch`.
+    ///
+    /// The images must not be used until `batch.finish()` is called.
+    pub fn allocate_with(mut self, batch: &mut GsUploadBatch) -> VkResult<GsImageDistributor<M>> {
 
         // confirm there are images awaiting to be allocated.
         if self.image_infos.is_empty() {
@@ -140,32 +165,15 @@ impl<M> GsAllotIntoDistributor<GsImageDistributor<M>> for GsImageAllocator<M>
         }
 
         // 4.record image barrier transitions(upload data, generate mipmap...etc, if needed).
-        let mut copyer = DataCopyer::new(&self.device)?;
-
         let mut barrier_bundles = collect_barrier_bundle(&self.image_infos);
         for bundle in barrier_bundles.iter_mut() {
-            bundle.make_barrier_transform(&self.device, &copyer, &mut self.image_infos)?;
+            bundle.make_barrier_transform(&self.device, batch, &mut self.image_infos)?;
         }
 
-        // 5.execute image barrier transition.
-        copyer.done()?;
-
         // final done.
         GsImageDistributor::new(self.phantom_type, self.device, self.image_infos, self.samplers, memory)
     }
 
-    fn reset(&mut self) {
-
-        self.image_infos.iter().for_each(|ci| ci.discard(&self.device));
-        self.image_infos.clear();
-        self.memory_filter.reset();
-    }
-}
-
-impl<M> GsImageAllocator<M>
-    where
-        M: ImageMemoryTypeAbs {
-
     pub fn create(device: &GsDevice, storage_type: M) -> GsImageAllocator<M> {
 
         GsImageAllocator {
@@ -215,13 +223,12 @@ impl ImageAllotCI {
             current_layout : self.current_layout,
             dimension      : self.backend.storage.dimension,
             subrange       : self.backend.view_ci.subrange.clone(),
+            format         : self.backend.image_ci.specific.format.clone().into(),
+            flags          : self.backend.image_ci.property.flags,
+            mip_levels     : self.backend.image_ci.property.mip_levels,
+            array_layers   : self.backend.image_ci.property.array_layers,
         }
     }
-
-    pub fn discard(&self, device: &GsDevice) {
-
-        self.image.discard(device);
-    }
 }
 
 impl ImageCopiable for ImageAllotCI {
@@ -239,15 +246,14 @@ impl ImageCopiable for ImageAllotCI {
             extent: vkDim3D {
                 width : max(self.backend.storage.dimension.width  >> copy_mip_level, 1),
                 height: max(self.backend.storage.dimension.height >> copy_mip_level, 1),
-                // TODO: This parameter may be wrong setting.
-                depth : self.backend.image_ci.property.array_layers,
+                depth : max(self.backend.storage.dimension.depth  >> copy_mip_level, 1),
             },
             sub_resource_layers: vk::ImageSubresourceLayers {
                 aspect_mask      : self.backend.view_ci.subrange.0.aspect_mask,
                 mip_level        : copy_mip_level,
-                // copy all the layers.
+                // copy all the layers, the data of each layer is tightly packed in buffer.
                 base_array_layer : 0,
-                layer_count      : self.typ.layer_count(),
+                layer_count      : self.backend.image_ci.property.array_layers,
             },
         }
     }
@@ -290,13 +296,29 @@ fn collect_barrier_bundle(image_infos: &[ImageAllotCI]) -> Vec<Box<dyn ImageBarr
                     let bundle = SampleImageBarrierBundle::new(stage, image_type.clone(), indices);
                     Box::new(bundle) as Box<dyn ImageBarrierBundleAbs>
                 },
+                | ImageInstanceType::Texture2DArray { stage } => {
+                    let bundle = SampleImageBarrierBundle::new(stage, image_type.clone(), indices);
+                    Box::new(bundle) as Box<dyn ImageBarrierBundleAbs>
+                },
+                | ImageInstanceType::Texture3D { stage } => {
+                    let bundle = SampleImageBarrierBundle::new(stage, image_type.clone(), indices);
+                    Box::new(bundle) as Box<dyn ImageBarrierBundleAbs>
+                },
                 | ImageInstanceType::DepthStencilAttachment => {
                     let bundle = DSImageBarrierBundle::new(indices);
                     Box::new(bundle) as Box<dyn ImageBarrierBundleAbs>
                 },
+                | ImageInstanceType::ColorAttachment => {
+                    let bundle = ColorImageBarrierBundle::new(indices);
+                    Box::new(bundle) as Box<dyn ImageBarrierBundleAbs>
+                },
                 | ImageInstanceType::DepthStencilImage { format: _, stage: _ } => {
                     unimplemented!()
                 },
+                | ImageInstanceType::StorageImage { stage } => {
+                    let bundle = StorageImageBarrierBundle::new(stage, indices);
+                    Box::new(bundle) as Box<dyn ImageBarrierBundleAbs>
+                },
             }
 
         }).collect();
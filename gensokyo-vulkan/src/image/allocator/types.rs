@@ -7,7 +7,7 @@ use crate::memory::{ GsMemoryAbstract, MemoryFilter };
 use crate::memory::types::GsMemoryType;
 use crate::memory::instance::{ GsImageMemory, GsCachedMemory, GsDeviceMemory };
 
-use crate::utils::phantom::{ Device, Cached };
+use crate::utils::phantom::{ Device, Cached, Transient };
 use crate::error::VkResult;
 
 pub trait ImageMemoryTypeAbs: Copy {
@@ -26,6 +26,8 @@ pub enum ImageStorageType {}
 impl ImageStorageType {
     pub const DEVICE: Device = Device;
     pub const CACHED: Cached = Cached;
+    /// `TRANSIENT` is used by the attachments whose contents are never stored, such as multisampled attachments.
+    pub const TRANSIENT: Transient = Transient;
 }
 
 impl ImageMemoryTypeAbs for Device {
@@ -51,3 +53,16 @@ impl ImageMemoryTypeAbs for Cached {
         Ok(memory_abs)
     }
 }
+
+impl ImageMemoryTypeAbs for Transient {
+    const MEMORY_TYPE: GsMemoryType = GsMemoryType::TransientMemory;
+
+    fn allot_memory(&self, device: &GsDevice, size: vkbytes, filter: &MemoryFilter) -> VkResult<GsImageMemory> {
+
+        // lazily allocated memory is never mapped or transferred, so it's managed as device memory.
+        let device_memory = GsDeviceMemory::allocate(device, size, filter)?;
+        let memory_abs = Box::new(device_memory) as GsImageMemory;
+
+        Ok(memory_abs)
+    }
+}
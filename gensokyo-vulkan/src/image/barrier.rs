@@ -15,9 +15,13 @@ pub struct ImageBarrierCI(vk::ImageMemoryBarrier);
 impl ImageBarrierCI {
 
     pub fn new(image: &GsImage, subrange: ImageSubRange) -> ImageBarrierBuilder {
+        ImageBarrierCI::with_handle(image.handle, subrange)
+    }
+
+    pub(crate) fn with_handle(image: vk::Image, subrange: ImageSubRange) -> ImageBarrierBuilder {
 
         let mut barrier = ImageBarrierCI::default();
-        barrier.0.image = image.handle;
+        barrier.0.image = image;
         barrier.0.subresource_range = subrange.0;
 
         ImageBarrierBuilder(barrier)
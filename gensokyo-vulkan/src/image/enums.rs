@@ -4,7 +4,6 @@ use ash::vk;
 use crate::image::format::GsImageFormat;
 
 use crate::types::format::Format;
-use crate::types::vkuint;
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum ImageInstanceType {
@@ -12,18 +11,12 @@ pub enum ImageInstanceType {
     CombinedImageSampler { stage: ImagePipelineStage },
     SampledImage { stage: ImagePipelineStage },
     CubeMapImage { stage: ImagePipelineStage },
+    Texture2DArray { stage: ImagePipelineStage },
+    Texture3D { stage: ImagePipelineStage },
     DepthStencilAttachment,
+    ColorAttachment,
     DepthStencilImage { format: Format, stage: ImagePipelineStage },
-}
-
-impl ImageInstanceType {
-
-    pub(super) fn layer_count(&self) -> vkuint {
-        match self {
-            | ImageInstanceType::CubeMapImage { .. } => 6,
-            | _ => 1,
-        }
-    }
+    StorageImage { stage: ImagePipelineStage },
 }
 
 /// ImagePipelineStage indicate in which pipeline stage this image is used.
@@ -32,6 +25,7 @@ pub enum ImagePipelineStage {
 
     VertexStage,
     FragmentStage,
+    ComputeStage,
 }
 
 impl From<ImagePipelineStage> for vk::PipelineStageFlags {
@@ -40,6 +34,7 @@ impl From<ImagePipelineStage> for vk::PipelineStageFlags {
         match flag {
             | ImagePipelineStage::VertexStage   => vk::PipelineStageFlags::VERTEX_SHADER,
             | ImagePipelineStage::FragmentStage => vk::PipelineStageFlags::FRAGMENT_SHADER,
+            | ImagePipelineStage::ComputeStage  => vk::PipelineStageFlags::COMPUTE_SHADER,
         }
     }
 }
@@ -4,7 +4,7 @@ use ash::vk;
 use crate::core::GsDevice;
 
 use crate::types::format::{ Format, TexBCCompressFormat, TexASTCLdrCompressFormat, TexETCCompressFormat };
-use crate::types::VK_TRUE;
+use crate::types::{ vkbytes, VK_TRUE };
 
 #[derive(Debug, Clone)]
 pub enum GsImageFormat {
@@ -43,6 +43,117 @@ impl GsImageFormat {
     }
 }
 
+/// The pairs of UNORM format and its sRGB counterpart.
+const SRGB_FORMAT_PAIRS: [(vk::Format, vk::Format); 29] = [
+    (vk::Format::R8_UNORM,                 vk::Format::R8_SRGB),
+    (vk::Format::R8G8_UNORM,               vk::Format::R8G8_SRGB),
+    (vk::Format::R8G8B8_UNORM,             vk::Format::R8G8B8_SRGB),
+    (vk::Format::B8G8R8_UNORM,             vk::Format::B8G8R8_SRGB),
+    (vk::Format::R8G8B8A8_UNORM,           vk::Format::R8G8B8A8_SRGB),
+    (vk::Format::B8G8R8A8_UNORM,           vk::Format::B8G8R8A8_SRGB),
+    (vk::Format::A8B8G8R8_UNORM_PACK32,    vk::Format::A8B8G8R8_SRGB_PACK32),
+    (vk::Format::BC1_RGB_UNORM_BLOCK,      vk::Format::BC1_RGB_SRGB_BLOCK),
+    (vk::Format::BC1_RGBA_UNORM_BLOCK,     vk::Format::BC1_RGBA_SRGB_BLOCK),
+    (vk::Format::BC2_UNORM_BLOCK,          vk::Format::BC2_SRGB_BLOCK),
+    (vk::Format::BC3_UNORM_BLOCK,          vk::Format::BC3_SRGB_BLOCK),
+    (vk::Format::BC7_UNORM_BLOCK,          vk::Format::BC7_SRGB_BLOCK),
+    (vk::Format::ETC2_R8G8B8_UNORM_BLOCK,  vk::Format::ETC2_R8G8B8_SRGB_BLOCK),
+    (vk::Format::ETC2_R8G8B8A1_UNORM_BLOCK, vk::Format::ETC2_R8G8B8A1_SRGB_BLOCK),
+    (vk::Format::ETC2_R8G8B8A8_UNORM_BLOCK, vk::Format::ETC2_R8G8B8A8_SRGB_BLOCK),
+    (vk::Format::ASTC_4X4_UNORM_BLOCK,     vk::Format::ASTC_4X4_SRGB_BLOCK),
+    (vk::Format::ASTC_5X4_UNORM_BLOCK,     vk::Format::ASTC_5X4_SRGB_BLOCK),
+    (vk::Format::ASTC_5X5_UNORM_BLOCK,     vk::Format::ASTC_5X5_SRGB_BLOCK),
+    (vk::Format::ASTC_6X5_UNORM_BLOCK,     vk::Format::ASTC_6X5_SRGB_BLOCK),
+    (vk::Format::ASTC_6X6_UNORM_BLOCK,     vk::Format::ASTC_6X6_SRGB_BLOCK),
+    (vk::Format::ASTC_8X5_UNORM_BLOCK,     vk::Format::ASTC_8X5_SRGB_BLOCK),
+    (vk::Format::ASTC_8X6_UNORM_BLOCK,     vk::Format::ASTC_8X6_SRGB_BLOCK),
+    (vk::Format::ASTC_8X8_UNORM_BLOCK,     vk::Format::ASTC_8X8_SRGB_BLOCK),
+    (vk::Format::ASTC_10X5_UNORM_BLOCK,    vk::Format::ASTC_10X5_SRGB_BLOCK),
+    (vk::Format::ASTC_10X6_UNORM_BLOCK,    vk::Format::ASTC_10X6_SRGB_BLOCK),
+    (vk::Format::ASTC_10X8_UNORM_BLOCK,    vk::Format::ASTC_10X8_SRGB_BLOCK),
+    (vk::Format::ASTC_10X10_UNORM_BLOCK,   vk::Format::ASTC_10X10_SRGB_BLOCK),
+    (vk::Format::ASTC_12X10_UNORM_BLOCK,   vk::Format::ASTC_12X10_SRGB_BLOCK),
+    (vk::Format::ASTC_12X12_UNORM_BLOCK,   vk::Format::ASTC_12X12_SRGB_BLOCK),
+];
+
+impl GsImageFormat {
+
+    /// Check if the texels of this format are stored in sRGB color space.
+    pub fn is_srgb(&self) -> bool {
+
+        let format: vk::Format = self.clone().into();
+        SRGB_FORMAT_PAIRS.iter().any(|&(_, srgb)| srgb == format)
+    }
+
+    /// Return the sRGB counterpart of this format if `is_srgb` is true, otherwise return its UNORM counterpart.
+    ///
+    /// The format without counterpart(such as float formats) is returned unchanged.
+    pub fn with_srgb(self, is_srgb: bool) -> GsImageFormat {
+
+        let format: vk::Format = self.clone().into();
+
+        let converted = SRGB_FORMAT_PAIRS.iter().find_map(|&(unorm, srgb)| {
+            if is_srgb && format == unorm {
+                Some(srgb)
+            } else if is_srgb == false && format == srgb {
+                Some(unorm)
+            } else {
+                None
+            }
+        });
+
+        match converted {
+            | Some(converted) => GsImageFormat::from(converted),
+            | None => self,
+        }
+    }
+
+    /// Return the size in bytes of a texel block of this format.
+    ///
+    /// For compressed formats, it's the size of a compressed block. For depth/stencil formats, it's the size of the combined texel.
+    pub(crate) fn block_size(&self) -> vkbytes {
+
+        let format: vk::Format = self.clone().into();
+
+        match format.as_raw() {
+            | 1         => 1,  // R4G4_UNORM_PACK8
+            | 2  ..=8   => 2,  // 16-bit packed formats
+            | 9  ..=15  => 1,  // R8
+            | 16 ..=22  => 2,  // R8G8
+            | 23 ..=36  => 3,  // R8G8B8, B8G8R8
+            | 37 ..=69  => 4,  // R8G8B8A8, B8G8R8A8 and 32-bit packed formats
+            | 70 ..=76  => 2,  // R16
+            | 77 ..=83  => 4,  // R16G16
+            | 84 ..=90  => 6,  // R16G16B16
+            | 91 ..=97  => 8,  // R16G16B16A16
+            | 98 ..=100 => 4,  // R32
+            | 101..=103 => 8,  // R32G32
+            | 104..=106 => 12, // R32G32B32
+            | 107..=109 => 16, // R32G32B32A32
+            | 110..=112 => 8,  // R64
+            | 113..=115 => 16, // R64G64
+            | 116..=118 => 24, // R64G64B64
+            | 119..=121 => 32, // R64G64B64A64
+            | 122..=123 => 4,  // B10G11R11_UFLOAT_PACK32, E5B9G9R9_UFLOAT_PACK32
+            | 124       => 2,  // D16_UNORM
+            | 125..=126 => 4,  // X8_D24_UNORM_PACK32, D32_SFLOAT
+            | 127       => 1,  // S8_UINT
+            | 128       => 3,  // D16_UNORM_S8_UINT
+            | 129       => 4,  // D24_UNORM_S8_UINT
+            | 130       => 8,  // D32_SFLOAT_S8_UINT
+            | 131..=134 => 8,  // BC1
+            | 135..=138 => 16, // BC2, BC3
+            | 139..=140 => 8,  // BC4
+            | 141..=146 => 16, // BC5, BC6H, BC7
+            | 147..=150 => 8,  // ETC2_R8G8B8, ETC2_R8G8B8A1
+            | 151..=152 => 16, // ETC2_R8G8B8A8
+            | 153..=154 => 8,  // EAC_R11
+            | 155..=184 => 16, // EAC_R11G11, ASTC
+            | _         => 4,
+        }
+    }
+}
+
 impl From<GsImageFormat> for vk::Format {
 
     fn from(f: GsImageFormat) -> vk::Format {
@@ -66,3 +177,22 @@ impl From<GsImageFormat> for Format {
         }
     }
 }
+
+impl From<vk::Format> for GsImageFormat {
+
+    /// Classify `vk::Format` by its compression scheme, which is used when the format is read from texture containers.
+    fn from(f: vk::Format) -> GsImageFormat {
+
+        let raw = f.as_raw();
+
+        if raw >= vk::Format::BC1_RGB_UNORM_BLOCK.as_raw() && raw <= vk::Format::BC7_SRGB_BLOCK.as_raw() {
+            GsImageFormat::CompressionBC(f.into())
+        } else if raw >= vk::Format::ETC2_R8G8B8_UNORM_BLOCK.as_raw() && raw <= vk::Format::EAC_R11G11_SNORM_BLOCK.as_raw() {
+            GsImageFormat::CompressionETC2(f.into())
+        } else if raw >= vk::Format::ASTC_4X4_UNORM_BLOCK.as_raw() && raw <= vk::Format::ASTC_12X12_SRGB_BLOCK.as_raw() {
+            GsImageFormat::CompressionASTCLdr(f.into())
+        } else {
+            GsImageFormat::Uncompressed(f.into())
+        }
+    }
+}
@@ -38,6 +38,10 @@ impl<T> ImageTgtCIApi for T
     fn set_share_queues(&mut self, queue_family_indices: Vec<vkuint>) {
         self.backend_mut().set_share_queues(queue_family_indices);
     }
+
+    fn set_mutable_format(&mut self) {
+        self.backend_mut().set_mutable_format();
+    }
 }
 
 impl<T> ImageViewCIApi for T
@@ -0,0 +1,102 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+
+use crate::image::target::GsImage;
+use crate::image::enums::{ ImageInstanceType, ImagePipelineStage };
+use crate::image::storage::ImageStorageInfo;
+use crate::image::instance::base::GsBackendImage;
+use crate::image::mipmap::MipmapMethod;
+use crate::image::instance::traits::ImageCISpecificApi;
+use crate::image::instance::array::image::{ GsTexture2DArray, ITexture2DArray };
+use crate::image::instance::api::ImageCIInheritApi;
+use crate::image::instance::sampler::{ GsSampler, SamplerCI };
+use crate::image::allocator::ImageAllotCI;
+
+use crate::descriptor::binding::DescriptorMeta;
+use crate::descriptor::{ GsDescriptorType, ImageDescriptorType };
+
+use crate::error::{ VkResult, VkError };
+use crate::types::vkuint;
+
+/// 2D Texture Array Create Info.
+pub struct Texture2DArrayCI {
+
+    pipeline_stage: ImagePipelineStage,
+    backend: GsBackendImage,
+
+    sampler_ci: SamplerCI,
+}
+
+impl GsTexture2DArray {
+
+    /// Create a 2D texture array sampled through combined image sampler.
+    ///
+    /// `storage.dimension` is the dimension of each layer, and the data of `layer_count` layers must be tightly packed in `storage`.
+    pub fn new(binding: vkuint, storage: ImageStorageInfo, layer_count: vkuint, pipeline_stage: ImagePipelineStage) -> Texture2DArrayCI {
+
+        let mut backend = GsBackendImage::from(storage);
+        backend.image_ci.property.image_type   = vk::ImageType::TYPE_2D;
+        backend.image_ci.property.tiling       = vk::ImageTiling::OPTIMAL;
+        backend.image_ci.property.usages       = vk::ImageUsageFlags::SAMPLED | vk::ImageUsageFlags::TRANSFER_DST;
+        backend.image_ci.property.mipmap       = MipmapMethod::Disable; // default to disable mipmap generation.
+        backend.image_ci.property.array_layers = layer_count;
+
+        backend.view_ci.view_type = vk::ImageViewType::TYPE_2D_ARRAY;
+        backend.view_ci.subrange.0.layer_count = layer_count;
+
+        let mut sampler_ci = GsSampler::new();
+        sampler_ci.reset_descriptor(DescriptorMeta {
+            binding,
+            descriptor_type: GsDescriptorType::Image(ImageDescriptorType::CombinedImageSampler),
+        });
+
+        Texture2DArrayCI { pipeline_stage, backend, sampler_ci }
+    }
+}
+
+impl Texture2DArrayCI {
+
+    pub fn reset_sampler(&mut self, sampler_ci: SamplerCI) {
+        self.sampler_ci.reset_ci(sampler_ci);
+    }
+}
+
+impl ImageCISpecificApi for Texture2DArrayCI {
+    type IConveyor = ITexture2DArray;
+
+    fn check_physical_support(&mut self, device: &GsDevice) -> VkResult<()> {
+
+        let layer_count = self.backend.image_ci.property.array_layers;
+        if layer_count == 0 || layer_count > device.phys.limits().max_image_array_layers {
+            return Err(VkError::other(format!("The layer count({}) of texture array is not support by device.", layer_count)))
+        }
+
+        self.backend.check_physical_support(device)
+    }
+
+    fn refactor(self, device: &GsDevice, image: GsImage) -> VkResult<(ImageAllotCI, Self::IConveyor)> {
+
+        let sampler = self.sampler_ci.build(device)?;
+        let isi = ITexture2DArray::new(sampler);
+
+        let allot_cis = ImageAllotCI::new(
+            ImageInstanceType::Texture2DArray { stage: self.pipeline_stage },
+            image, self.backend,
+        );
+
+        Ok((allot_cis, isi))
+    }
+}
+
+impl ImageCIInheritApi for Texture2DArrayCI {
+
+    fn backend(&self) -> &GsBackendImage {
+        &self.backend
+    }
+
+    fn backend_mut(&mut self) -> &mut GsBackendImage {
+        &mut self.backend
+    }
+}
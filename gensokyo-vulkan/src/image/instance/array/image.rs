@@ -0,0 +1,102 @@
+
+use ash::vk;
+
+use crate::image::entity::ImageEntity;
+use crate::image::copy::{ ImageCopiable, ImageFullCopyInfo };
+use crate::image::instance::sampler::{ GsSampler, GsSamplerMirror };
+use crate::image::instance::traits::{ ImageInstance, IImageConveyor, ImageInstanceInfoDesc, ImageViewable };
+
+use crate::descriptor::binding::{ DescriptorBindingImgInfo, DescriptorBindingImgTgt };
+use crate::types::{ vkuint, vkDim3D };
+
+/// Wrapper class of 2D Texture Array in Vulkan, which is sampled by `sampler2DArray` in shader.
+pub struct GsTexture2DArray {
+
+    isi: ITexture2DArray,
+
+    entity: ImageEntity,
+    desc: ImageInstanceInfoDesc,
+}
+
+impl ImageInstance<ITexture2DArray> for GsTexture2DArray {
+
+    fn build(isi: ITexture2DArray, entity: ImageEntity, desc: ImageInstanceInfoDesc) -> Self where Self: Sized {
+        GsTexture2DArray { isi, entity, desc }
+    }
+}
+
+impl ImageViewable for GsTexture2DArray {
+
+    fn entity(&self) -> &ImageEntity {
+        &self.entity
+    }
+
+    fn desc(&self) -> &ImageInstanceInfoDesc {
+        &self.desc
+    }
+}
+
+impl GsTexture2DArray {
+
+    pub fn layer_count(&self) -> vkuint {
+        self.desc.subrange.0.layer_count
+    }
+}
+
+impl DescriptorBindingImgTgt for GsTexture2DArray {
+
+    fn binding_info(&self) -> DescriptorBindingImgInfo {
+
+        DescriptorBindingImgInfo {
+            meta           : self.isi.sampler.descriptor.clone(),
+            sampler_handle : self.isi.sampler.handle,
+            dst_layout     : vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+            view_handle    : self.entity.view,
+        }
+    }
+}
+
+impl ImageCopiable for GsTexture2DArray {
+
+    fn full_copy_mipmap(&self, copy_mip_level: vkuint) -> ImageFullCopyInfo {
+
+        use std::cmp::max;
+
+        ImageFullCopyInfo {
+            handle: self.entity.image,
+            layout: self.desc.current_layout,
+            extent: vkDim3D {
+                width : max(self.desc.dimension.width  >> copy_mip_level, 1),
+                height: max(self.desc.dimension.height >> copy_mip_level, 1),
+                depth : 1,
+            },
+            sub_resource_layers: vk::ImageSubresourceLayers {
+                aspect_mask      : vk::ImageAspectFlags::COLOR,
+                mip_level        : copy_mip_level,
+                base_array_layer : 0,
+                layer_count      : self.desc.subrange.0.layer_count,
+            },
+        }
+    }
+}
+
+pub struct ITexture2DArray {
+
+    // no need to destroy sampler manually.
+    // it will automatically destroy by GsImageRepository.
+    sampler: GsSampler,
+}
+
+impl ITexture2DArray {
+
+    pub(super) fn new(sampler: GsSampler) -> ITexture2DArray {
+        ITexture2DArray { sampler }
+    }
+}
+
+impl IImageConveyor for ITexture2DArray {
+
+    fn sampler_mirror(&self) -> Option<GsSamplerMirror> {
+        Some(self.sampler.mirror())
+    }
+}
@@ -0,0 +1,6 @@
+
+pub use self::image::{ GsTexture2DArray, ITexture2DArray };
+pub use self::ci::Texture2DArrayCI;
+
+mod image;
+mod ci;
@@ -3,10 +3,7 @@ use ash::vk;
 
 use crate::core::GsDevice;
 
-use crate::buffer::allocator::GsBufferAllocator;
-use crate::buffer::allocator::types::BufferStorageType;
-use crate::buffer::instance::GsImgsrcBuffer;
-use crate::buffer::GsBufferRepository;
+use crate::buffer::BufferFullCopyInfo;
 
 use crate::image::barrier::ImageBarrierCI;
 use crate::image::storage::ImageSource;
@@ -15,47 +12,45 @@ use crate::image::instance::traits::ImageBarrierBundleAbs;
 use crate::image::mipmap::MipmapMethod;
 use crate::image::view::ImageSubRange;
 use crate::image::allocator::ImageAllotCI;
-use crate::memory::transfer::DataCopyer;
+use crate::memory::transfer::{ DataCopyer, GsUploadBatch };
 
 use crate::error::{ VkResult, VkError };
 use crate::command::{ GsCmdRecorder, GsCmdTransferApi };
-use crate::utils::allot::{ GsAllocatorApi, GsAllotIntoDistributor };
-use crate::utils::allot::{ GsDistributeApi, GsDistIntoRepository };
-use crate::utils::phantom::{ Staging, Transfer };
+use crate::utils::phantom::Transfer;
 
 pub struct SampleImageBarrierBundle {
 
     image_type: ImageInstanceType,
     info_indices: Vec<usize>,
     dst_stage: ImagePipelineStage,
-
-    staging_repository: Option<GsBufferRepository<Staging>>,
 }
 
 impl ImageBarrierBundleAbs for SampleImageBarrierBundle {
 
-    fn make_barrier_transform(&mut self, device: &GsDevice, copyer: &DataCopyer, infos: &mut Vec<ImageAllotCI>) -> VkResult<()> {
+    fn make_barrier_transform(&mut self, device: &GsDevice, batch: &mut GsUploadBatch, infos: &mut Vec<ImageAllotCI>) -> VkResult<()> {
 
-        let recorder = copyer.recorder();
+        // 1.send textures to the staging buffer of batch.
+        let staging_ranges = self.upload_staging_data(batch, infos)?;
 
-        // 1.create staging buffer and memories.
-        let (mut staging_repository, buffer_blocks) = self.create_staging_repository(device, infos)?;
-        // 2.send textures to the staging buffer.
-        self.upload_staging_data(&mut staging_repository, &buffer_blocks, infos)?;
+        {
+            let copyer = batch.copyer();
 
-        // 3.upload image data from buffers to images.
-        self.upload_image_data(copyer, infos, &buffer_blocks);
+            // 2.upload image data from buffers to images.
+            self.upload_image_data(copyer, infos, &staging_ranges);
 
-        // 4.generate mipmap for each image if needed.
-        use crate::image::instance::base::mipmap::generate_mipmaps;
-        generate_mipmaps(recorder, &self.image_type, &self.info_indices, infos);
+            // 3.generate mipmap for each image if needed.
+            use crate::image::instance::base::mipmap::generate_mipmaps;
+            generate_mipmaps(copyer.recorder(), &self.info_indices, infos);
+        }
 
-        // 5.make image barrier transition for shader reading.
-        self.prepare_shader_read(recorder, infos);
+        // 4.generate mipmap by compute shader for the formats without blit support.
+        use crate::image::instance::base::downsample::generate_compute_mipmaps;
+        generate_compute_mipmaps(device, batch, &self.info_indices, infos)?;
 
-        // done. Keep the staging buffer data until the command buffer executes.
-        self.staging_repository = Some(staging_repository);
+        // 5.make image barrier transition for shader reading.
+        self.prepare_shader_read(batch.copyer().recorder(), infos);
 
+        // done. The staging data is kept by batch until the command buffer executes.
         Ok(())
     }
 }
@@ -66,42 +61,19 @@ impl SampleImageBarrierBundle {
         SampleImageBarrierBundle {
             image_type,
             info_indices: indices, dst_stage,
-            staging_repository: None,
-        }
-    }
-
-    fn create_staging_repository(&mut self, device: &GsDevice, infos: &Vec<ImageAllotCI>) -> VkResult<(GsBufferRepository<Staging>, Vec<GsImgsrcBuffer>)> {
-
-        let staging_buffer_count = self.info_indices.len();
-        let mut staging_indices = Vec::with_capacity(staging_buffer_count);
-
-        let mut staging_allocator = GsBufferAllocator::create(device, BufferStorageType::STAGING);
-
-        for &index in self.info_indices.iter() {
-
-            let img_info = GsImgsrcBuffer::new(infos[index].space);
-            let buffer_index = staging_allocator.assign(img_info)?;
-            staging_indices.push(buffer_index);
         }
-
-        let distributor = staging_allocator.allocate()?;
-
-        let staging_buffers: Vec<GsImgsrcBuffer> = staging_indices.into_iter()
-            .map(|index| distributor.acquire(index))
-            .collect();
-
-        Ok((distributor.into_repository(), staging_buffers))
     }
 
-    fn upload_staging_data(&self, staging_repository: &mut GsBufferRepository<Staging>, img_data_blocks: &[GsImgsrcBuffer], infos: &Vec<ImageAllotCI>) -> VkResult<()> {
+    fn upload_staging_data(&self, batch: &mut GsUploadBatch, infos: &Vec<ImageAllotCI>) -> VkResult<Vec<BufferFullCopyInfo>> {
 
-        let mut uploader = staging_repository.data_uploader()?;
+        let mut staging_ranges = Vec::with_capacity(self.info_indices.len());
 
-        for (&info_index, img_block) in self.info_indices.iter().zip(img_data_blocks.iter()) {
+        for &info_index in self.info_indices.iter() {
 
             match infos[info_index].backend.storage.source {
                 | ImageSource::UploadData(ref source) => {
-                    uploader.upload(img_block, &source.data)?;
+                    let block_size = infos[info_index].backend.storage.format.block_size();
+                    staging_ranges.push(batch.stage_texels(&source.data, block_size)?);
                 },
                 | _ => {
                     return Err(VkError::other("The data of sample image is missing."))
@@ -109,19 +81,22 @@ impl SampleImageBarrierBundle {
             }
         }
 
-        uploader.finish()?;
-
-        Ok(())
+        Ok(staging_ranges)
     }
 
-    fn upload_image_data(&self, copyer: &DataCopyer, infos: &mut [ImageAllotCI], src_blocks: &[GsImgsrcBuffer]) {
+    fn upload_image_data(&self, copyer: &DataCopyer, infos: &mut [ImageAllotCI], src_blocks: &[BufferFullCopyInfo]) {
 
         match self.image_type {
             | ImageInstanceType::CombinedImageSampler { .. }
-            | ImageInstanceType::SampledImage { .. } => {
+            | ImageInstanceType::SampledImage { .. }
+            | ImageInstanceType::Texture2DArray { .. }
+            | ImageInstanceType::Texture3D { .. } => {
                 use crate::image::instance::base::upload::upload_2d_image_data;
                 upload_2d_image_data(copyer, &self.info_indices, infos, src_blocks);
             },
+            | ImageInstanceType::StorageImage { .. } => {
+                unreachable!("Storage Images should be uploaded by StorageImageBarrierBundle.")
+            },
             | ImageInstanceType::CubeMapImage { .. } => {
                 use crate::image::instance::cubemap::upload::upload_cube_image_data;
                 upload_cube_image_data(copyer, &self.info_indices, infos, src_blocks);
@@ -130,6 +105,9 @@ impl SampleImageBarrierBundle {
             | ImageInstanceType::DepthStencilAttachment => {
                 unreachable!("Depth Stencil Images shouldn't be uploaded any data.")
             },
+            | ImageInstanceType::ColorAttachment => {
+                unreachable!("Color Attachments shouldn't be uploaded any data.")
+            },
         }
     }
 
@@ -165,7 +143,8 @@ fn shader_read_barrier(info: &mut ImageAllotCI, subrange: ImageSubRange) -> Imag
             debug_assert_eq!(info.current_layout, vk::ImageLayout::TRANSFER_DST_OPTIMAL);
         },
         | MipmapMethod::StepBlit
-        | MipmapMethod::BaseLevelBlit => {
+        | MipmapMethod::BaseLevelBlit
+        | MipmapMethod::ComputeDownsample(_) => {
             debug_assert_eq!(info.current_access, vk::AccessFlags::TRANSFER_READ);
             debug_assert_eq!(info.current_layout, vk::ImageLayout::TRANSFER_SRC_OPTIMAL);
         },
@@ -0,0 +1,641 @@
+
+use ash::vk;
+use ash::version::DeviceV1_0;
+
+use crate::core::GsDevice;
+use crate::core::device::{ GsLogicalDevice, DestroyHandle };
+
+use crate::image::allocator::ImageAllotCI;
+use crate::image::barrier::ImageBarrierCI;
+use crate::image::mipmap::{ MipmapMethod, MipmapFilter };
+use crate::image::view::{ ImageSubViewCI, ImageSubRange };
+use crate::image::instance::sampler::GsSampler;
+
+use crate::descriptor::{ GsDescriptorPool, GsDescriptorSetLayout, GsDescriptorSet, GsDescriptorType, ImageDescriptorType };
+use crate::descriptor::binding::{ DescriptorBindingCI, DescriptorBindingImgInfo, DescriptorMeta };
+use crate::pipeline::layout::{ GsPipelineLayout, GsPushConstantRange };
+use crate::pipeline::target::GsPipelineStage;
+use crate::pipeline::shader::shaderc::{ GsShaderCompiler, ShaderCompilePrefab, cast_shaderc_kind };
+use crate::memory::transfer::GsUploadBatch;
+use crate::command::GsCmdTransferApi;
+
+use crate::utils::wrapper::VKWrapperInfo;
+use crate::error::{ VkResult, VkError };
+use crate::types::vkuint;
+
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::{ ptr, mem, slice };
+use std::cmp::max;
+
+/// The width and height of the local workgroup in downsample shader.
+const DOWNSAMPLE_GROUP_SIZE: vkuint = 8;
+
+/// The compute shader to downsample level n-1 to level n of all the array layers.
+///
+/// `{FORMAT}` is replaced by the format qualifier of the storage image, and `{PREFIX}` by the type prefix of its texels("", "u" or "i").
+/// The source level is fetched through sampler, so sRGB texels are decoded to linear space by hardware,
+/// and the result is encoded back to sRGB manually since the storage image is written through its UNORM alias.
+/// Integer texels are filtered in float, and rounded to the nearest integer when they are written.
+const DOWNSAMPLE_SHADER_SOURCE: &str = r#"
+#version 450
+
+layout (local_size_x = 8, local_size_y = 8, local_size_z = 1) in;
+
+layout (binding = 0) uniform {PREFIX}sampler2DArray src_level;
+layout (binding = 1, {FORMAT}) uniform writeonly {PREFIX}image2DArray dst_level;
+
+layout (push_constant) uniform DownsampleParams {
+    uint filter_kind; // 0 for box filter, 1 for kaiser filter.
+    uint is_srgb;
+    uint is_bgra;
+} params;
+
+// the kaiser window(alpha = 4) covering the 4 texels around the 2x2 footprint of previous level.
+const float KAISER_WEIGHTS[4] = float[](0.0535, 0.4465, 0.4465, 0.0535);
+
+vec4 fetch_texel(ivec2 coord, int layer, ivec2 src_size) {
+    return vec4(texelFetch(src_level, ivec3(clamp(coord, ivec2(0), src_size - 1), layer), 0));
+}
+
+vec3 linear_to_srgb(vec3 color) {
+    bvec3 cutoff = lessThan(color, vec3(0.0031308));
+    vec3 higher = 1.055 * pow(color, vec3(1.0 / 2.4)) - 0.055;
+    vec3 lower  = color * 12.92;
+    return mix(higher, lower, cutoff);
+}
+
+void main() {
+
+    ivec3 texel = ivec3(gl_GlobalInvocationID);
+    ivec2 dst_size = imageSize(dst_level).xy;
+
+    if (texel.x >= dst_size.x || texel.y >= dst_size.y) {
+        return;
+    }
+
+    ivec2 src_size = textureSize(src_level, 0).xy;
+    ivec2 base = texel.xy * 2;
+    vec4 color = vec4(0.0);
+
+    if (params.filter_kind == 0) {
+        color += fetch_texel(base + ivec2(0, 0), texel.z, src_size);
+        color += fetch_texel(base + ivec2(1, 0), texel.z, src_size);
+        color += fetch_texel(base + ivec2(0, 1), texel.z, src_size);
+        color += fetch_texel(base + ivec2(1, 1), texel.z, src_size);
+        color *= 0.25;
+    } else {
+        for (int y = 0; y < 4; y++) {
+            for (int x = 0; x < 4; x++) {
+                float weight = KAISER_WEIGHTS[x] * KAISER_WEIGHTS[y];
+                color += weight * fetch_texel(base + ivec2(x - 1, y - 1), texel.z, src_size);
+            }
+        }
+    }
+
+    if (params.is_srgb == 1) {
+        color.rgb = linear_to_srgb(clamp(color.rgb, 0.0, 1.0));
+    }
+
+    // BGRA texels are written through the RGBA alias, so the channels are swapped back here.
+    if (params.is_bgra == 1) {
+        color = color.bgra;
+    }
+
+    imageStore(dst_level, texel, {STORE});
+}
+"#;
+
+/// The numeric type of texels read and written by downsample shader.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum TexelNumeric {
+    /// the float, UNORM and SNORM formats.
+    Float,
+    /// the UINT formats.
+    Uint,
+    /// the SINT formats.
+    Sint,
+}
+
+/// The format qualifier of storage image used to write the mip levels.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StorageQualifier {
+
+    name: &'static str,
+    numeric: TexelNumeric,
+}
+
+impl StorageQualifier {
+
+    /// Generate the downsample shader for this qualifier.
+    fn shader_source(&self) -> String {
+
+        let (prefix, store) = match self.numeric {
+            | TexelNumeric::Float => ("",  "color"),
+            | TexelNumeric::Uint  => ("u", "uvec4(round(color))"),
+            | TexelNumeric::Sint  => ("i", "ivec4(round(color))"),
+        };
+
+        DOWNSAMPLE_SHADER_SOURCE
+            .replace("{FORMAT}", self.name)
+            .replace("{PREFIX}", prefix)
+            .replace("{STORE}", store)
+    }
+}
+
+/// Return the format qualifier of storage image used to write the mip levels of `format`.
+///
+/// It returns `None` if the format can not be downsampled by compute shader.
+pub(crate) fn storage_qualifier(format: vk::Format) -> Option<StorageQualifier> {
+
+    let (name, numeric) = match storage_alias_format(format) {
+        | vk::Format::R8G8B8A8_UNORM           => ("rgba8",          TexelNumeric::Float),
+        | vk::Format::R8G8B8A8_SNORM           => ("rgba8_snorm",    TexelNumeric::Float),
+        | vk::Format::R8G8_UNORM               => ("rg8",            TexelNumeric::Float),
+        | vk::Format::R8G8_SNORM               => ("rg8_snorm",      TexelNumeric::Float),
+        | vk::Format::R8_UNORM                 => ("r8",             TexelNumeric::Float),
+        | vk::Format::R8_SNORM                 => ("r8_snorm",       TexelNumeric::Float),
+        | vk::Format::R16G16B16A16_UNORM       => ("rgba16",         TexelNumeric::Float),
+        | vk::Format::R16G16B16A16_SNORM       => ("rgba16_snorm",   TexelNumeric::Float),
+        | vk::Format::R16G16_UNORM             => ("rg16",           TexelNumeric::Float),
+        | vk::Format::R16_UNORM                => ("r16",            TexelNumeric::Float),
+        | vk::Format::R16G16B16A16_SFLOAT      => ("rgba16f",        TexelNumeric::Float),
+        | vk::Format::R16G16_SFLOAT            => ("rg16f",          TexelNumeric::Float),
+        | vk::Format::R16_SFLOAT               => ("r16f",           TexelNumeric::Float),
+        | vk::Format::R32G32B32A32_SFLOAT      => ("rgba32f",        TexelNumeric::Float),
+        | vk::Format::R32G32_SFLOAT            => ("rg32f",          TexelNumeric::Float),
+        | vk::Format::R32_SFLOAT               => ("r32f",           TexelNumeric::Float),
+        | vk::Format::B10G11R11_UFLOAT_PACK32  => ("r11f_g11f_b10f", TexelNumeric::Float),
+        | vk::Format::A2B10G10R10_UNORM_PACK32 => ("rgb10_a2",       TexelNumeric::Float),
+        | vk::Format::R8G8B8A8_UINT            => ("rgba8ui",        TexelNumeric::Uint),
+        | vk::Format::R8G8_UINT                => ("rg8ui",          TexelNumeric::Uint),
+        | vk::Format::R8_UINT                  => ("r8ui",           TexelNumeric::Uint),
+        | vk::Format::R16G16B16A16_UINT        => ("rgba16ui",       TexelNumeric::Uint),
+        | vk::Format::R16G16_UINT              => ("rg16ui",         TexelNumeric::Uint),
+        | vk::Format::R16_UINT                 => ("r16ui",          TexelNumeric::Uint),
+        | vk::Format::R32G32B32A32_UINT        => ("rgba32ui",       TexelNumeric::Uint),
+        | vk::Format::R32G32_UINT              => ("rg32ui",         TexelNumeric::Uint),
+        | vk::Format::R32_UINT                 => ("r32ui",          TexelNumeric::Uint),
+        | vk::Format::A2B10G10R10_UINT_PACK32  => ("rgb10_a2ui",     TexelNumeric::Uint),
+        | vk::Format::R8G8B8A8_SINT            => ("rgba8i",         TexelNumeric::Sint),
+        | vk::Format::R8G8_SINT                => ("rg8i",           TexelNumeric::Sint),
+        | vk::Format::R8_SINT                  => ("r8i",            TexelNumeric::Sint),
+        | vk::Format::R16G16B16A16_SINT        => ("rgba16i",        TexelNumeric::Sint),
+        | vk::Format::R16G16_SINT              => ("rg16i",          TexelNumeric::Sint),
+        | vk::Format::R16_SINT                 => ("r16i",           TexelNumeric::Sint),
+        | vk::Format::R32G32B32A32_SINT        => ("rgba32i",        TexelNumeric::Sint),
+        | vk::Format::R32G32_SINT              => ("rg32i",          TexelNumeric::Sint),
+        | vk::Format::R32_SINT                 => ("r32i",           TexelNumeric::Sint),
+        | _ => return None,
+    };
+
+    Some(StorageQualifier { name, numeric })
+}
+
+/// Return the format used to write the mip levels of `format` as storage image.
+///
+/// The sRGB formats are written through their UNORM alias, and the BGRA formats through the RGBA alias,
+/// since these formats rarely support storage image. The other formats are written directly.
+pub(crate) fn storage_alias_format(format: vk::Format) -> vk::Format {
+
+    match format {
+        | vk::Format::R8G8B8A8_SRGB  => vk::Format::R8G8B8A8_UNORM,
+        | vk::Format::R8G8_SRGB      => vk::Format::R8G8_UNORM,
+        | vk::Format::R8_SRGB        => vk::Format::R8_UNORM,
+        | vk::Format::B8G8R8A8_UNORM
+        | vk::Format::B8G8R8A8_SRGB  => vk::Format::R8G8B8A8_UNORM,
+        | _ => format,
+    }
+}
+
+/// Check if the compute shader could be dispatched in the transfer command buffer.
+///
+/// The downsample passes are recorded with the uploading commands, so the queue family of transfer queue must support compute operations.
+pub(crate) fn is_dispatch_support(device: &GsDevice) -> bool {
+
+    let family_index = device.logic.transfer_queue().queue().family_index;
+    device.phys.families.is_queue_support_capability(family_index, vk::QueueFlags::COMPUTE)
+}
+
+/// The push constants of downsample shader.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct DownsampleParams {
+
+    filter_kind: vkuint,
+    is_srgb    : vkuint,
+    is_bgra    : vkuint,
+}
+
+impl DownsampleParams {
+
+    fn as_bytes(&self) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(self as *const DownsampleParams as *const u8, mem::size_of::<DownsampleParams>())
+        }
+    }
+}
+
+/// The dispatch to generate one mip level of an image.
+struct DownsamplePass {
+
+    image: vk::Image,
+    /// the subrange of the generated level, covering all the array layers.
+    dst_range: ImageSubRange,
+    /// the subrange of the previous level, covering all the array layers.
+    src_range: ImageSubRange,
+
+    pipeline: vk::Pipeline,
+    set: vk::DescriptorSet,
+    params: DownsampleParams,
+    group_count: [vkuint; 3],
+}
+
+impl DownsamplePass {
+
+    /// Make the previous level readable in shader, and the generated level writable in shader.
+    fn prepare_barriers(&self) -> Vec<ImageBarrierCI> {
+
+        let src_barrier = ImageBarrierCI::with_handle(self.image, self.src_range.clone())
+            .access_mask(vk::AccessFlags::TRANSFER_READ, vk::AccessFlags::SHADER_READ)
+            .layout(vk::ImageLayout::TRANSFER_SRC_OPTIMAL, vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL)
+            .build();
+
+        let dst_barrier = ImageBarrierCI::with_handle(self.image, self.dst_range.clone())
+            .access_mask(vk::AccessFlags::empty(), vk::AccessFlags::SHADER_WRITE)
+            .layout(vk::ImageLayout::UNDEFINED, vk::ImageLayout::GENERAL)
+            .build();
+
+        vec![src_barrier, dst_barrier]
+    }
+
+    /// Transfer both levels back to transfer source layout, so the generated level is ready to be the source of next level.
+    fn finish_barriers(&self) -> Vec<ImageBarrierCI> {
+
+        let src_barrier = ImageBarrierCI::with_handle(self.image, self.src_range.clone())
+            .access_mask(vk::AccessFlags::SHADER_READ, vk::AccessFlags::TRANSFER_READ)
+            .layout(vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL, vk::ImageLayout::TRANSFER_SRC_OPTIMAL)
+            .build();
+
+        let dst_barrier = ImageBarrierCI::with_handle(self.image, self.dst_range.clone())
+            .access_mask(vk::AccessFlags::SHADER_WRITE, vk::AccessFlags::TRANSFER_READ)
+            .layout(vk::ImageLayout::GENERAL, vk::ImageLayout::TRANSFER_SRC_OPTIMAL)
+            .build();
+
+        vec![src_barrier, dst_barrier]
+    }
+}
+
+/// Generate the mip-chain by compute shader for the images using `MipmapMethod::ComputeDownsample`.
+///
+/// The base level of these images must be in `TRANSFER_SRC_OPTIMAL` layout.
+/// Each level is downsampled from the previous level, and all the array layers(or cube faces) of a level are generated by one dispatch.
+/// All the levels are left in `TRANSFER_SRC_OPTIMAL` layout, the same as `MipmapMethod::StepBlit`.
+///
+/// The pipelines are taken from the downsample cache of device, and the descriptors and views used here are destroyed after `batch` completes.
+pub(crate) fn generate_compute_mipmaps(device: &GsDevice, batch: &mut GsUploadBatch, info_indices: &[usize], infos: &[ImageAllotCI]) -> VkResult<()> {
+
+    let compute_indices: Vec<usize> = info_indices.iter()
+        .filter(|&&index| is_compute_downsample(&infos[index]))
+        .cloned().collect();
+
+    let set_count: vkuint = compute_indices.iter()
+        .map(|&index| infos[index].backend.image_ci.property.mip_levels - 1)
+        .sum();
+
+    if set_count == 0 {
+        return Ok(())
+    }
+
+    if is_dispatch_support(device) == false {
+        return Err(VkError::other("The queue family of transfer queue does not support compute operations for mipmap generation."))
+    }
+
+    let descriptor_pool = {
+        let mut pool_ci = GsDescriptorPool::new(vk::DescriptorPoolCreateFlags::empty());
+        pool_ci.add_pool_size(GsDescriptorType::Image(ImageDescriptorType::CombinedImageSampler), set_count);
+        pool_ci.add_pool_size(GsDescriptorType::Image(ImageDescriptorType::StorageImage), set_count);
+        pool_ci.set_pool_size_max(set_count);
+        pool_ci.build(device)?
+    };
+
+    let mut descriptor_writes = VKWrapperInfo::with_capability((set_count * 2) as usize);
+    // the passes of each mip level, starting from level 1.
+    let mut level_passes: Vec<Vec<DownsamplePass>> = Vec::new();
+    let mut pipeline_layout = vk::PipelineLayout::null();
+
+    for &index in compute_indices.iter() {
+
+        let image_info = &infos[index];
+
+        debug_assert_eq!(image_info.current_access, vk::AccessFlags::TRANSFER_READ);
+        debug_assert_eq!(image_info.current_layout, vk::ImageLayout::TRANSFER_SRC_OPTIMAL);
+
+        let filter = match image_info.backend.image_ci.property.mipmap {
+            | MipmapMethod::ComputeDownsample(filter) => filter,
+            | _ => unreachable!("Only the images using compute downsample are collected."),
+        };
+
+        let image_format = &image_info.backend.image_ci.specific.format;
+        let format: vk::Format = image_format.clone().into();
+        let storage_format = storage_alias_format(format);
+        let qualifier = storage_qualifier(format)
+            .ok_or(VkError::other(format!("vk::Format: {:?} is not support for mipmap generation by compute shader.", format)))?;
+
+        let handles = device.logic.downsample_cache().acquire(device, qualifier)?;
+        pipeline_layout = handles.pipeline_layout;
+
+        let params = DownsampleParams {
+            filter_kind: match filter {
+                | MipmapFilter::Box    => 0,
+                | MipmapFilter::Kaiser => 1,
+            },
+            is_srgb: if image_format.is_srgb() { 1 } else { 0 },
+            is_bgra: match format {
+                | vk::Format::B8G8R8A8_UNORM
+                | vk::Format::B8G8R8A8_SRGB => 1,
+                | _ => 0,
+            },
+        };
+
+        let dimension = image_info.backend.image_ci.specific.dimension;
+        let layer_count = image_info.backend.image_ci.property.array_layers;
+        let mip_levels  = image_info.backend.image_ci.property.mip_levels;
+
+        for level in 1..mip_levels {
+
+            let src_range = image_info.backend.view_ci.subrange.clone()
+                .with_layer(0, layer_count)
+                .with_mip_level(level - 1, 1);
+            let dst_range = image_info.backend.view_ci.subrange.clone()
+                .with_layer(0, layer_count)
+                .with_mip_level(level, 1);
+
+            let src_view = ImageSubViewCI::new(vk::ImageViewType::TYPE_2D_ARRAY, src_range.clone())
+                .build_handle(device, image_info.image.handle, format)?;
+            batch.hold_transient(DestroyHandle::ImageView(src_view));
+
+            let dst_view = ImageSubViewCI::new(vk::ImageViewType::TYPE_2D_ARRAY, dst_range.clone())
+                .build_handle(device, image_info.image.handle, storage_format)?;
+            batch.hold_transient(DestroyHandle::ImageView(dst_view));
+
+            let set_handle = descriptor_pool.allocate_single(device, &handles.set_layout)
+                .or(Err(VkError::device("Failed to allocate Descriptor Set.")))?;
+            let set = GsDescriptorSet::new(set_handle, handles.set_layout.clone());
+
+            descriptor_writes.push(source_binding(handles.sampler, src_view).write_info(&set));
+            descriptor_writes.push(storage_binding(dst_view).write_info(&set));
+
+            let dst_width  = max(dimension.width  >> level, 1);
+            let dst_height = max(dimension.height >> level, 1);
+
+            let pass = DownsamplePass {
+                image: image_info.image.handle,
+                dst_range, src_range,
+                pipeline: handles.pipeline,
+                set: set_handle, params,
+                group_count: [
+                    (dst_width  + DOWNSAMPLE_GROUP_SIZE - 1) / DOWNSAMPLE_GROUP_SIZE,
+                    (dst_height + DOWNSAMPLE_GROUP_SIZE - 1) / DOWNSAMPLE_GROUP_SIZE,
+                    layer_count,
+                ],
+            };
+
+            if level_passes.len() < level as usize {
+                level_passes.push(Vec::new());
+            }
+            level_passes[(level - 1) as usize].push(pass);
+        }
+    }
+
+    device.logic.update_descriptor_sets(descriptor_writes.borrow_info());
+    // the descriptor sets are freed together with the pool after the batch completes.
+    batch.hold_transient(descriptor_pool.into_destroy_handle());
+
+    let recorder = batch.copyer().recorder();
+
+    // the levels are generated in order, since each level is the source of next level.
+    for passes in level_passes.iter() {
+
+        let prepare_barriers = passes.iter()
+            .flat_map(|pass| pass.prepare_barriers().into_iter())
+            .collect();
+        recorder.image_pipeline_barrier(
+            vk::PipelineStageFlags::TRANSFER | vk::PipelineStageFlags::COMPUTE_SHADER, // src stage
+            vk::PipelineStageFlags::COMPUTE_SHADER, // dst stage
+            vk::DependencyFlags::empty(),
+            prepare_barriers
+        );
+
+        for pass in passes.iter() {
+            recorder.dispatch_compute(pass.pipeline, pipeline_layout, pass.set, pass.params.as_bytes(), pass.group_count);
+        }
+
+        let finish_barriers = passes.iter()
+            .flat_map(|pass| pass.finish_barriers().into_iter())
+            .collect();
+        recorder.image_pipeline_barrier(
+            vk::PipelineStageFlags::COMPUTE_SHADER, // src stage
+            vk::PipelineStageFlags::COMPUTE_SHADER | vk::PipelineStageFlags::TRANSFER, // dst stage
+            vk::DependencyFlags::empty(),
+            finish_barriers
+        );
+    }
+
+    // the tracked access and layout stay at TRANSFER_READ and TRANSFER_SRC_OPTIMAL for all the mip levels.
+    Ok(())
+}
+
+fn is_compute_downsample(info: &ImageAllotCI) -> bool {
+
+    match info.backend.image_ci.property.mipmap {
+        | MipmapMethod::ComputeDownsample(_) => true,
+        | _ => false,
+    }
+}
+
+/// The binding of the previous level, which is read by `texelFetch` in shader.
+fn source_binding(sampler: vk::Sampler, view: vk::ImageView) -> DescriptorBindingImgInfo {
+
+    DescriptorBindingImgInfo {
+        meta: DescriptorMeta {
+            binding: 0,
+            descriptor_type: GsDescriptorType::Image(ImageDescriptorType::CombinedImageSampler),
+        },
+        sampler_handle: sampler,
+        view_handle   : view,
+        dst_layout    : vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+    }
+}
+
+/// The binding of the generated level, which is written as storage image.
+fn storage_binding(view: vk::ImageView) -> DescriptorBindingImgInfo {
+
+    DescriptorBindingImgInfo {
+        meta: DescriptorMeta {
+            binding: 1,
+            descriptor_type: GsDescriptorType::Image(ImageDescriptorType::StorageImage),
+        },
+        sampler_handle: vk::Sampler::null(),
+        view_handle   : view,
+        dst_layout    : vk::ImageLayout::GENERAL,
+    }
+}
+
+/// The objects shared by all the downsample passes on a device.
+///
+/// They are created when the mipmaps are generated by compute shader for the first time.
+/// Each pipeline is built once for its storage format, so the downsample shader is only compiled for the formats not met before.
+pub(crate) struct GsDownsampleCache {
+
+    layouts: Option<DownsampleLayouts>,
+    /// the compute pipelines keyed by the format qualifier of storage image.
+    pipelines: HashMap<&'static str, vk::Pipeline>,
+}
+
+struct DownsampleLayouts {
+
+    sampler: GsSampler,
+    set_layout: GsDescriptorSetLayout,
+    pipeline_layout: vk::PipelineLayout,
+}
+
+/// The objects to record the downsample passes of an image.
+struct DownsampleHandles {
+
+    sampler: vk::Sampler,
+    set_layout: GsDescriptorSetLayout,
+    pipeline_layout: vk::PipelineLayout,
+    pipeline: vk::Pipeline,
+}
+
+impl GsDownsampleCache {
+
+    pub fn new() -> GsDownsampleCache {
+
+        GsDownsampleCache {
+            layouts: None,
+            pipelines: HashMap::new(),
+        }
+    }
+
+    /// Return the objects to downsample the images written through `qualifier`, creating them if they have not been created yet.
+    fn acquire(&mut self, device: &GsDevice, qualifier: StorageQualifier) -> VkResult<DownsampleHandles> {
+
+        if self.layouts.is_none() {
+            self.layouts = Some(DownsampleLayouts::new(device)?);
+        }
+
+        let layouts = self.layouts.as_ref().unwrap();
+
+        let pipeline = match self.pipelines.get(qualifier.name) {
+            | Some(&pipeline) => pipeline,
+            | None => {
+                let pipeline = layouts.create_pipeline(device, qualifier)?;
+                self.pipelines.insert(qualifier.name, pipeline);
+                pipeline
+            },
+        };
+
+        let handles = DownsampleHandles {
+            sampler: layouts.sampler.handle,
+            set_layout: layouts.set_layout.clone(),
+            pipeline_layout: layouts.pipeline_layout,
+            pipeline,
+        };
+        Ok(handles)
+    }
+
+    /// Release all the objects in this cache. They are destroyed when the device becomes idle.
+    pub fn discard(&mut self, device: &GsLogicalDevice) {
+
+        for (_, pipeline) in self.pipelines.drain() {
+            device.defer_destroy(DestroyHandle::Pipeline(pipeline));
+        }
+
+        // the sampler is released by its drop.
+        if let Some(layouts) = self.layouts.take() {
+            device.release_layout(DestroyHandle::PipelineLayout(layouts.pipeline_layout));
+            device.release_layout(DestroyHandle::DescriptorSetLayout(layouts.set_layout.handle));
+        }
+    }
+}
+
+impl DownsampleLayouts {
+
+    fn new(device: &GsDevice) -> VkResult<DownsampleLayouts> {
+
+        // the texels are accessed by texelFetch, so the filter of sampler is never used.
+        let sampler = GsSampler::new_descriptor(0)
+            .filter(vk::Filter::NEAREST, vk::Filter::NEAREST)
+            .mipmap(vk::SamplerMipmapMode::NEAREST, vk::SamplerAddressMode::CLAMP_TO_EDGE, vk::SamplerAddressMode::CLAMP_TO_EDGE, vk::SamplerAddressMode::CLAMP_TO_EDGE)
+            .build(device)?;
+
+        let set_layout = {
+            let mut set_layout_ci = GsDescriptorSetLayout::new(2);
+            set_layout_ci.add_binding(&source_binding(vk::Sampler::null(), vk::ImageView::null()), vk::ShaderStageFlags::COMPUTE);
+            set_layout_ci.add_binding(&storage_binding(vk::ImageView::null()), vk::ShaderStageFlags::COMPUTE);
+            set_layout_ci.build(device)?
+        };
+
+        let pipeline_layout = {
+            let mut pipeline_layout_ci = GsPipelineLayout::new();
+            pipeline_layout_ci.add_descriptor_layout(&set_layout);
+            pipeline_layout_ci.add_push_constant(GsPushConstantRange::new(GsPipelineStage::COMPUTE, 0, mem::size_of::<DownsampleParams>() as _));
+            pipeline_layout_ci.build(device)?
+        };
+
+        let layouts = DownsampleLayouts { sampler, set_layout, pipeline_layout };
+        Ok(layouts)
+    }
+
+    fn create_pipeline(&self, device: &GsDevice, qualifier: StorageQualifier) -> VkResult<vk::Pipeline> {
+
+        let mut compiler = GsShaderCompiler::setup(ShaderCompilePrefab::Vulkan)?;
+        let kind = cast_shaderc_kind(vk::ShaderStageFlags::COMPUTE);
+        let codes = compiler.compile_source_into_spirv(&qualifier.shader_source(), kind, "[Mipmap Downsample]", "main")?;
+
+        let module_ci = vk::ShaderModuleCreateInfo {
+            s_type    : vk::StructureType::SHADER_MODULE_CREATE_INFO,
+            p_next    : ptr::null(),
+            flags     : vk::ShaderModuleCreateFlags::empty(),
+            code_size : codes.len(),
+            p_code    : codes.as_ptr() as _,
+        };
+
+        let module = unsafe {
+            device.logic.handle.create_shader_module(&module_ci, None)
+                .or(Err(VkError::create("Shader Module")))?
+        };
+
+        let main = CString::new("main").unwrap();
+
+        let pipeline_ci = vk::ComputePipelineCreateInfo {
+            s_type: vk::StructureType::COMPUTE_PIPELINE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags : vk::PipelineCreateFlags::empty(),
+            stage : vk::PipelineShaderStageCreateInfo {
+                s_type : vk::StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
+                p_next : ptr::null(),
+                flags  : vk::PipelineShaderStageCreateFlags::empty(),
+                stage  : vk::ShaderStageFlags::COMPUTE,
+                module,
+                p_name : main.as_ptr(),
+                p_specialization_info: ptr::null(),
+            },
+            layout: self.pipeline_layout,
+            base_pipeline_handle: vk::Pipeline::null(),
+            base_pipeline_index : -1,
+        };
+
+        let handles = unsafe {
+            let handles = device.logic.handle.create_compute_pipelines(vk::PipelineCache::null(), &[pipeline_ci], None);
+            // the shader module is no longer needed after pipeline creation.
+            device.logic.handle.destroy_shader_module(module, None);
+
+            handles.or(Err(VkError::create("Compute Pipeline")))?
+        };
+
+        Ok(handles[0])
+    }
+}
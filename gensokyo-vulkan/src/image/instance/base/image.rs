@@ -5,9 +5,10 @@ use crate::core::GsDevice;
 
 use crate::image::target::{ GsImage, ImageTgtCI, ImagePropertyCI, ImageSpecificCI };
 use crate::image::view::{ ImageViewCI, ImageSubRange };
-use crate::image::storage::ImageStorageInfo;
+use crate::image::storage::{ ImageStorageInfo, ImageSource };
 use crate::image::instance::traits::{ ImageCICommonApi, ImageTgtCIApi, ImageViewCIApi };
 use crate::image::mipmap::MipmapMethod;
+use crate::image::instance::base::downsample;
 
 use crate::error::{ VkResult, VkError };
 use crate::types::vkuint;
@@ -24,16 +25,24 @@ impl From<ImageStorageInfo> for GsBackendImage {
 
     fn from(storage: ImageStorageInfo) -> GsBackendImage {
 
-        let property = ImagePropertyCI::default();
+        let mut property = ImagePropertyCI::default();
 
         let mut specific = ImageSpecificCI::default();
         specific.format    = storage.format.clone().into();
         specific.dimension = storage.dimension;
 
+        let mut view_ci = ImageViewCI::default();
+
+        // keep all the mip levels if the data contains prebuilt mip chain.
+        if let ImageSource::UploadData(ref data) = storage.source {
+            property.mip_levels = data.mip_levels();
+            view_ci.subrange.0.level_count = property.mip_levels;
+        }
+
         GsBackendImage {
             storage,
             image_ci: ImageTgtCI { property, specific },
-            view_ci: ImageViewCI::default(),
+            view_ci,
         }
     }
 }
@@ -46,16 +55,29 @@ impl ImageCICommonApi for GsBackendImage {
 
         match method {
             | MipmapMethod::Disable => {
-                self.image_ci.property.usages = vk::ImageUsageFlags::SAMPLED | vk::ImageUsageFlags::TRANSFER_DST;
-                self.image_ci.property.mip_levels = 1;
+                // keep the other usages(such as STORAGE) set by the image instance.
+                self.image_ci.property.usages = (self.image_ci.property.usages & !vk::ImageUsageFlags::TRANSFER_SRC) | vk::ImageUsageFlags::TRANSFER_DST;
+                // the prebuilt mip levels of image data are still kept.
+                let mip_levels = self.prebuilt_mip_levels();
+                self.image_ci.property.mip_levels = mip_levels;
                 self.view_ci.subrange.0.base_mip_level = 0;
-                self.view_ci.subrange.0.level_count    = 1;
+                self.view_ci.subrange.0.level_count    = mip_levels;
             },
             | MipmapMethod::StepBlit
             | MipmapMethod::BaseLevelBlit => {
                 let mip_level = self.estimate_mip_levels();
 
-                self.image_ci.property.usages = vk::ImageUsageFlags::SAMPLED | vk::ImageUsageFlags::TRANSFER_SRC | vk::ImageUsageFlags::TRANSFER_DST;
+                self.image_ci.property.usages = self.image_ci.property.usages | vk::ImageUsageFlags::TRANSFER_SRC | vk::ImageUsageFlags::TRANSFER_DST;
+                self.image_ci.property.mip_levels = mip_level;
+                self.view_ci.subrange.0.base_mip_level = 0;
+                self.view_ci.subrange.0.level_count    = mip_level;
+            },
+            | MipmapMethod::ComputeDownsample(_) => {
+                let mip_level = self.estimate_mip_levels();
+
+                // it ends in transfer source layout like blit.
+                // the usages for compute shader are added in `check_physical_support`, since it may fall back to blit on some devices.
+                self.image_ci.property.usages = self.image_ci.property.usages | vk::ImageUsageFlags::TRANSFER_SRC | vk::ImageUsageFlags::TRANSFER_DST;
                 self.image_ci.property.mip_levels = mip_level;
                 self.view_ci.subrange.0.base_mip_level = 0;
                 self.view_ci.subrange.0.level_count    = mip_level;
@@ -66,7 +88,8 @@ impl ImageCICommonApi for GsBackendImage {
     fn estimate_mip_levels(&self) -> vkuint {
 
         use std::cmp::max;
-        let max_extent = max(self.image_ci.specific.dimension.width, self.image_ci.specific.dimension.height) as f32;
+        let dimension = &self.image_ci.specific.dimension;
+        let max_extent = max(max(dimension.width, dimension.height), dimension.depth) as f32;
         (max_extent.log2().floor() as vkuint) + 1
     }
 
@@ -95,6 +118,10 @@ impl ImageTgtCIApi for GsBackendImage {
     fn set_share_queues(&mut self, queue_family_indices: Vec<vkuint>) {
         self.image_ci.specific.share_queue_families(Some(queue_family_indices));
     }
+
+    fn set_mutable_format(&mut self) {
+        self.image_ci.property.flags = self.image_ci.property.flags | vk::ImageCreateFlags::MUTABLE_FORMAT;
+    }
 }
 
 // Property setting for vk::ImageView.
@@ -111,7 +138,39 @@ impl ImageViewCIApi for GsBackendImage {
 
 impl GsBackendImage {
 
-    pub fn check_physical_support(&self, device: &GsDevice) -> VkResult<()> {
+    fn prebuilt_mip_levels(&self) -> vkuint {
+
+        match self.storage.source {
+            | ImageSource::UploadData(ref data) => data.mip_levels(),
+            | ImageSource::NoSource => 1,
+        }
+    }
+
+    /// Add the image usages and flags required by `MipmapMethod::ComputeDownsample`.
+    fn set_compute_usages(&mut self) {
+
+        // each level is sampled as source and written as storage image.
+        self.image_ci.property.usages = self.image_ci.property.usages | vk::ImageUsageFlags::SAMPLED | vk::ImageUsageFlags::STORAGE;
+
+        // sRGB and BGRA formats are written through their alias, since they rarely support storage image.
+        let format = self.image_ci.specific.format.clone().into();
+        if downsample::storage_alias_format(format) != format {
+            self.image_ci.property.flags = self.image_ci.property.flags
+                | vk::ImageCreateFlags::MUTABLE_FORMAT | vk::ImageCreateFlags::EXTENDED_USAGE;
+        }
+    }
+
+    pub fn check_physical_support(&mut self, device: &GsDevice) -> VkResult<()> {
+
+        // compute downsample falls back to blit if the device could not run it.
+        if let MipmapMethod::ComputeDownsample(_) = self.image_ci.property.mipmap {
+            if self.image_ci.property.mipmap.is_support_by_device(device, &self.image_ci)? {
+                self.set_compute_usages();
+            } else if MipmapMethod::StepBlit.is_support_by_device(device, &self.image_ci)? {
+                // the mip levels and usages are the same as compute downsample.
+                self.image_ci.property.mipmap = MipmapMethod::StepBlit;
+            }
+        }
 
         // check mipmap support.
         if self.image_ci.property.mipmap.is_support_by_device(device, &self.image_ci)? == false {
@@ -3,23 +3,20 @@ use ash::vk;
 
 use crate::image::mipmap::{ MipmapMethod, MipmapBlitInfo };
 use crate::image::allocator::ImageAllotCI;
-use crate::image::enums::ImageInstanceType;
 use crate::image::barrier::ImageBarrierCI;
 
 use crate::command::{ GsCmdRecorder, GsCmdTransferApi };
 use crate::utils::phantom::Transfer;
-use crate::types::vkuint;
 
 use std::collections::HashSet;
 
 // visit http://cpp-rendering.io/mipmap-generation/ for detail.
-pub(super) fn generate_mipmaps(recorder: &GsCmdRecorder<Transfer>, image_type: &ImageInstanceType, info_indices: &Vec<usize>, infos: &mut Vec<ImageAllotCI>) {
+pub(crate) fn generate_mipmaps(recorder: &GsCmdRecorder<Transfer>, info_indices: &Vec<usize>, infos: &mut Vec<ImageAllotCI>) {
 
     use crate::image::mipmap;
 
     let mut candidate_indices: HashSet<usize> = info_indices.iter().cloned().collect();
     let mut current_level = 1; // mipmap generation start from level 1.
-    let blit_layer_count = blit_layer_count(image_type);
 
     while candidate_indices.is_empty() == false {
 
@@ -45,7 +42,7 @@ pub(super) fn generate_mipmaps(recorder: &GsCmdRecorder<Transfer>, image_type: &
                     blit_prepare_barriers.push(prepare_barrier);
 
                     // image blit information.
-                    let blit = mipmap::blit_info(image_info, current_level, blit_layer_count);
+                    let blit = mipmap::blit_info(image_info, current_level);
                     blit_infos.push(blit);
 
                     // barrier after image blit.
@@ -55,7 +52,9 @@ pub(super) fn generate_mipmaps(recorder: &GsCmdRecorder<Transfer>, image_type: &
                 | MipmapMethod::BaseLevelBlit => {
                     unimplemented!()
                 },
-                | MipmapMethod::Disable => {
+                | MipmapMethod::Disable
+                | MipmapMethod::ComputeDownsample(_) => {
+                    // the images using compute shader are downsampled by `generate_compute_mipmaps`.
                     indices_remove.push(index);
                 },
             };
@@ -106,20 +105,3 @@ pub(super) fn blit_image(recorder: &GsCmdRecorder<Transfer>, blit_commands: Vec<
         );
     }
 }
-
-pub(super) fn blit_layer_count(image_type: &ImageInstanceType) -> vkuint {
-
-    match image_type {
-        | ImageInstanceType::CombinedImageSampler { .. }
-        | ImageInstanceType::SampledImage { .. } => {
-            1
-        },
-        | ImageInstanceType::CubeMapImage { .. } => {
-            6
-        },
-        | ImageInstanceType::DepthStencilImage { .. }
-        | ImageInstanceType::DepthStencilAttachment => {
-            unreachable!("Depth Stencil Images don't use mipmap generation.")
-        },
-    }
-}
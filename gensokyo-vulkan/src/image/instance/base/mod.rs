@@ -2,7 +2,13 @@
 pub use self::barrier::SampleImageBarrierBundle;
 pub use self::image::GsBackendImage;
 
+pub(super) use self::upload::{ upload_2d_image_data, is_region_data, copy_image_regions };
+pub(super) use self::mipmap::generate_mipmaps;
+pub(super) use self::downsample::generate_compute_mipmaps;
+
 mod image;
 mod barrier;
 mod mipmap;
 mod upload;
+
+pub(crate) mod downsample;
@@ -5,21 +5,29 @@ use crate::image::mipmap::MipmapMethod;
 use crate::image::allocator::ImageAllotCI;
 use crate::image::copy::ImageCopiable;
 use crate::image::barrier::ImageBarrierCI;
+use crate::image::storage::ImageSource;
+use crate::image::copy::{ ImageRangesCopyInfo, ImageCopyRange };
 
-use crate::buffer::BufferCopiable;
-use crate::buffer::instance::GsImgsrcBuffer;
+use crate::buffer::{ BufferFullCopyInfo, BufferCopyRanges };
 
 use crate::memory::transfer::DataCopyer;
 use crate::command::GsCmdTransferApi;
+use crate::types::vkDim3D;
 
-pub(super) fn upload_2d_image_data(copyer: &DataCopyer, info_indices: &[usize], infos: &mut [ImageAllotCI], src_blocks: &[GsImgsrcBuffer]) {
+pub(crate) fn upload_2d_image_data(copyer: &DataCopyer, info_indices: &[usize], infos: &mut [ImageAllotCI], src_blocks: &[BufferFullCopyInfo]) {
 
     transfer_prepare_transition(copyer, info_indices, infos);
 
     // copy buffer to base mipmap level image.
     for (i, &index) in info_indices.iter().enumerate() {
-        // copy the whole buffer to the base mip level(level 0) of image.
-        copyer.copy_buffer_to_image(src_blocks[i].full_copy(), infos[index].full_copy_mipmap(0));
+
+        if is_region_data(&infos[index]) {
+            // copy each prebuilt mip level and array layer.
+            copy_image_regions(copyer, &src_blocks[i], &infos[index]);
+        } else {
+            // copy the whole buffer to the base mip level(level 0) of image.
+            copyer.copy_buffer_to_image(src_blocks[i].clone(), infos[index].full_copy_mipmap(0));
+        }
     }
 
     transfer_finish_transition(copyer, info_indices, infos);
@@ -31,13 +39,13 @@ fn transfer_prepare_transition(copyer: &DataCopyer, info_indices: &[usize], info
     let transfer_dst_barriers: Vec<ImageBarrierCI> = info_indices.iter().map(|&index| {
 
         let image_info = &mut infos[index];
-        // copy to the base mip level of image.
+        // copy to the base mip level of image, or all the mip levels if the data contains prebuilt mip chain.
         // TODO: Consider the base layer and layer count.
-        let base_mip_level = image_info.backend.view_ci.subrange.clone()
-            .with_mip_level(0, 1); // base mip level is at 0.
+        let dst_mip_levels = image_info.backend.view_ci.subrange.clone()
+            .with_mip_level(0, image_info.backend.image_ci.property.mip_levels);
 
         use crate::image::barrier::transfer_dst_barrier;
-        transfer_dst_barrier(image_info, base_mip_level)
+        transfer_dst_barrier(image_info, dst_mip_levels)
 
     }).collect();
 
@@ -60,7 +68,8 @@ fn transfer_finish_transition(copyer: &DataCopyer, info_indices: &[usize], infos
         match image_info.backend.image_ci.property.mipmap {
             | MipmapMethod::Disable => None,
             | MipmapMethod::StepBlit
-            | MipmapMethod::BaseLevelBlit => {
+            | MipmapMethod::BaseLevelBlit
+            | MipmapMethod::ComputeDownsample(_) => {
                 let base_mip_level = image_info.backend.view_ci.subrange.clone()
                     .with_mip_level(0, 1); // base mip level is at 0.
 
@@ -80,3 +89,63 @@ fn transfer_finish_transition(copyer: &DataCopyer, info_indices: &[usize], infos
         );
     }
 }
+
+/// Check if the source data of the image contains the prebuilt mip levels or array layers.
+pub(crate) fn is_region_data(info: &ImageAllotCI) -> bool {
+
+    match info.backend.storage.source {
+        | ImageSource::UploadData(ref data) => data.regions.is_empty() == false,
+        | ImageSource::NoSource => false,
+    }
+}
+
+/// Copy each region of image data from the staging buffer to the corresponding mip level and array layer of the image.
+///
+/// The regions whose array layer is out of the image are ignored.
+pub(crate) fn copy_image_regions(copyer: &DataCopyer, src: &BufferFullCopyInfo, info: &ImageAllotCI) {
+
+    use std::cmp::max;
+
+    let regions = match info.backend.storage.source {
+        | ImageSource::UploadData(ref data) => &data.regions,
+        | ImageSource::NoSource => return,
+    };
+
+    let array_layers = info.backend.image_ci.property.array_layers;
+    let dimension = &info.backend.storage.dimension;
+
+    let (offsets, ranges): (Vec<_>, Vec<_>) = regions.iter()
+        .filter(|region| region.array_layer < array_layers)
+        .map(|region| {
+
+            let range = ImageCopyRange {
+                extent: vkDim3D {
+                    // the mip level comes from texture file, so the shift must not overflow.
+                    width : max(dimension.width.checked_shr(region.mip_level).unwrap_or(0), 1),
+                    height: max(dimension.height.checked_shr(region.mip_level).unwrap_or(0), 1),
+                    depth : max(dimension.depth.checked_shr(region.mip_level).unwrap_or(0), 1),
+                },
+                sub_resource_layers: vk::ImageSubresourceLayers {
+                    aspect_mask      : info.backend.view_ci.subrange.0.aspect_mask,
+                    mip_level        : region.mip_level,
+                    base_array_layer : region.array_layer,
+                    layer_count      : 1,
+                },
+            };
+
+            (src.offset + region.offset, range)
+        }).unzip();
+
+    let src_ranges = BufferCopyRanges {
+        handle: src.handle,
+        offsets,
+    };
+
+    let dst_ranges = ImageRangesCopyInfo {
+        handle: info.image.handle,
+        layout: info.current_layout,
+        ranges,
+    };
+
+    copyer.copy_buffer_to_image_ranges(src_ranges, dst_ranges);
+}
@@ -0,0 +1,66 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+
+use crate::image::barrier::ImageBarrierCI;
+use crate::image::allocator::ImageAllotCI;
+use crate::image::instance::traits::ImageBarrierBundleAbs;
+
+use crate::memory::transfer::GsUploadBatch;
+use crate::command::GsCmdTransferApi;
+use crate::error::VkResult;
+
+//  Color Attachment Image Barrier Bundle.
+pub struct ColorImageBarrierBundle {
+
+    info_indices: Vec<usize>,
+}
+
+impl ImageBarrierBundleAbs for ColorImageBarrierBundle {
+
+    fn make_barrier_transform(&mut self, _device: &GsDevice, batch: &mut GsUploadBatch, infos: &mut Vec<ImageAllotCI>) -> VkResult<()> {
+
+        let final_barriers = self.info_indices.iter()
+            .map(|&index| self.final_barrier(&mut infos[index])).collect();
+
+        // make the image readable in shader even if it is sampled before the first rendering.
+        let _ = batch.copyer().recorder().image_pipeline_barrier(
+            vk::PipelineStageFlags::TOP_OF_PIPE,
+            vk::PipelineStageFlags::FRAGMENT_SHADER | vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT,
+            vk::DependencyFlags::empty(),
+            final_barriers
+        );
+
+        Ok(())
+    }
+}
+
+impl ColorImageBarrierBundle {
+
+    pub fn new(indices: Vec<usize>) -> ColorImageBarrierBundle {
+        ColorImageBarrierBundle {
+            info_indices: indices,
+        }
+    }
+
+    fn final_barrier(&self, info: &mut ImageAllotCI) -> ImageBarrierCI {
+
+        // the transient attachments(such as multisampled attachments) are never sampled.
+        let (dst_access, dst_layout) = if info.backend.image_ci.property.usages.contains(vk::ImageUsageFlags::SAMPLED) {
+            (vk::AccessFlags::SHADER_READ, vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL)
+        } else {
+            (vk::AccessFlags::COLOR_ATTACHMENT_WRITE, vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL)
+        };
+
+        let barrier = ImageBarrierCI::new(&info.image, info.backend.view_ci.subrange.clone())
+            .access_mask(vk::AccessFlags::empty(), dst_access)
+            .layout(info.backend.image_ci.property.initial_layout, dst_layout)
+            .build();
+
+        info.current_access = dst_access;
+        info.current_layout = dst_layout;
+
+        barrier
+    }
+}
@@ -0,0 +1,143 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+
+use crate::image::target::GsImage;
+use crate::image::format::GsImageFormat;
+use crate::image::storage::{ ImageStorageInfo, ImageSource };
+use crate::image::enums::ImageInstanceType;
+use crate::image::instance::base::GsBackendImage;
+use crate::image::instance::color::{ GsColorAttachment, IColorAttachment };
+use crate::image::instance::api::ImageCIInheritApi;
+use crate::image::instance::traits::ImageCISpecificApi;
+use crate::image::allocator::ImageAllotCI;
+
+use crate::descriptor::binding::DescriptorMeta;
+use crate::descriptor::{ GsDescriptorType, ImageDescriptorType };
+
+use crate::error::{ VkResult, VkError };
+use crate::types::format::Format;
+use crate::types::{ vkuint, vkDim2D, vkDim3D };
+
+/// Color Attachment Create Info.
+pub struct ColorAttachmentCI {
+
+    backend: GsBackendImage,
+
+    descriptor: DescriptorMeta,
+}
+
+impl GsColorAttachment {
+
+    /// Create an offscreen color render target, which could be sampled in shader at `binding` after rendering.
+    pub fn new(binding: vkuint, dimension: vkDim2D, format: Format) -> ColorAttachmentCI {
+
+        let storage = ImageStorageInfo {
+            source: ImageSource::NoSource,
+            format: GsImageFormat::Uncompressed(format),
+            dimension: vkDim3D {
+                width : dimension.width,
+                height: dimension.height,
+                depth : 1,
+            },
+        };
+
+        let mut backend = GsBackendImage::from(storage);
+
+        backend.image_ci.property.image_type = vk::ImageType::TYPE_2D;
+        backend.image_ci.property.tiling     = vk::ImageTiling::OPTIMAL;
+        backend.image_ci.property.usages     = vk::ImageUsageFlags::COLOR_ATTACHMENT | vk::ImageUsageFlags::SAMPLED;
+
+        backend.view_ci.view_type = vk::ImageViewType::TYPE_2D;
+        backend.view_ci.subrange.0.aspect_mask = vk::ImageAspectFlags::COLOR;
+
+        let descriptor = DescriptorMeta {
+            binding,
+            descriptor_type: GsDescriptorType::Image(ImageDescriptorType::SampledImage),
+        };
+
+        ColorAttachmentCI { backend, descriptor }
+    }
+
+    /// Create a transient multisampled color attachment, whose samples are resolved to another attachment at the end of subpass.
+    ///
+    /// The image is not able to be sampled, and it's recommended to allocate it by `ImageStorageType::TRANSIENT`.
+    /// `sample_count` should be clamped by `GsPhysicalDevice::clamp_sample_count` before using.
+    pub fn multisample(dimension: vkDim2D, format: Format, sample_count: vk::SampleCountFlags) -> ColorAttachmentCI {
+
+        let mut ci = GsColorAttachment::new(0, dimension, format);
+        ci.backend.image_ci.property.usages       = vk::ImageUsageFlags::COLOR_ATTACHMENT | vk::ImageUsageFlags::TRANSIENT_ATTACHMENT;
+        ci.backend.image_ci.property.sample_count = sample_count;
+
+        ci
+    }
+
+    /// Create a transient color attachment, which is written by a subpass and read by the following subpasses as input attachment at `binding`.
+    ///
+    /// Its content only lives during the render pass, so it's recommended to allocate it by `ImageStorageType::TRANSIENT`.
+    pub fn transient(binding: vkuint, dimension: vkDim2D, format: Format) -> ColorAttachmentCI {
+
+        let mut ci = GsColorAttachment::new(binding, dimension, format);
+        ci.backend.image_ci.property.usages = vk::ImageUsageFlags::COLOR_ATTACHMENT | vk::ImageUsageFlags::TRANSIENT_ATTACHMENT | vk::ImageUsageFlags::INPUT_ATTACHMENT;
+        ci.descriptor.descriptor_type = GsDescriptorType::Image(ImageDescriptorType::InputAttachment);
+
+        ci
+    }
+}
+
+impl ImageCISpecificApi for ColorAttachmentCI {
+    type IConveyor = IColorAttachment;
+
+    fn check_physical_support(&mut self, device: &GsDevice) -> VkResult<()> {
+
+        let format = self.backend.image_ci.specific.format.clone().into();
+        let required_features = if self.backend.image_ci.property.usages.contains(vk::ImageUsageFlags::SAMPLED) {
+            vk::FormatFeatureFlags::COLOR_ATTACHMENT | vk::FormatFeatureFlags::SAMPLED_IMAGE
+        } else {
+            vk::FormatFeatureFlags::COLOR_ATTACHMENT
+        };
+
+        let is_color_support = match self.backend.image_ci.property.tiling {
+            | vk::ImageTiling::LINEAR => {
+                device.phys.formats.query_format_linear(format, required_features)?
+            },
+            | vk::ImageTiling::OPTIMAL => {
+                device.phys.formats.query_format_optimal(format, required_features)?
+            },
+            | _ => {
+                unreachable!("vk::ImageTiling should be LINEAR or OPTIMAL.")
+            },
+        };
+
+        if is_color_support {
+            Ok(())
+        } else {
+            Err(VkError::other(format!("vk::Format: {:?} is not support for Color Attachment", self.backend.image_ci.specific.format)))
+        }
+    }
+
+    fn refactor(self, _: &GsDevice, image: GsImage) -> VkResult<(ImageAllotCI, Self::IConveyor)> {
+
+        let is_transient = self.backend.image_ci.property.usages.contains(vk::ImageUsageFlags::TRANSIENT_ATTACHMENT);
+        let ica = IColorAttachment::new(self.descriptor, self.backend.storage.format.clone(), self.backend.image_ci.property.sample_count, is_transient);
+
+        let allot_cis = ImageAllotCI::new(
+            ImageInstanceType::ColorAttachment,
+            image, self.backend
+        );
+
+        Ok((allot_cis, ica))
+    }
+}
+
+impl ImageCIInheritApi for ColorAttachmentCI {
+
+    fn backend(&self) -> &GsBackendImage {
+        &self.backend
+    }
+
+    fn backend_mut(&mut self) -> &mut GsBackendImage {
+        &mut self.backend
+    }
+}
@@ -0,0 +1,135 @@
+
+use ash::vk;
+
+use crate::image::entity::ImageEntity;
+use crate::image::copy::{ ImageCopiable, ImageFullCopyInfo };
+use crate::image::instance::sampler::GsSamplerMirror;
+use crate::image::instance::traits::{ ImageInstance, IImageConveyor, ImageInstanceInfoDesc, ImageViewable };
+use crate::image::format::GsImageFormat;
+
+use crate::descriptor::binding::DescriptorMeta;
+use crate::descriptor::binding::{ DescriptorBindingImgInfo, DescriptorBindingImgTgt };
+
+use crate::pipeline::pass::{ RenderAttachmentCI, Color };
+use crate::types::{ vkuint, vkDim3D };
+
+/// Wrapper class of offscreen color render target.
+///
+/// The image is in `vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL` after allocation and after each render pass, so it could be sampled in shader.
+pub struct GsColorAttachment {
+
+    ica: IColorAttachment,
+
+    entity: ImageEntity,
+    desc: ImageInstanceInfoDesc,
+}
+
+pub struct IColorAttachment {
+
+    descriptor: DescriptorMeta,
+    format: GsImageFormat,
+    sample_count: vk::SampleCountFlags,
+    /// whether the content of the image only lives during the render pass.
+    is_transient: bool,
+}
+
+impl ImageInstance<IColorAttachment> for GsColorAttachment {
+
+    fn build(ica: IColorAttachment, entity: ImageEntity, desc: ImageInstanceInfoDesc) -> GsColorAttachment {
+        GsColorAttachment { ica, entity, desc }
+    }
+}
+
+impl ImageViewable for GsColorAttachment {
+
+    fn entity(&self) -> &ImageEntity {
+        &self.entity
+    }
+
+    fn desc(&self) -> &ImageInstanceInfoDesc {
+        &self.desc
+    }
+}
+
+impl GsColorAttachment {
+
+    /// Use this image as the color attachment of all the framebuffers in render pass.
+    ///
+    /// The attachment of a transient image is cleared at the beginning of render pass and its content is not stored.
+    pub fn attachment(&self) -> RenderAttachmentCI<Color> {
+
+        let frame_view = Color(vec![self.entity.view]);
+        let attachment = RenderAttachmentCI::create(frame_view, self.ica.format.clone().into());
+
+        if self.ica.is_transient {
+            // the samples are resolved to other attachment, or the content is only read by the following subpasses, so there is no need to store them.
+            attachment.sample(self.ica.sample_count)
+                .op(vk::AttachmentLoadOp::CLEAR, vk::AttachmentStoreOp::DONT_CARE)
+                .layout(vk::ImageLayout::UNDEFINED, vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL, vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL)
+        } else {
+            attachment
+        }
+    }
+
+    /// Use each image of `targets` as the color attachment of the framebuffer at the same index.
+    ///
+    /// The count of `targets` must be equal to the framebuffer count of render pass, and all the targets must be in the same format.
+    pub fn frame_attachment(targets: &[GsColorAttachment]) -> RenderAttachmentCI<Color> {
+
+        debug_assert!(targets.is_empty() == false);
+
+        let frame_view = Color(targets.iter().map(|t| t.entity.view).collect());
+        RenderAttachmentCI::create(frame_view, targets[0].ica.format.clone().into())
+    }
+}
+
+impl DescriptorBindingImgTgt for GsColorAttachment {
+
+    fn binding_info(&self) -> DescriptorBindingImgInfo {
+
+        DescriptorBindingImgInfo {
+            meta           : self.ica.descriptor.clone(),
+            sampler_handle : vk::Sampler::null(),
+            dst_layout     : vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+            view_handle    : self.entity.view,
+        }
+    }
+}
+
+impl ImageCopiable for GsColorAttachment {
+
+    fn full_copy_mipmap(&self, copy_mip_level: vkuint) -> ImageFullCopyInfo {
+
+        use std::cmp::max;
+
+        ImageFullCopyInfo {
+            handle: self.entity.image,
+            layout: self.desc.current_layout,
+            extent: vkDim3D {
+                width  : max(self.desc.dimension.width  >> copy_mip_level, 1),
+                height : max(self.desc.dimension.height >> copy_mip_level, 1),
+                depth  : 1,
+            },
+            sub_resource_layers: vk::ImageSubresourceLayers {
+                aspect_mask      : vk::ImageAspectFlags::COLOR,
+                mip_level        : copy_mip_level,
+                base_array_layer : 0,
+                layer_count      : 1,
+            },
+        }
+    }
+}
+
+impl IImageConveyor for IColorAttachment {
+
+    fn sampler_mirror(&self) -> Option<GsSamplerMirror> {
+        None
+    }
+}
+
+impl IColorAttachment {
+
+    pub(super) fn new(descriptor: DescriptorMeta, format: GsImageFormat, sample_count: vk::SampleCountFlags, is_transient: bool) -> IColorAttachment {
+        IColorAttachment { descriptor, format, sample_count, is_transient }
+    }
+}
@@ -0,0 +1,8 @@
+
+pub use self::image::{ GsColorAttachment, IColorAttachment };
+pub use self::ci::ColorAttachmentCI;
+pub use self::barrier::ColorImageBarrierBundle;
+
+mod image;
+mod ci;
+mod barrier;
@@ -59,7 +59,7 @@ impl CombinedImgSamplerCI {
 impl ImageCISpecificApi for CombinedImgSamplerCI {
     type IConveyor = ICombinedImg;
 
-    fn check_physical_support(&self, device: &GsDevice) -> VkResult<()> {
+    fn check_physical_support(&mut self, device: &GsDevice) -> VkResult<()> {
 
         self.backend.check_physical_support(device)
     }
@@ -4,7 +4,7 @@ use ash::vk;
 use crate::image::entity::ImageEntity;
 use crate::image::copy::{ ImageCopiable, ImageFullCopyInfo };
 use crate::image::instance::sampler::{ GsSampler, GsSamplerMirror };
-use crate::image::instance::traits::{ ImageInstance, IImageConveyor, ImageInstanceInfoDesc };
+use crate::image::instance::traits::{ ImageInstance, IImageConveyor, ImageInstanceInfoDesc, ImageViewable };
 
 use crate::descriptor::binding::{ DescriptorBindingImgInfo, DescriptorBindingImgTgt };
 use crate::types::{ vkuint, vkDim3D };
@@ -25,6 +25,17 @@ impl ImageInstance<ICombinedImg> for GsCombinedImgSampler {
     }
 }
 
+impl ImageViewable for GsCombinedImgSampler {
+
+    fn entity(&self) -> &ImageEntity {
+        &self.entity
+    }
+
+    fn desc(&self) -> &ImageInstanceInfoDesc {
+        &self.desc
+    }
+}
+
 impl DescriptorBindingImgTgt for GsCombinedImgSampler {
 
     fn binding_info(&self) -> DescriptorBindingImgInfo {
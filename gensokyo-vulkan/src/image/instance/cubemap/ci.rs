@@ -63,7 +63,7 @@ impl CubeMapImgCI {
 impl ImageCISpecificApi for CubeMapImgCI {
     type IConveyor = ICubeMap;
 
-    fn check_physical_support(&self, device: &GsDevice) -> VkResult<()> {
+    fn check_physical_support(&mut self, device: &GsDevice) -> VkResult<()> {
 
         self.backend.check_physical_support(device)
     }
@@ -74,7 +74,7 @@ impl ImageCISpecificApi for CubeMapImgCI {
         let isi = ICubeMap::new(sampler);
 
         let allot_cis = ImageAllotCI::new(
-            ImageInstanceType::CombinedImageSampler { stage: self.pipeline_stage },
+            ImageInstanceType::CubeMapImage { stage: self.pipeline_stage },
             image, self.backend,
         );
 
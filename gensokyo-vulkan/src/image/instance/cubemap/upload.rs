@@ -6,22 +6,30 @@ use crate::image::allocator::ImageAllotCI;
 use crate::image::copy::ImageCopiable;
 use crate::image::barrier::ImageBarrierCI;
 
-use crate::buffer::BufferCopiable;
-use crate::buffer::instance::GsImgsrcBuffer;
+use crate::buffer::{ BufferFullCopyInfo, BufferCopyRanges };
 
 use crate::memory::transfer::DataCopyer;
 use crate::command::GsCmdTransferApi;
+use crate::types::vkbytes;
 
 pub(in crate::image::instance)
-fn upload_cube_image_data(copyer: &DataCopyer, info_indices: &[usize], infos: &mut [ImageAllotCI], src_blocks: &[GsImgsrcBuffer]) {
+fn upload_cube_image_data(copyer: &DataCopyer, info_indices: &[usize], infos: &mut [ImageAllotCI], src_blocks: &[BufferFullCopyInfo]) {
 
     transfer_prepare_transition(copyer, info_indices, infos);
 
     for (i, &index) in info_indices.iter().enumerate() {
 
+        use crate::image::instance::base::{ is_region_data, copy_image_regions };
+        if is_region_data(&infos[index]) {
+            // the data contains the prebuilt mip levels of each face.
+            copy_image_regions(copyer, &src_blocks[i], &infos[index]);
+            continue
+        }
+
         const CUBEMAP_BUFFER_RANGE_COUNT: usize = 6;
 
-        let src_ranges = src_blocks[i].copy_split_ranges(CUBEMAP_BUFFER_RANGE_COUNT);
+        let src = &src_blocks[i];
+        let src_ranges = BufferCopyRanges::from_stride(src.handle, src.offset, src.size / CUBEMAP_BUFFER_RANGE_COUNT as vkbytes, CUBEMAP_BUFFER_RANGE_COUNT);
         // just copy to the base mipmap level.
         let dst_ranges = infos[index].full_copy_mipmap_layer_ranges(0);
 
@@ -40,13 +48,13 @@ fn transfer_prepare_transition(copyer: &DataCopyer, info_indices: &[usize], info
     let transfer_dst_barriers: Vec<ImageBarrierCI> = info_indices.iter().map(|&index| {
 
         let image_info = &mut infos[index];
-        // copy to the base mip level of image.
-        let base_mip_level = image_info.backend.view_ci.subrange.clone()
+        // copy to the base mip level of image, or all the mip levels if the data contains prebuilt mip chain.
+        let dst_mip_levels = image_info.backend.view_ci.subrange.clone()
             .with_layer(0, image_info.backend.image_ci.property.array_layers)
-            .with_mip_level(0, 1); // base mip level is at 0.
+            .with_mip_level(0, image_info.backend.image_ci.property.mip_levels);
 
         use crate::image::barrier::transfer_dst_barrier;
-        transfer_dst_barrier(image_info, base_mip_level)
+        transfer_dst_barrier(image_info, dst_mip_levels)
 
     }).collect();
 
@@ -69,7 +77,8 @@ fn transfer_finish_transition(copyer: &DataCopyer, info_indices: &[usize], infos
         match image_info.backend.image_ci.property.mipmap {
             | MipmapMethod::Disable => None,
             | MipmapMethod::StepBlit
-            | MipmapMethod::BaseLevelBlit => {
+            | MipmapMethod::BaseLevelBlit
+            | MipmapMethod::ComputeDownsample(_) => {
                 let base_mip_level = image_info.backend.view_ci.subrange.clone()
                     .with_layer(0, image_info.backend.image_ci.property.array_layers)
                     .with_mip_level(0, 1); // base mip level is at 0.
@@ -7,7 +7,7 @@ use crate::image::barrier::ImageBarrierCI;
 use crate::image::allocator::ImageAllotCI;
 use crate::image::instance::traits::ImageBarrierBundleAbs;
 
-use crate::memory::transfer::DataCopyer;
+use crate::memory::transfer::GsUploadBatch;
 use crate::command::GsCmdTransferApi;
 use crate::error::VkResult;
 
@@ -19,12 +19,12 @@ pub struct DSImageBarrierBundle {
 
 impl ImageBarrierBundleAbs for DSImageBarrierBundle {
 
-    fn make_barrier_transform(&mut self, _device: &GsDevice, copyer: &DataCopyer, infos: &mut Vec<ImageAllotCI>) -> VkResult<()> {
+    fn make_barrier_transform(&mut self, _device: &GsDevice, batch: &mut GsUploadBatch, infos: &mut Vec<ImageAllotCI>) -> VkResult<()> {
 
         let final_barriers = self.info_indices.iter()
             .map(|&index| self.final_barrier(&mut infos[index])).collect();
 
-        let _ = copyer.recorder().image_pipeline_barrier(
+        let _ = batch.copyer().recorder().image_pipeline_barrier(
             vk::PipelineStageFlags::TOP_OF_PIPE,
             vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS,
             vk::DependencyFlags::empty(),
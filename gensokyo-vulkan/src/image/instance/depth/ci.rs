@@ -46,12 +46,35 @@ impl GsDSAttachment {
 
         DSAttachmentCI { backend }
     }
+
+    /// Create a transient multisampled depth stencil attachment, used together with multisampled color attachments.
+    ///
+    /// It's recommended to allocate it by `ImageStorageType::TRANSIENT`.
+    pub fn multisample(dimension: vkDim2D, format: DepthStencilImageFormat, sample_count: vk::SampleCountFlags) -> DSAttachmentCI {
+
+        let mut ci = GsDSAttachment::new(dimension, format);
+        ci.backend.image_ci.property.usages       = vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT | vk::ImageUsageFlags::TRANSIENT_ATTACHMENT;
+        ci.backend.image_ci.property.sample_count = sample_count;
+
+        ci
+    }
+
+    /// Create a transient depth stencil attachment, whose content is only used during the render pass.
+    ///
+    /// It's recommended to allocate it by `ImageStorageType::TRANSIENT`.
+    pub fn transient(dimension: vkDim2D, format: DepthStencilImageFormat) -> DSAttachmentCI {
+
+        let mut ci = GsDSAttachment::new(dimension, format);
+        ci.backend.image_ci.property.usages = vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT | vk::ImageUsageFlags::TRANSIENT_ATTACHMENT;
+
+        ci
+    }
 }
 
 impl ImageCISpecificApi for DSAttachmentCI {
     type IConveyor = IDepthStencilImg;
 
-    fn check_physical_support(&self, device: &GsDevice) -> VkResult<()> {
+    fn check_physical_support(&mut self, device: &GsDevice) -> VkResult<()> {
 
         let is_depth_support = match self.backend.image_ci.property.tiling {
             | vk::ImageTiling::LINEAR => {
@@ -74,7 +97,7 @@ impl ImageCISpecificApi for DSAttachmentCI {
 
     fn refactor(self, _: &GsDevice, image: GsImage) -> VkResult<(ImageAllotCI, Self::IConveyor)> {
 
-        let idsi = IDepthStencilImg::new(self.backend.storage.format.clone(), self.backend.view_ci.subrange.0.aspect_mask);
+        let idsi = IDepthStencilImg::new(self.backend.storage.format.clone(), self.backend.view_ci.subrange.0.aspect_mask, self.backend.image_ci.property.sample_count);
 
         let allot_cis = ImageAllotCI::new(
             ImageInstanceType::DepthStencilAttachment,
@@ -4,7 +4,7 @@ use ash::vk;
 use crate::image::entity::ImageEntity;
 use crate::image::copy::{ ImageCopiable, ImageFullCopyInfo };
 use crate::image::instance::sampler::GsSamplerMirror;
-use crate::image::instance::traits::{ ImageInstance, IImageConveyor, ImageInstanceInfoDesc };
+use crate::image::instance::traits::{ ImageInstance, IImageConveyor, ImageInstanceInfoDesc, ImageViewable };
 use crate::image::format::GsImageFormat;
 
 use crate::pipeline::pass::{ RenderAttachmentCI, DepthStencil };
@@ -22,6 +22,7 @@ pub struct IDepthStencilImg {
 
     aspect: vk::ImageAspectFlags,
     format: GsImageFormat,
+    sample_count: vk::SampleCountFlags,
 }
 
 impl ImageInstance<IDepthStencilImg> for GsDSAttachment {
@@ -31,19 +32,31 @@ impl ImageInstance<IDepthStencilImg> for GsDSAttachment {
     }
 }
 
+impl ImageViewable for GsDSAttachment {
+
+    fn entity(&self) -> &ImageEntity {
+        &self.entity
+    }
+
+    fn desc(&self) -> &ImageInstanceInfoDesc {
+        &self.desc
+    }
+}
+
 impl GsDSAttachment {
 
     pub fn attachment(&self) -> RenderAttachmentCI<DepthStencil> {
 
         let frame_view = DepthStencil(self.entity.view.clone());
         RenderAttachmentCI::create(frame_view, self.idsi.format.clone().into())
+            .sample(self.idsi.sample_count)
     }
 }
 
 impl IDepthStencilImg {
 
-    pub(super) fn new(format: GsImageFormat, aspect: vk::ImageAspectFlags) -> IDepthStencilImg {
-        IDepthStencilImg { format, aspect }
+    pub(super) fn new(format: GsImageFormat, aspect: vk::ImageAspectFlags, sample_count: vk::SampleCountFlags) -> IDepthStencilImg {
+        IDepthStencilImg { format, aspect, sample_count }
     }
 }
 
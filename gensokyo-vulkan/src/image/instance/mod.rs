@@ -3,8 +3,14 @@ mod api;
 
 pub(super) mod base;
 pub mod depth;
+pub mod color;
 pub mod combinedimg;
 pub mod sampledimg;
 pub mod cubemap;
+pub mod array;
+pub mod volume;
+pub mod storage;
 pub mod sampler;
 pub mod traits;
+
+pub(crate) use self::base::downsample::GsDownsampleCache;
@@ -49,7 +49,7 @@ impl GsSampledImage {
 impl ImageCISpecificApi for SampledImageCI {
     type IConveyor = ISampledImg;
 
-    fn check_physical_support(&self, device: &GsDevice) -> VkResult<()> {
+    fn check_physical_support(&mut self, device: &GsDevice) -> VkResult<()> {
 
         self.backend.check_physical_support(device)
     }
@@ -4,7 +4,7 @@ use ash::vk;
 use crate::image::entity::ImageEntity;
 use crate::image::copy::{ ImageCopiable, ImageFullCopyInfo };
 use crate::image::instance::sampler::GsSamplerMirror;
-use crate::image::instance::traits::{ ImageInstance, IImageConveyor, ImageInstanceInfoDesc };
+use crate::image::instance::traits::{ ImageInstance, IImageConveyor, ImageInstanceInfoDesc, ImageViewable };
 
 use crate::descriptor::binding::DescriptorMeta;
 use crate::descriptor::binding::{ DescriptorBindingImgInfo, DescriptorBindingImgTgt };
@@ -32,6 +32,17 @@ impl ImageInstance<ISampledImg> for GsSampledImage {
     }
 }
 
+impl ImageViewable for GsSampledImage {
+
+    fn entity(&self) -> &ImageEntity {
+        &self.entity
+    }
+
+    fn desc(&self) -> &ImageInstanceInfoDesc {
+        &self.desc
+    }
+}
+
 impl DescriptorBindingImgTgt for GsSampledImage {
 
     fn binding_info(&self) -> DescriptorBindingImgInfo {
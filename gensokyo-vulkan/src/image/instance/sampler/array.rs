@@ -14,7 +14,7 @@ use crate::descriptor::binding::{ DescriptorBindingImgArrayInfo, DescriptorBindi
 use crate::types::vkuint;
 use crate::error::{ VkResult, VkError };
 
-use std::iter::Map;
+use std::iter::Cloned;
 use std::slice::Iter;
 
 pub struct SamplerArrayCI {
@@ -34,16 +34,18 @@ impl SamplerArrayCI {
     pub(crate) fn build(self, device: &GsDevice) -> VkResult<GsSamplerArray> {
 
         let mut handles = Vec::with_capacity(self.sampler_cis.len());
+        let mut mirrors = Vec::with_capacity(self.sampler_cis.len());
         for sampler in self.sampler_cis.into_iter() {
            let handle = unsafe {
                device.logic.handle.create_sampler(&sampler.take_ci(), None)
                    .or(Err(VkError::create("Sampler")))?
            };
             handles.push(handle);
+            mirrors.push(GsSamplerMirror::new(device, handle));
         }
 
         let result = GsSamplerArray {
-            handles,
+            handles, mirrors,
             descriptor: self.descriptor,
         };
         Ok(result)
@@ -53,6 +55,7 @@ impl SamplerArrayCI {
 pub struct GsSamplerArray {
 
     handles: Vec<vk::Sampler>,
+    mirrors: Vec<GsSamplerMirror>,
     descriptor: DescriptorArrayMeta,
 }
 
@@ -70,18 +73,9 @@ impl GsSamplerArray {
         }
     }
 
-    pub(crate) fn mirrors(&self) -> Map<Iter<vk::Sampler>, fn(&vk::Sampler) -> GsSamplerMirror> {
-        self.handles.iter().map(|handle| GsSamplerMirror(handle.clone()))
+    pub(crate) fn mirrors(&self) -> Cloned<Iter<GsSamplerMirror>> {
+        self.mirrors.iter().cloned()
     }
-
-    // pub(crate) fn destroy(&self, device: &GsDevice) {
-    //
-    //     self.handles.iter().for_each(|&handle| {
-    //         unsafe {
-    //             device.logic.handle.destroy_sampler(handle, None)
-    //         }
-    //     })
-    // }
 }
 
 impl DescriptorBindingImgArrayTgt for GsSamplerArray {
@@ -19,6 +19,10 @@ pub struct SamplerCI {
     ci: vk::SamplerCreateInfo,
 }
 
+// the `p_next` of vk::SamplerCreateInfo is always null, so it's safe to move it between threads.
+unsafe impl Send for SamplerCI {}
+unsafe impl Sync for SamplerCI {}
+
 impl GsSampler {
 
     pub fn new() -> SamplerCI {
@@ -85,7 +89,7 @@ impl SamplerCI {
                 .or(Err(VkError::create("Sampler")))?
         };
 
-        let sampler = GsSampler { handle, descriptor };
+        let sampler = GsSampler::new(device, handle, descriptor);
         Ok(sampler)
     }
 
@@ -1,28 +1,31 @@
 
 use ash::vk;
-use ash::version::DeviceV1_0;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
 
 use crate::descriptor::binding::DescriptorMeta;
 use crate::descriptor::binding::{ DescriptorBindingImgInfo, DescriptorBindingImgTgt };
 
+use std::sync::Arc;
+use std::hash::{ Hash, Hasher };
+
 pub struct GsSampler {
 
     pub(crate) handle : vk::Sampler,
     pub(crate) descriptor: DescriptorMeta,
+
+    mirror: GsSamplerMirror,
 }
 
 impl GsSampler {
 
-    // pub(crate) fn destroy(&self, device: &GsDevice) {
-    //     unsafe {
-    //         device.logic.handle.destroy_sampler(self.handle, None);
-    //     }
-    // }
+    pub(super) fn new(device: &GsDevice, handle: vk::Sampler, descriptor: DescriptorMeta) -> GsSampler {
+        GsSampler { handle, descriptor, mirror: GsSamplerMirror::new(device, handle) }
+    }
 
     pub(crate) fn mirror(&self) -> GsSamplerMirror {
-        GsSamplerMirror(self.handle.clone())
+        self.mirror.clone()
     }
 }
 
@@ -40,15 +43,45 @@ impl DescriptorBindingImgTgt for GsSampler {
 }
 
 // ----------------------------------------------------------------------------------------
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
-pub struct GsSamplerMirror(pub(super) vk::Sampler);
+/// A shared reference to `vk::Sampler`.
+///
+/// The `vk::Sampler` object will be destroyed when the last mirror is dropped and device no longer uses it.
+#[derive(Clone)]
+pub struct GsSamplerMirror(Arc<SamplerOwner>);
+
+struct SamplerOwner {
+
+    handle: vk::Sampler,
+    device: GsDevice,
+}
 
 impl GsSamplerMirror {
 
-    pub(crate) fn discard(&self, device: &GsDevice) {
-        unsafe {
-            device.logic.handle.destroy_sampler(self.0, None);
-        }
+    pub(super) fn new(device: &GsDevice, handle: vk::Sampler) -> GsSamplerMirror {
+        GsSamplerMirror(Arc::new(SamplerOwner { handle, device: device.clone() }))
+    }
+}
+
+impl Drop for SamplerOwner {
+
+    fn drop(&mut self) {
+        self.device.logic.defer_destroy(DestroyHandle::Sampler(self.handle));
+    }
+}
+
+impl Hash for GsSamplerMirror {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.handle.hash(state);
     }
 }
+
+impl PartialEq for GsSamplerMirror {
+
+    fn eq(&self, other: &GsSamplerMirror) -> bool {
+        self.0.handle == other.0.handle
+    }
+}
+
+impl Eq for GsSamplerMirror {}
 // ----------------------------------------------------------------------------------------
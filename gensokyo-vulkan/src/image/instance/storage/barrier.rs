@@ -0,0 +1,123 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+
+use crate::buffer::BufferFullCopyInfo;
+
+use crate::image::barrier::ImageBarrierCI;
+use crate::image::storage::ImageSource;
+use crate::image::enums::ImagePipelineStage;
+use crate::image::instance::base::{ upload_2d_image_data, generate_mipmaps, generate_compute_mipmaps };
+use crate::image::instance::traits::ImageBarrierBundleAbs;
+use crate::image::allocator::ImageAllotCI;
+
+use crate::memory::transfer::GsUploadBatch;
+use crate::command::{ GsCmdRecorder, GsCmdTransferApi };
+use crate::utils::phantom::Transfer;
+use crate::error::VkResult;
+
+/// Storage Image Barrier Bundle.
+pub struct StorageImageBarrierBundle {
+
+    info_indices: Vec<usize>,
+    dst_stage: ImagePipelineStage,
+}
+
+impl ImageBarrierBundleAbs for StorageImageBarrierBundle {
+
+    fn make_barrier_transform(&mut self, device: &GsDevice, batch: &mut GsUploadBatch, infos: &mut Vec<ImageAllotCI>) -> VkResult<()> {
+
+        // only the images with initial content need data uploading and mipmap generation.
+        let upload_indices: Vec<usize> = self.info_indices.iter()
+            .filter(|&&index| is_upload_required(&infos[index]))
+            .cloned().collect();
+
+        if upload_indices.is_empty() == false {
+
+            // 1.send image data to the staging buffer of batch.
+            let staging_ranges = upload_staging_data(batch, &upload_indices, infos)?;
+
+            {
+                let copyer = batch.copyer();
+
+                // 2.upload image data from buffers to images.
+                upload_2d_image_data(copyer, &upload_indices, infos, &staging_ranges);
+
+                // 3.generate mipmap for each image if needed.
+                generate_mipmaps(copyer.recorder(), &upload_indices, infos);
+            }
+
+            // also generate mipmap by compute shader for the formats without blit support.
+            generate_compute_mipmaps(device, batch, &upload_indices, infos)?;
+        }
+
+        // 4.transfer all the images to general layout for shader reading and writing.
+        self.prepare_shader_access(batch.copyer().recorder(), infos);
+
+        Ok(())
+    }
+}
+
+impl StorageImageBarrierBundle {
+
+    pub fn new(dst_stage: ImagePipelineStage, indices: Vec<usize>) -> StorageImageBarrierBundle {
+        StorageImageBarrierBundle {
+            info_indices: indices, dst_stage,
+        }
+    }
+
+    fn prepare_shader_access(&self, recorder: &GsCmdRecorder<Transfer>, infos: &mut Vec<ImageAllotCI>) {
+
+        let final_barriers = self.info_indices.iter().map(|&index| {
+
+            let image_info = &mut infos[index];
+            // the whole image is transferred to GENERAL layout, including all the array layers and mip levels.
+            let whole_range = image_info.backend.view_ci.subrange.clone()
+                .with_layer(0, image_info.backend.image_ci.property.array_layers)
+                .with_mip_level(0, image_info.backend.image_ci.property.mip_levels);
+
+            let barrier = ImageBarrierCI::new(&image_info.image, whole_range)
+                .access_mask(image_info.current_access, vk::AccessFlags::SHADER_READ | vk::AccessFlags::SHADER_WRITE)
+                .layout(image_info.current_layout, vk::ImageLayout::GENERAL)
+                .build();
+
+            image_info.current_access = vk::AccessFlags::SHADER_READ | vk::AccessFlags::SHADER_WRITE;
+            image_info.current_layout = vk::ImageLayout::GENERAL;
+
+            barrier
+
+        }).collect();
+
+        // the src stage also covers the images without any uploading.
+        recorder.image_pipeline_barrier(
+            vk::PipelineStageFlags::TRANSFER | vk::PipelineStageFlags::TOP_OF_PIPE, // src stage
+            self.dst_stage.into(), // dst stage
+            vk::DependencyFlags::empty(),
+            final_barriers
+        );
+    }
+}
+
+fn is_upload_required(info: &ImageAllotCI) -> bool {
+
+    match info.backend.storage.source {
+        | ImageSource::UploadData(_) => true,
+        | ImageSource::NoSource => false,
+    }
+}
+
+fn upload_staging_data(batch: &mut GsUploadBatch, upload_indices: &[usize], infos: &Vec<ImageAllotCI>) -> VkResult<Vec<BufferFullCopyInfo>> {
+
+    let mut staging_ranges = Vec::with_capacity(upload_indices.len());
+
+    for &info_index in upload_indices.iter() {
+
+        if let ImageSource::UploadData(ref source) = infos[info_index].backend.storage.source {
+            let block_size = infos[info_index].backend.storage.format.block_size();
+            staging_ranges.push(batch.stage_texels(&source.data, block_size)?);
+        }
+    }
+
+    Ok(staging_ranges)
+}
@@ -0,0 +1,127 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+
+use crate::image::target::GsImage;
+use crate::image::format::GsImageFormat;
+use crate::image::storage::{ ImageStorageInfo, ImageSource, ImageData };
+use crate::image::mipmap::MipmapMethod;
+use crate::image::enums::{ ImageInstanceType, ImagePipelineStage };
+use crate::image::instance::base::GsBackendImage;
+use crate::image::instance::traits::ImageCISpecificApi;
+use crate::image::instance::api::ImageCIInheritApi;
+use crate::image::instance::storage::image::{ GsStorageImage, IStorageImg };
+use crate::image::allocator::ImageAllotCI;
+
+use crate::descriptor::binding::DescriptorMeta;
+use crate::descriptor::{ GsDescriptorType, ImageDescriptorType };
+
+use crate::error::{ VkResult, VkError };
+use crate::types::format::Format;
+use crate::types::{ vkuint, vkDim2D, vkDim3D };
+
+/// Storage Image Create Info.
+pub struct StorageImageCI {
+
+    pipeline_stage: ImagePipelineStage,
+    backend: GsBackendImage,
+
+    descriptor: DescriptorMeta,
+}
+
+impl GsStorageImage {
+
+    /// Create a 2D image which could be written by shader through storage image descriptor.
+    ///
+    /// `pipeline_stage` is the first stage that accesses the image after allocation, usually `ImagePipelineStage::ComputeStage`.
+    pub fn new(binding: vkuint, dimension: vkDim2D, format: Format, pipeline_stage: ImagePipelineStage) -> StorageImageCI {
+
+        let storage = ImageStorageInfo {
+            source: ImageSource::NoSource,
+            format: GsImageFormat::Uncompressed(format),
+            dimension: vkDim3D {
+                width : dimension.width,
+                height: dimension.height,
+                depth : 1,
+            },
+        };
+
+        let mut backend = GsBackendImage::from(storage);
+        backend.image_ci.property.image_type = vk::ImageType::TYPE_2D;
+        backend.image_ci.property.tiling     = vk::ImageTiling::OPTIMAL;
+        // the image is also sampled by the following passes.
+        backend.image_ci.property.usages     = vk::ImageUsageFlags::STORAGE | vk::ImageUsageFlags::SAMPLED | vk::ImageUsageFlags::TRANSFER_DST;
+        backend.image_ci.property.mipmap     = MipmapMethod::Disable;
+
+        backend.view_ci.view_type = vk::ImageViewType::TYPE_2D;
+        backend.view_ci.subrange.0.aspect_mask = vk::ImageAspectFlags::COLOR;
+
+        let descriptor = DescriptorMeta {
+            binding,
+            descriptor_type: GsDescriptorType::Image(ImageDescriptorType::StorageImage),
+        };
+
+        StorageImageCI { pipeline_stage, backend, descriptor }
+    }
+}
+
+impl StorageImageCI {
+
+    /// Set the initial content of the image, which will be uploaded during allocation.
+    ///
+    /// If mipmap generation is enabled, the rest mip levels are generated from the uploaded data.
+    pub fn set_upload_data(&mut self, data: ImageData) {
+        self.backend.storage.source = ImageSource::UploadData(data);
+    }
+}
+
+impl ImageCISpecificApi for StorageImageCI {
+    type IConveyor = IStorageImg;
+
+    fn check_physical_support(&mut self, device: &GsDevice) -> VkResult<()> {
+
+        let format = self.backend.image_ci.specific.format.clone().into();
+
+        let is_storage_support = match self.backend.image_ci.property.tiling {
+            | vk::ImageTiling::LINEAR => {
+                device.phys.formats.query_format_linear(format, vk::FormatFeatureFlags::STORAGE_IMAGE)?
+            },
+            | vk::ImageTiling::OPTIMAL => {
+                device.phys.formats.query_format_optimal(format, vk::FormatFeatureFlags::STORAGE_IMAGE)?
+            },
+            | _ => {
+                unreachable!("vk::ImageTiling should be LINEAR or OPTIMAL.")
+            },
+        };
+
+        if is_storage_support == false {
+            return Err(VkError::other(format!("vk::Format: {:?} is not support for Storage Image.", self.backend.image_ci.specific.format)))
+        }
+
+        self.backend.check_physical_support(device)
+    }
+
+    fn refactor(self, _device: &GsDevice, image: GsImage) -> VkResult<(ImageAllotCI, Self::IConveyor)> {
+
+        let isi = IStorageImg::new(self.descriptor);
+
+        let allot_cis = ImageAllotCI::new(
+            ImageInstanceType::StorageImage { stage: self.pipeline_stage },
+            image, self.backend,
+        );
+
+        Ok((allot_cis, isi))
+    }
+}
+
+impl ImageCIInheritApi for StorageImageCI {
+
+    fn backend(&self) -> &GsBackendImage {
+        &self.backend
+    }
+
+    fn backend_mut(&mut self) -> &mut GsBackendImage {
+        &mut self.backend
+    }
+}
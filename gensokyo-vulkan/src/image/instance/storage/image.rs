@@ -0,0 +1,167 @@
+
+use ash::vk;
+
+use crate::image::entity::ImageEntity;
+use crate::image::barrier::ImageBarrierCI;
+use crate::image::view::ImageSubRange;
+use crate::image::copy::{ ImageCopiable, ImageFullCopyInfo };
+use crate::image::instance::sampler::GsSamplerMirror;
+use crate::image::instance::traits::{ ImageInstance, IImageConveyor, ImageInstanceInfoDesc, ImageViewable };
+
+use crate::descriptor::binding::DescriptorMeta;
+use crate::descriptor::binding::{ DescriptorBindingImgInfo, DescriptorBindingImgTgt };
+use crate::descriptor::{ GsDescriptorType, ImageDescriptorType };
+
+use crate::types::{ vkuint, vkDim3D };
+
+/// Wrapper class of Storage Image in Vulkan.
+///
+/// The image always stays in `vk::ImageLayout::GENERAL` after allocation,
+/// so it can be written by shader and then be read by the following shader without layout transition.
+pub struct GsStorageImage {
+
+    isi: IStorageImg,
+
+    entity: ImageEntity,
+    desc: ImageInstanceInfoDesc,
+}
+
+pub struct IStorageImg {
+
+    descriptor: DescriptorMeta,
+}
+
+/// The binding target to read a storage image through sampled image descriptor.
+pub struct StorageImageSampled {
+
+    descriptor: DescriptorMeta,
+    view: vk::ImageView,
+}
+
+impl ImageInstance<IStorageImg> for GsStorageImage {
+
+    fn build(isi: IStorageImg, entity: ImageEntity, desc: ImageInstanceInfoDesc) -> Self where Self: Sized {
+        GsStorageImage { isi, entity, desc }
+    }
+}
+
+impl ImageViewable for GsStorageImage {
+
+    fn entity(&self) -> &ImageEntity {
+        &self.entity
+    }
+
+    fn desc(&self) -> &ImageInstanceInfoDesc {
+        &self.desc
+    }
+}
+
+impl GsStorageImage {
+
+    /// Return the barrier to make the shader writes of the image visible to the following shader reads.
+    ///
+    /// Record it by `image_pipeline_barrier` with the writing stage(usually `vk::PipelineStageFlags::COMPUTE_SHADER`) as source stage,
+    /// and the reading stage(such as `vk::PipelineStageFlags::FRAGMENT_SHADER`) as destination stage.
+    pub fn write_to_read_barrier(&self) -> ImageBarrierCI {
+
+        ImageBarrierCI::with_handle(self.entity.image, self.whole_range())
+            .access_mask(vk::AccessFlags::SHADER_WRITE, vk::AccessFlags::SHADER_READ)
+            .layout(vk::ImageLayout::GENERAL, vk::ImageLayout::GENERAL)
+            .build()
+    }
+
+    /// Return the barrier to make the image writable again after it was read by shader.
+    pub fn read_to_write_barrier(&self) -> ImageBarrierCI {
+
+        ImageBarrierCI::with_handle(self.entity.image, self.whole_range())
+            .access_mask(vk::AccessFlags::SHADER_READ, vk::AccessFlags::SHADER_WRITE)
+            .layout(vk::ImageLayout::GENERAL, vk::ImageLayout::GENERAL)
+            .build()
+    }
+
+    /// The subresource range covering all the array layers and mip levels of this image.
+    fn whole_range(&self) -> ImageSubRange {
+
+        self.desc.subrange.clone()
+            .with_layer(0, self.desc.array_layers)
+            .with_mip_level(0, self.desc.mip_levels)
+    }
+
+    /// Return the binding target to sample this image in shader at `binding`.
+    ///
+    /// The descriptor type is `ImageDescriptorType::SampledImage`, so a separate sampler is required.
+    pub fn sampled(&self, binding: vkuint) -> StorageImageSampled {
+
+        StorageImageSampled {
+            descriptor: DescriptorMeta {
+                binding,
+                descriptor_type: GsDescriptorType::Image(ImageDescriptorType::SampledImage),
+            },
+            view: self.entity.view,
+        }
+    }
+}
+
+impl DescriptorBindingImgTgt for GsStorageImage {
+
+    fn binding_info(&self) -> DescriptorBindingImgInfo {
+
+        DescriptorBindingImgInfo {
+            meta           : self.isi.descriptor.clone(),
+            sampler_handle : vk::Sampler::null(),
+            dst_layout     : vk::ImageLayout::GENERAL,
+            view_handle    : self.entity.view,
+        }
+    }
+}
+
+impl DescriptorBindingImgTgt for StorageImageSampled {
+
+    fn binding_info(&self) -> DescriptorBindingImgInfo {
+
+        DescriptorBindingImgInfo {
+            meta           : self.descriptor.clone(),
+            sampler_handle : vk::Sampler::null(),
+            dst_layout     : vk::ImageLayout::GENERAL,
+            view_handle    : self.view,
+        }
+    }
+}
+
+impl ImageCopiable for GsStorageImage {
+
+    fn full_copy_mipmap(&self, copy_mip_level: vkuint) -> ImageFullCopyInfo {
+
+        use std::cmp::max;
+
+        ImageFullCopyInfo {
+            handle: self.entity.image,
+            layout: self.desc.current_layout,
+            extent: vkDim3D {
+                width : max(self.desc.dimension.width  >> copy_mip_level, 1),
+                height: max(self.desc.dimension.height >> copy_mip_level, 1),
+                depth : 1,
+            },
+            sub_resource_layers: vk::ImageSubresourceLayers {
+                aspect_mask      : vk::ImageAspectFlags::COLOR,
+                mip_level        : copy_mip_level,
+                base_array_layer : 0,
+                layer_count      : 1,
+            },
+        }
+    }
+}
+
+impl IImageConveyor for IStorageImg {
+
+    fn sampler_mirror(&self) -> Option<GsSamplerMirror> {
+        None
+    }
+}
+
+impl IStorageImg {
+
+    pub(super) fn new(descriptor: DescriptorMeta) -> IStorageImg {
+        IStorageImg { descriptor }
+    }
+}
@@ -0,0 +1,8 @@
+
+pub use self::image::{ GsStorageImage, IStorageImg, StorageImageSampled };
+pub use self::ci::StorageImageCI;
+pub use self::barrier::StorageImageBarrierBundle;
+
+mod image;
+mod ci;
+mod barrier;
@@ -10,7 +10,7 @@ use crate::image::entity::ImageEntity;
 use crate::image::copy::ImageCopiable;
 use crate::image::mipmap::MipmapMethod;
 use crate::image::allocator::ImageAllotCI;
-use crate::memory::transfer::DataCopyer;
+use crate::memory::transfer::GsUploadBatch;
 
 use crate::error::VkResult;
 use crate::types::{ vkuint, vkDim3D };
@@ -29,7 +29,7 @@ pub trait ImageCICommonApi: ImageTgtCIApi + ImageViewCIApi {
 pub trait ImageCISpecificApi: Sized {
     type IConveyor: IImageConveyor;
 
-    fn check_physical_support(&self, device: &GsDevice) -> VkResult<()>;
+    fn check_physical_support(&mut self, device: &GsDevice) -> VkResult<()>;
 
     fn refactor(self, device: &GsDevice, image: GsImage) -> VkResult<(ImageAllotCI, Self::IConveyor)>;
 }
@@ -41,6 +41,8 @@ pub trait ImageTgtCIApi: Sized {
     fn set_initial_layout(&mut self, layout: vk::ImageLayout);
     fn set_samples(&mut self, count: vk::SampleCountFlags, mip_levels: vkuint, array_layers: vkuint);
     fn set_share_queues(&mut self, queue_family_indices: Vec<vkuint>);
+    /// Allow the additional views of the image to use a different but compatible format.
+    fn set_mutable_format(&mut self);
 }
 
 pub trait ImageViewCIApi: Sized {
@@ -65,7 +67,7 @@ pub trait ImageViewCIApi: Sized {
 /// Image Barrier Bundle Abstract.
 pub trait ImageBarrierBundleAbs {
 
-    fn make_barrier_transform(&mut self, device: &GsDevice, copyer: &DataCopyer, allot_cis: &mut Vec<ImageAllotCI>) -> VkResult<()>;
+    fn make_barrier_transform(&mut self, device: &GsDevice, batch: &mut GsUploadBatch, allot_cis: &mut Vec<ImageAllotCI>) -> VkResult<()>;
 }
 
 pub trait IImageConveyor {
@@ -78,10 +80,24 @@ pub trait ImageInstance<I>: ImageCopiable {
     fn build(img: I, entity: ImageEntity, desc: ImageInstanceInfoDesc) -> Self where Self: Sized;
 }
 
+/// The image instance whose additional views can be created by `GsImageRepository::create_view`.
+pub trait ImageViewable {
+
+    fn entity(&self) -> &ImageEntity;
+
+    fn desc(&self) -> &ImageInstanceInfoDesc;
+}
+
 #[derive(Debug, Default)]
 pub struct ImageInstanceInfoDesc {
 
     pub current_layout: vk::ImageLayout,
     pub dimension: vkDim3D,
     pub subrange: ImageSubRange,
+
+    /// the format of the image, which is also the format of its default view.
+    pub format: vk::Format,
+    pub flags: vk::ImageCreateFlags,
+    pub mip_levels: vkuint,
+    pub array_layers: vkuint,
 }
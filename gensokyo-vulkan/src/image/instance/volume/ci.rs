@@ -0,0 +1,102 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+
+use crate::image::target::GsImage;
+use crate::image::enums::{ ImageInstanceType, ImagePipelineStage };
+use crate::image::storage::ImageStorageInfo;
+use crate::image::instance::base::GsBackendImage;
+use crate::image::mipmap::MipmapMethod;
+use crate::image::instance::traits::ImageCISpecificApi;
+use crate::image::instance::volume::image::{ GsTexture3D, ITexture3D };
+use crate::image::instance::api::ImageCIInheritApi;
+use crate::image::instance::sampler::{ GsSampler, SamplerCI };
+use crate::image::allocator::ImageAllotCI;
+
+use crate::descriptor::binding::DescriptorMeta;
+use crate::descriptor::{ GsDescriptorType, ImageDescriptorType };
+
+use crate::error::{ VkResult, VkError };
+use crate::types::vkuint;
+
+/// 3D Texture Create Info.
+pub struct Texture3DCI {
+
+    pipeline_stage: ImagePipelineStage,
+    backend: GsBackendImage,
+
+    sampler_ci: SamplerCI,
+}
+
+impl GsTexture3D {
+
+    /// Create a 3D texture sampled through combined image sampler.
+    ///
+    /// The depth of texture is `storage.dimension.depth`, and the slices must be tightly packed in `storage`.
+    pub fn new(binding: vkuint, storage: ImageStorageInfo, pipeline_stage: ImagePipelineStage) -> Texture3DCI {
+
+        let mut backend = GsBackendImage::from(storage);
+        backend.image_ci.property.image_type = vk::ImageType::TYPE_3D;
+        backend.image_ci.property.tiling     = vk::ImageTiling::OPTIMAL;
+        backend.image_ci.property.usages     = vk::ImageUsageFlags::SAMPLED | vk::ImageUsageFlags::TRANSFER_DST;
+        backend.image_ci.property.mipmap     = MipmapMethod::Disable; // default to disable mipmap generation.
+
+        backend.view_ci.view_type = vk::ImageViewType::TYPE_3D;
+
+        let mut sampler_ci = GsSampler::new();
+        sampler_ci.reset_descriptor(DescriptorMeta {
+            binding,
+            descriptor_type: GsDescriptorType::Image(ImageDescriptorType::CombinedImageSampler),
+        });
+
+        Texture3DCI { pipeline_stage, backend, sampler_ci }
+    }
+}
+
+impl Texture3DCI {
+
+    pub fn reset_sampler(&mut self, sampler_ci: SamplerCI) {
+        self.sampler_ci.reset_ci(sampler_ci);
+    }
+}
+
+impl ImageCISpecificApi for Texture3DCI {
+    type IConveyor = ITexture3D;
+
+    fn check_physical_support(&mut self, device: &GsDevice) -> VkResult<()> {
+
+        let dimension = &self.backend.image_ci.specific.dimension;
+        let max_dimension = device.phys.limits().max_image_dimension3_d;
+
+        if dimension.width > max_dimension || dimension.height > max_dimension || dimension.depth > max_dimension {
+            return Err(VkError::other(format!("The dimension({:?}) of 3D texture is not support by device.", dimension)))
+        }
+
+        self.backend.check_physical_support(device)
+    }
+
+    fn refactor(self, device: &GsDevice, image: GsImage) -> VkResult<(ImageAllotCI, Self::IConveyor)> {
+
+        let sampler = self.sampler_ci.build(device)?;
+        let isi = ITexture3D::new(sampler);
+
+        let allot_cis = ImageAllotCI::new(
+            ImageInstanceType::Texture3D { stage: self.pipeline_stage },
+            image, self.backend,
+        );
+
+        Ok((allot_cis, isi))
+    }
+}
+
+impl ImageCIInheritApi for Texture3DCI {
+
+    fn backend(&self) -> &GsBackendImage {
+        &self.backend
+    }
+
+    fn backend_mut(&mut self) -> &mut GsBackendImage {
+        &mut self.backend
+    }
+}
@@ -0,0 +1,95 @@
+
+use ash::vk;
+
+use crate::image::entity::ImageEntity;
+use crate::image::copy::{ ImageCopiable, ImageFullCopyInfo };
+use crate::image::instance::sampler::{ GsSampler, GsSamplerMirror };
+use crate::image::instance::traits::{ ImageInstance, IImageConveyor, ImageInstanceInfoDesc, ImageViewable };
+
+use crate::descriptor::binding::{ DescriptorBindingImgInfo, DescriptorBindingImgTgt };
+use crate::types::{ vkuint, vkDim3D };
+
+/// Wrapper class of 3D Texture in Vulkan, which is sampled by `sampler3D` in shader.
+pub struct GsTexture3D {
+
+    isi: ITexture3D,
+
+    entity: ImageEntity,
+    desc: ImageInstanceInfoDesc,
+}
+
+impl ImageInstance<ITexture3D> for GsTexture3D {
+
+    fn build(isi: ITexture3D, entity: ImageEntity, desc: ImageInstanceInfoDesc) -> Self where Self: Sized {
+        GsTexture3D { isi, entity, desc }
+    }
+}
+
+impl ImageViewable for GsTexture3D {
+
+    fn entity(&self) -> &ImageEntity {
+        &self.entity
+    }
+
+    fn desc(&self) -> &ImageInstanceInfoDesc {
+        &self.desc
+    }
+}
+
+impl DescriptorBindingImgTgt for GsTexture3D {
+
+    fn binding_info(&self) -> DescriptorBindingImgInfo {
+
+        DescriptorBindingImgInfo {
+            meta           : self.isi.sampler.descriptor.clone(),
+            sampler_handle : self.isi.sampler.handle,
+            dst_layout     : vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+            view_handle    : self.entity.view,
+        }
+    }
+}
+
+impl ImageCopiable for GsTexture3D {
+
+    fn full_copy_mipmap(&self, copy_mip_level: vkuint) -> ImageFullCopyInfo {
+
+        use std::cmp::max;
+
+        ImageFullCopyInfo {
+            handle: self.entity.image,
+            layout: self.desc.current_layout,
+            extent: vkDim3D {
+                width : max(self.desc.dimension.width  >> copy_mip_level, 1),
+                height: max(self.desc.dimension.height >> copy_mip_level, 1),
+                depth : max(self.desc.dimension.depth  >> copy_mip_level, 1),
+            },
+            sub_resource_layers: vk::ImageSubresourceLayers {
+                aspect_mask      : vk::ImageAspectFlags::COLOR,
+                mip_level        : copy_mip_level,
+                base_array_layer : 0,
+                layer_count      : 1,
+            },
+        }
+    }
+}
+
+pub struct ITexture3D {
+
+    // no need to destroy sampler manually.
+    // it will automatically destroy by GsImageRepository.
+    sampler: GsSampler,
+}
+
+impl ITexture3D {
+
+    pub(super) fn new(sampler: GsSampler) -> ITexture3D {
+        ITexture3D { sampler }
+    }
+}
+
+impl IImageConveyor for ITexture3D {
+
+    fn sampler_mirror(&self) -> Option<GsSamplerMirror> {
+        Some(self.sampler.mirror())
+    }
+}
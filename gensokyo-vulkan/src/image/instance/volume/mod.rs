@@ -0,0 +1,6 @@
+
+pub use self::image::{ GsTexture3D, ITexture3D };
+pub use self::ci::Texture3DCI;
+
+mod image;
+mod ci;
@@ -2,14 +2,17 @@
 use ash::vk;
 
 use crate::core::GsDevice;
+use crate::core::physical::DeviceExtensionType;
 
 use crate::image::ImageTgtCI;
 use crate::image::allocator::ImageAllotCI;
 use crate::image::barrier::ImageBarrierCI;
 use crate::image::view::ImageSubRange;
+use crate::image::instance::base::downsample;
 
 use crate::types::{ vkuint, vksint };
-use crate::error::VkResult;
+use crate::types::format::Format;
+use crate::error::{ VkResult, VkError };
 
 use std::cmp::max;
 
@@ -21,10 +24,49 @@ pub enum MipmapMethod {
     StepBlit,
     /// `BaseLevelBlit` specifies program to use the base image and blit down from that to all levels.
     BaseLevelBlit,
+    /// `ComputeDownsample` specifies program to downsample the whole mip-chain from level n-1 to n by compute shader.
+    ///
+    /// It's the fallback for the formats without blit support, and requires the format to support storage image
+    /// (or its alias: the UNORM format for sRGB formats, and the RGBA format for BGRA formats).
+    /// Float, UNORM, SNORM and integer formats are supported, where integer texels are rounded after filtering.
+    /// All the array layers(or cube faces) are downsampled, but 3D image is not supported.
+    ///
+    /// The queue family of transfer queue must support compute operations, and sRGB and BGRA formats also require `DeviceExtensionType::Maintenance2`.
+    /// If these requirements are not met, `MipmapMethod::StepBlit` is used instead when the format supports blit.
+    ComputeDownsample(MipmapFilter),
+}
+
+/// The filter used to downsample each mip level in `MipmapMethod::ComputeDownsample`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MipmapFilter {
+    /// `Box` averages the 2x2 texels of previous level.
+    Box,
+    /// `Kaiser` weights the 4x4 texels of previous level with a Kaiser window, which keeps the result sharper than `Box`.
+    Kaiser,
 }
 
 impl MipmapMethod {
 
+    /// Pick the mipmap method for images of `format` with optimal tiling.
+    ///
+    /// It returns `MipmapMethod::StepBlit` if the format supports blit, or falls back to `MipmapMethod::ComputeDownsample` with Box filter.
+    pub fn auto(device: &GsDevice, format: Format) -> VkResult<MipmapMethod> {
+
+        let is_blit_support = device.phys.formats.query_format_optimal(
+            format, vk::FormatFeatureFlags::BLIT_SRC | vk::FormatFeatureFlags::BLIT_DST
+        )?;
+
+        if is_blit_support {
+            return Ok(MipmapMethod::StepBlit)
+        }
+
+        if is_downsample_support(device, format.into(), vk::ImageTiling::OPTIMAL)? {
+            Ok(MipmapMethod::ComputeDownsample(MipmapFilter::Box))
+        } else {
+            Err(VkError::other(format!("vk::Format: {:?} supports neither blit nor storage image for mipmap generation.", format)))
+        }
+    }
+
     pub(crate) fn is_support_by_device(&self, device: &GsDevice, image_ci: &ImageTgtCI) -> VkResult<bool> {
 
         match self {
@@ -51,10 +93,50 @@ impl MipmapMethod {
                     },
                 }
             },
+            | MipmapMethod::ComputeDownsample(_) => {
+
+                if image_ci.property.image_type == vk::ImageType::TYPE_3D {
+                    return Ok(false)
+                }
+
+                is_downsample_support(device, image_ci.specific.format.clone().into(), image_ci.property.tiling)
+            },
         }
     }
 }
 
+/// Check if the mip levels of `format` could be downsampled by compute shader.
+///
+/// The previous level is sampled in `format`, and the next level is written through the storage alias of `format`.
+fn is_downsample_support(device: &GsDevice, format: vk::Format, tiling: vk::ImageTiling) -> VkResult<bool> {
+
+    if downsample::storage_qualifier(format).is_none() || downsample::is_dispatch_support(device) == false {
+        return Ok(false)
+    }
+
+    // the image written through its storage alias must be created with `vk::ImageCreateFlags::EXTENDED_USAGE`, which requires VK_KHR_maintenance2.
+    if downsample::storage_alias_format(format) != format && device.phys.extensions.is_enable(DeviceExtensionType::Maintenance2) == false {
+        return Ok(false)
+    }
+
+    let sample_format = Format::any(format);
+    let storage_format = Format::any(downsample::storage_alias_format(format));
+
+    match tiling {
+        | vk::ImageTiling::LINEAR => {
+            Ok(device.phys.formats.query_format_linear(sample_format, vk::FormatFeatureFlags::SAMPLED_IMAGE)?
+                && device.phys.formats.query_format_linear(storage_format, vk::FormatFeatureFlags::STORAGE_IMAGE)?)
+        },
+        | vk::ImageTiling::OPTIMAL => {
+            Ok(device.phys.formats.query_format_optimal(sample_format, vk::FormatFeatureFlags::SAMPLED_IMAGE)?
+                && device.phys.formats.query_format_optimal(storage_format, vk::FormatFeatureFlags::STORAGE_IMAGE)?)
+        },
+        | _ => {
+            unreachable!("vk::ImageTiling should be LINEAR or OPTIMAL.")
+        },
+    }
+}
+
 pub(super) struct MipmapBlitInfo {
 
     pub image : vk::Image,
@@ -66,9 +148,11 @@ pub(super) struct MipmapBlitInfo {
     pub blit: vk::ImageBlit,
 }
 
-pub(super) fn blit_info(image_info: &mut ImageAllotCI, round: vkuint, blit_layer_count: vkuint) -> MipmapBlitInfo {
+pub(super) fn blit_info(image_info: &mut ImageAllotCI, round: vkuint) -> MipmapBlitInfo {
 
     let image_dimension = &image_info.backend.image_ci.specific.dimension;
+    // each array layer is blitted separately by the same command, and the depth is blitted down for 3D image.
+    let blit_layer_count = image_info.backend.image_ci.property.array_layers;
 
     // image blit command.
     let image_blit = vk::ImageBlit {
@@ -83,7 +167,7 @@ pub(super) fn blit_info(image_info: &mut ImageAllotCI, round: vkuint, blit_layer
             vk::Offset3D {
                 x: max((image_dimension.width  >> (round - 1)) as vksint, 1),
                 y: max((image_dimension.height >> (round - 1)) as vksint, 1),
-                z: blit_layer_count as vksint,
+                z: max((image_dimension.depth  >> (round - 1)) as vksint, 1),
             },
         ],
         dst_subresource: vk::ImageSubresourceLayers {
@@ -97,7 +181,7 @@ pub(super) fn blit_info(image_info: &mut ImageAllotCI, round: vkuint, blit_layer
             vk::Offset3D {
                 x: max((image_dimension.width  >> round) as vksint, 1),
                 y: max((image_dimension.height >> round) as vksint, 1),
-                z: blit_layer_count as vksint,
+                z: max((image_dimension.depth  >> round) as vksint, 1),
             },
         ],
     };
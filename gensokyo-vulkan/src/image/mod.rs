@@ -1,9 +1,9 @@
 
 pub use self::target::{ GsImage, ImageTgtCI , ImagePropertyCI, ImageSpecificCI };
-pub use self::view::{ GsImageView, ImageViewCI, ImageSubRange };
+pub use self::view::{ GsImageView, ImageViewCI, ImageSubViewCI, ImageSubRange };
 pub use self::enums::{ ImagePipelineStage, DepthStencilImageFormat };
 pub use self::barrier::ImageBarrierCI;
-pub use self::mipmap::MipmapMethod;
+pub use self::mipmap::{ MipmapMethod, MipmapFilter };
 pub use self::format::GsImageFormat;
 pub use self::entity::ImageEntity;
 pub use self::repository::GsImageRepository;
@@ -1,12 +1,16 @@
 
+use ash::vk;
+
 use crate::core::GsDevice;
 
 use crate::image::target::GsImage;
-use crate::image::view::GsImageView;
+use crate::image::view::{ GsImageView, ImageSubViewCI };
 use crate::image::instance::sampler::GsSamplerMirror;
+use crate::image::instance::traits::ImageViewable;
 use crate::image::allocator::types::ImageMemoryTypeAbs;
 
 use crate::memory::instance::GsImageMemory;
+use crate::error::{ VkResult, VkError };
 
 use std::marker::PhantomData;
 use std::collections::HashSet;
@@ -38,6 +42,59 @@ impl<M> GsImageRepository<M>
             device, images, views, samplers, memory,
         }
     }
+
+    /// Create an additional view over `image`, which must be allocated by this repository.
+    ///
+    /// The view is destroyed together with the images of this repository.
+    pub fn create_view<I>(&mut self, image: &I, ci: ImageSubViewCI) -> VkResult<vk::ImageView>
+        where
+            I: ImageViewable {
+
+        let image_handle = image.entity().image;
+        let desc = image.desc();
+
+        if self.images.iter().any(|i| i.handle == image_handle) == false {
+            return Err(VkError::other("The image of the view does not belong to this repository."))
+        }
+
+        let subrange = &ci.subrange.0;
+        if subrange.level_count == 0 || subrange.base_mip_level + subrange.level_count > desc.mip_levels {
+            return Err(VkError::other("The mip levels of the view are out of the image."))
+        }
+        if subrange.layer_count == 0 || subrange.base_array_layer + subrange.layer_count > desc.array_layers {
+            return Err(VkError::other("The array layers of the view are out of the image."))
+        }
+        if desc.subrange.0.aspect_mask.contains(subrange.aspect_mask) == false {
+            return Err(VkError::other("The aspect mask of the view is not contained by the image."))
+        }
+
+        match ci.view_type {
+            | vk::ImageViewType::CUBE
+            | vk::ImageViewType::CUBE_ARRAY => {
+                if desc.flags.contains(vk::ImageCreateFlags::CUBE_COMPATIBLE) == false || subrange.layer_count % 6 != 0 {
+                    return Err(VkError::other("Cube view requires a cube compatible image and a multiple of 6 layers."))
+                }
+            },
+            | _ => {},
+        }
+
+        let format = match ci.format {
+            | Some(format) => {
+                let format: vk::Format = format.into();
+                if format != desc.format && desc.flags.contains(vk::ImageCreateFlags::MUTABLE_FORMAT) == false {
+                    return Err(VkError::other("Reinterpreting format requires the image to be created with mutable format."))
+                }
+                format
+            },
+            | None => desc.format,
+        };
+
+        let view = ci.build(&self.device, image_handle, format)?;
+        let view_handle = view.handle;
+        self.views.push(view);
+
+        Ok(view_handle)
+    }
 }
 
 impl<M> Drop for GsImageRepository<M>
@@ -46,9 +103,10 @@ impl<M> Drop for GsImageRepository<M>
 
     fn drop(&mut self) {
 
-        self.images.iter().for_each(|image| image.discard(&self.device));
-        self.views.iter().for_each(|view| view.discard(&self.device));
-        self.samplers.iter().for_each(|sampler| sampler.discard(&self.device));
+        // images, views and samplers must be released before their memory.
+        self.views.clear();
+        self.images.clear();
+        self.samplers.clear();
 
         self.memory.discard(&self.device);
     }
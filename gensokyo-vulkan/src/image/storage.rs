@@ -1,5 +1,5 @@
 
-use crate::types::{ vkbytes, vkDim3D };
+use crate::types::{ vkbytes, vkuint, vkDim3D };
 
 use crate::image::format::GsImageFormat;
 
@@ -22,12 +22,40 @@ pub struct ImageData {
 
     pub data: Vec<u8>,
     pub size: vkbytes,
+    /// `regions` describes where each mip level and array layer locates in `data`.
+    ///
+    /// It's empty if `data` only contains the base mip level of the image.
+    pub regions: Vec<ImageDataRegion>,
+}
+
+/// The location of the data of a specific mip level and array layer in `ImageData`.
+#[derive(Debug, Clone)]
+pub struct ImageDataRegion {
+
+    /// `offset` is the starting offset in bytes from the beginning of `ImageData::data`.
+    pub offset: vkbytes,
+    pub mip_level: vkuint,
+    pub array_layer: vkuint,
 }
 
 impl ImageData {
 
     pub fn new(data: Vec<u8>, size: vkbytes) -> ImageData {
 
-        ImageData { data, size }
+        ImageData { data, size, regions: Vec::new() }
+    }
+
+    /// Create the image data containing prebuilt mip levels or multiple array layers.
+    pub fn with_regions(data: Vec<u8>, size: vkbytes, regions: Vec<ImageDataRegion>) -> ImageData {
+
+        ImageData { data, size, regions }
+    }
+
+    /// Return the number of mip levels contained in the data.
+    pub fn mip_levels(&self) -> vkuint {
+
+        self.regions.iter()
+            .map(|region| region.mip_level + 1)
+            .max().unwrap_or(1)
     }
 }
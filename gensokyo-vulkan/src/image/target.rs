@@ -3,6 +3,7 @@ use ash::vk;
 use ash::version::DeviceV1_0;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
 
 use crate::image::mipmap::MipmapMethod;
 use crate::image::format::GsImageFormat;
@@ -21,6 +22,9 @@ pub struct GsImage {
 
     pub(crate) handle: vk::Image,
     requirement: vk::MemoryRequirements,
+
+    /// the device owning this image, or None if this image is not created by program(such as the image of swapchain).
+    device: Option<GsDevice>,
 }
 
 impl GsImage {
@@ -37,14 +41,18 @@ impl GsImage {
             device.logic.handle.get_image_memory_requirements(handle)
         };
 
-        let image = GsImage { handle, requirement };
+        let image = GsImage { handle, requirement, device: Some(device.clone()) };
         Ok(image)
     }
+}
 
-    pub fn discard(&self, device: &GsDevice) {
+impl Drop for GsImage {
 
-        unsafe {
-            device.logic.handle.destroy_image(self.handle, None);
+    /// The vk::Image object will be destroyed when device no longer uses it.
+    fn drop(&mut self) {
+
+        if let Some(ref device) = self.device {
+            device.logic.defer_destroy(DestroyHandle::Image(self.handle));
         }
     }
 }
@@ -58,7 +66,8 @@ impl From<vk::Image> for GsImage {
                 size: 0,
                 alignment: 0,
                 memory_type_bits: 0,
-            }
+            },
+            device: None,
         }
     }
 }
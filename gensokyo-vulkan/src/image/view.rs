@@ -3,6 +3,7 @@ use ash::vk;
 use ash::version::DeviceV1_0;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
 
 use crate::image::target::{ GsImage, ImageSpecificCI };
 use crate::image::format::GsImageFormat;
@@ -21,14 +22,31 @@ use std::ptr;
 pub struct GsImageView {
 
     pub(crate) handle: vk::ImageView,
+    device: GsDevice,
+}
+
+impl Drop for GsImageView {
+
+    /// The vk::ImageView object will be destroyed when device no longer uses it.
+    fn drop(&mut self) {
+
+        if self.handle != vk::ImageView::null() {
+            self.device.logic.defer_destroy(DestroyHandle::ImageView(self.handle));
+        }
+    }
 }
 
 impl GsImageView {
 
-    pub fn discard(&self, device: &GsDevice) {
+    /// Destroy the vk::ImageView object immediately, instead of waiting in the deferred destruction queue.
+    ///
+    /// The device must no longer use this view when calling this function.
+    pub(crate) fn destroy_immediately(mut self) {
+
         unsafe {
-            device.logic.handle.destroy_image_view(self.handle, None);
+            self.device.logic.handle.destroy_image_view(self.handle, None);
         }
+        self.handle = vk::ImageView::null();
     }
 }
 
@@ -79,7 +97,7 @@ impl ImageViewCI {
                 .or(Err(VkError::create("Image View")))?
         };
 
-        let view = GsImageView { handle };
+        let view = GsImageView { handle, device: device.clone() };
         Ok(view)
     }
 
@@ -108,6 +126,71 @@ impl Default for ImageViewCI {
     }
 }
 
+/// The create info of the additional view over an allocated image.
+///
+/// Use it with `GsImageRepository::create_view` to access a single mip level, a single face of cubemap,
+/// the depth aspect of depth stencil image, or to reinterpret the image with another compatible format.
+#[derive(Debug, Clone)]
+pub struct ImageSubViewCI {
+
+    pub(crate) view_type: vk::ImageViewType,
+    pub(crate) components: vk::ComponentMapping,
+    pub(crate) subrange: ImageSubRange,
+    /// `format` is `None` to use the format of the image.
+    pub(crate) format: Option<Format>,
+}
+
+impl ImageSubViewCI {
+
+    pub fn new(view_type: vk::ImageViewType, subrange: ImageSubRange) -> ImageSubViewCI {
+
+        ImageSubViewCI {
+            view_type, subrange,
+            components: ImageViewCI::default().components,
+            format: None,
+        }
+    }
+
+    /// Reinterpret the image with `format`, which requires the image to be created with `set_mutable_format`.
+    pub fn with_format(mut self, format: Format) -> ImageSubViewCI {
+        self.format = Some(format);
+        self
+    }
+
+    pub fn with_components(mut self, components: vk::ComponentMapping) -> ImageSubViewCI {
+        self.components = components;
+        self
+    }
+
+    pub(crate) fn build(&self, device: &GsDevice, image: vk::Image, format: vk::Format) -> VkResult<GsImageView> {
+
+        let handle = self.build_handle(device, image, format)?;
+
+        let view = GsImageView { handle, device: device.clone() };
+        Ok(view)
+    }
+
+    /// Create the view handle in `format`, whose destruction is managed by the caller.
+    pub(crate) fn build_handle(&self, device: &GsDevice, image: vk::Image, format: vk::Format) -> VkResult<vk::ImageView> {
+
+        let image_view_ci = vk::ImageViewCreateInfo {
+            s_type     : vk::StructureType::IMAGE_VIEW_CREATE_INFO,
+            p_next     : ptr::null(),
+            flags      : vk::ImageViewCreateFlags::empty(),
+            image,
+            view_type  : self.view_type,
+            format,
+            components : self.components,
+            subresource_range : self.subrange.0,
+        };
+
+        unsafe {
+            device.logic.handle.create_image_view(&image_view_ci, None)
+                .or(Err(VkError::create("Image View")))
+        }
+    }
+}
+
 
 #[derive(Debug, Clone)]
 pub struct ImageSubRange(pub(super) vk::ImageSubresourceRange);
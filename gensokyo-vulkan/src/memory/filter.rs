@@ -35,11 +35,19 @@ impl MemoryFilter {
 
     pub fn filter(&mut self, dst_entity: &impl MemoryDstEntity) -> VkResult<()> {
 
-        let new_candidates = self.device.phys.memory.find_memory_type(
-            dst_entity.type_bytes(),
-            self.memory_flag,
-            if self.candidate_memories.is_empty() { None } else { Some(&self.candidate_memories) }
-        );
+        let mut new_candidates = self.find_candidates(dst_entity);
+
+        if new_candidates.is_empty() && self.memory_flag.contains(vk::MemoryPropertyFlags::LAZILY_ALLOCATED) {
+            // lazily allocated memory is not available on most desktop devices, so fall back to the ordinary device memory.
+            self.memory_flag = self.memory_flag & !vk::MemoryPropertyFlags::LAZILY_ALLOCATED;
+            new_candidates = self.find_candidates(dst_entity);
+        }
+
+        if new_candidates.is_empty() && self.memory_flag.contains(vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_CACHED) {
+            // host cached memory is not guaranteed to exist, so fall back to the uncached host memory.
+            self.memory_flag = GsMemoryType::HostMemory.property_flags();
+            new_candidates = self.find_candidates(dst_entity);
+        }
 
         if new_candidates.is_empty() {
             Err(VkError::device(MEMORY_FILTER_ERROR_MESSAGE))
@@ -49,6 +57,15 @@ impl MemoryFilter {
         }
     }
 
+    fn find_candidates(&self, dst_entity: &impl MemoryDstEntity) -> Vec<usize> {
+
+        self.device.phys.memory.find_memory_type(
+            dst_entity.type_bytes(),
+            self.memory_flag,
+            if self.candidate_memories.is_empty() { None } else { Some(&self.candidate_memories) }
+        )
+    }
+
     pub fn optimal_memory(&self) -> VkResult<usize> {
 
         // TODO: Use better method to find optimal memory
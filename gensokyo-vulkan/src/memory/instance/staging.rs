@@ -3,10 +3,11 @@ use ash::vk;
 
 use crate::core::GsDevice;
 
-use crate::buffer::{ GsBuffer, BufferBlock, BufferCopiable };
+use crate::buffer::{ BufferBlock, BufferCopiable, BufferFullCopyInfo };
 use crate::buffer::allocator::BufferAllocateInfos;
 
 use crate::memory::target::GsMemory;
+use crate::memory::StagingLease;
 use crate::memory::types::GsMemoryType;
 use crate::memory::utils::{ MemoryMapStatus, MemoryRange, MemoryMapAlias, MemoryWritePtr };
 use crate::memory::traits::{ GsMemoryAbstract, MemoryMappable };
@@ -16,7 +17,6 @@ use crate::memory::instance::BufferMemoryAbs;
 use crate::memory::transfer::MemoryDataDelegate;
 
 use crate::error::{ VkResult, VkError };
-use crate::utils::phantom::Staging;
 use crate::types::vkbytes;
 
 
@@ -134,74 +134,48 @@ impl MemoryDataDelegate for StagingDataAgency {
 }
 
 
+/// The staging resource used to upload data to buffers in device local memory.
+///
+/// The staging buffer is lent from the staging pool of device, and returns to the pool after transfer.
 pub struct UploadStagingResource {
 
-    buffers: Vec<GsBuffer>,
-    src_memory: GsStagingMemory,
+    lease: Option<StagingLease>,
 
-    src_blocks: Vec<BufferBlock>,
+    src_blocks: Vec<BufferFullCopyInfo>,
     dst_blocks: Vec<BufferBlock>,
-
-    ranges_to_flush: Vec<MemoryRange>,
 }
 
 impl UploadStagingResource {
 
     pub fn new(device: &GsDevice, allocate_infos: &BufferAllocateInfos) -> VkResult<UploadStagingResource> {
 
-        let mut memory_filter = MemoryFilter::new(device, GsMemoryType::StagingMemory);
-
-        // generate buffers
-        let mut buffers = vec![];
-        for buffer_ci in allocate_infos.cis.iter() {
-
-            let buffer = buffer_ci.build(device, Staging)?;
-            memory_filter.filter(&buffer)?;
-            buffers.push(buffer);
-        }
-
-        // allocate memory
-        let mut src_memory = GsStagingMemory::allocate(
-            device, allocate_infos.spaces.iter().sum(), &memory_filter
-        )?;
-
-        // bind buffers to memory
-        let mut offset = 0;
-        for (i, buffer) in buffers.iter().enumerate() {
-            src_memory.bind_to_buffer(device, &buffer, offset)?;
-            offset += allocate_infos.spaces[i];
-        }
-
-        src_memory.map_range(device, None)?;
+        // the data of each buffer locates at the same offset as its memory offset in destination memory.
+        let total_size = allocate_infos.spaces.iter().sum();
+        let lease = device.logic.staging_pool().acquire(device, total_size)?;
 
         let resource = UploadStagingResource {
-            buffers, src_memory,
+            lease: Some(lease),
             src_blocks: vec![],
             dst_blocks: vec![],
-            ranges_to_flush: vec![],
         };
 
         Ok(resource)
     }
 
-    pub fn append_dst_block(&mut self, to: &BufferBlock, repository_index: usize) -> VkResult<MemoryWritePtr> {
+    pub fn append_dst_block(&mut self, to: &BufferBlock, _repository_index: usize) -> VkResult<MemoryWritePtr> {
 
-        let dst_block = to.clone();
+        let lease = self.lease.as_ref()
+            .ok_or(VkError::other("The staging resource has been discarded."))?;
 
-        let src_block = BufferBlock {
-            handle: self.buffers[repository_index].handle,
-            size: dst_block.size,
-            memory_offset: dst_block.memory_offset,
-        };
-
-        self.ranges_to_flush.push(MemoryRange { offset: dst_block.memory_offset, size: dst_block.size });
+        let dst_block = to.clone();
 
-        let data_ptr = unsafe {
-            self.src_memory.map_status.data_ptr(src_block.memory_offset)
-                .ok_or(VkError::device("Failed to get mapped memory pointer."))?
+        let src_block = BufferFullCopyInfo {
+            handle: lease.buffer,
+            offset: dst_block.memory_offset,
+            size  : dst_block.size,
         };
 
-        let writer = MemoryWritePtr::new(data_ptr, src_block.size);
+        let writer = MemoryWritePtr::new(lease.data_ptr(src_block.offset), src_block.size);
 
         self.src_blocks.push(src_block);
         self.dst_blocks.push(dst_block);
@@ -209,15 +183,8 @@ impl UploadStagingResource {
         Ok(writer)
     }
 
-    pub fn finish_src_transfer(&mut self, device: &GsDevice) -> VkResult<()> {
-
-        if !self.src_memory.target.is_coherent_memory() {
-            // FIXME: the VkPhysicalDeviceLimits::nonCoherentAtomSize is not satified for flushing range.
-            self.src_memory.flush_ranges(device, &self.ranges_to_flush)?;
-        }
-
-        self.src_memory.unmap(device);
-
+    pub fn finish_src_transfer(&mut self, _device: &GsDevice) -> VkResult<()> {
+        // the staging memory is host coherent, so there is no need to flush.
         Ok(())
     }
 
@@ -225,7 +192,7 @@ impl UploadStagingResource {
 
         let mut data_copyer = DataCopyer::new(device)?;
         for (src, dst) in self.src_blocks.iter().zip(self.dst_blocks.iter()) {
-            data_copyer.copy_buffer_to_buffer(src.full_copy(), dst.full_copy());
+            data_copyer.copy_buffer_to_buffer(src.clone(), dst.full_copy());
         }
 
         data_copyer.done()?;
@@ -233,10 +200,11 @@ impl UploadStagingResource {
         Ok(())
     }
 
+    /// Return the staging buffer to the pool. This must be called after the transfer completes.
     pub fn discard(&mut self, device: &GsDevice) {
 
-        self.buffers.iter()
-            .for_each(|buffer| buffer.discard(device));
-        self.src_memory.discard(device);
+        if let Some(lease) = self.lease.take() {
+            device.logic.staging_pool().release(lease);
+        }
     }
 }
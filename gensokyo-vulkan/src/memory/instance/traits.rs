@@ -10,10 +10,10 @@ use crate::buffer::allocator::BufferAllocateInfos;
 pub type GsBufferMemory = Box<dyn BufferMemoryAbs>;
 pub type GsImageMemory  = Box<dyn ImageMemoryAbs>;
 
-pub trait BufferMemoryAbs: GsMemoryAbstract {
+pub trait BufferMemoryAbs: GsMemoryAbstract + Send + Sync {
 
     fn to_upload_agency(&self, device: &GsDevice, allot_infos: &BufferAllocateInfos) -> VkResult<Box<dyn MemoryDataDelegate>>;
     fn to_update_agency(&self) -> VkResult<Box<dyn MemoryDataDelegate>>;
 }
 
-pub trait ImageMemoryAbs: GsMemoryAbstract {}
+pub trait ImageMemoryAbs: GsMemoryAbstract + Send + Sync {}
@@ -5,11 +5,14 @@ pub use self::utils::{ MemoryRange, MemoryWritePtr, MemoryMapStatus };
 pub use self::filter::MemoryFilter;
 pub use self::traits::MemoryDstEntity;
 
+pub(crate) use self::pool::{ GsStagingPool, StagingLease };
+
 mod target;
 mod traits;
 mod utils;
 mod filter;
 mod barrier;
+mod pool;
 
 pub mod types;
 pub mod instance;
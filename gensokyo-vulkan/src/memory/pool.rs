@@ -0,0 +1,168 @@
+
+use ash::vk;
+use ash::version::DeviceV1_0;
+
+use crate::core::GsDevice;
+use crate::memory::types::GsMemoryType;
+
+use crate::error::{ VkResult, VkError };
+use crate::types::{ vkbytes, vkptr };
+
+use std::ptr;
+
+/// The minimum size of each staging chunk(32 MiB).
+const STAGING_CHUNK_SIZE: vkbytes = 32 * 1024 * 1024;
+
+/// A pool of persistently mapped staging buffers, which is reused by all the data uploading on a device.
+///
+/// Each chunk of the pool is lent to a single uploading at a time, and returns to the pool when the transfer finishes.
+pub(crate) struct GsStagingPool {
+
+    chunks: Vec<StagingChunk>,
+}
+
+struct StagingChunk {
+
+    buffer: vk::Buffer,
+    memory: vk::DeviceMemory,
+    size  : vkbytes,
+    /// the pointer to the beginning of the mapped memory.
+    data_ptr: vkptr,
+    is_occupied: bool,
+}
+
+/// A staging chunk lent from `GsStagingPool`.
+pub(crate) struct StagingLease {
+
+    chunk_index: usize,
+
+    pub buffer: vk::Buffer,
+    pub size  : vkbytes,
+    data_ptr  : vkptr,
+}
+
+// the mapped pointers are only accessed by the owner of the lease, so it's safe to move them between threads.
+unsafe impl Send for GsStagingPool {}
+unsafe impl Sync for GsStagingPool {}
+unsafe impl Send for StagingLease {}
+unsafe impl Sync for StagingLease {}
+
+impl GsStagingPool {
+
+    pub fn new() -> GsStagingPool {
+        GsStagingPool { chunks: Vec::new() }
+    }
+
+    /// Lend a staging chunk whose size is not less than `size`. A new chunk will be created if there is no available one.
+    pub fn acquire(&mut self, device: &GsDevice, size: vkbytes) -> VkResult<StagingLease> {
+
+        let available = self.chunks.iter()
+            .position(|chunk| chunk.is_occupied == false && chunk.size >= size);
+
+        let chunk_index = match available {
+            | Some(index) => index,
+            | None => {
+                let chunk = StagingChunk::allocate(device, size.max(STAGING_CHUNK_SIZE))?;
+                self.chunks.push(chunk);
+                self.chunks.len() - 1
+            },
+        };
+
+        let chunk = &mut self.chunks[chunk_index];
+        chunk.is_occupied = true;
+
+        let lease = StagingLease {
+            chunk_index,
+            buffer  : chunk.buffer,
+            size    : chunk.size,
+            data_ptr: chunk.data_ptr,
+        };
+        Ok(lease)
+    }
+
+    /// Return the chunk to the pool. The transfer commands using this chunk must have completed.
+    pub fn release(&mut self, lease: StagingLease) {
+        self.chunks[lease.chunk_index].is_occupied = false;
+    }
+
+    /// Destroy all the staging chunks. The device must be idle when calling this function.
+    pub fn discard(&mut self, device: &ash::Device) {
+
+        for chunk in self.chunks.drain(..) {
+            unsafe {
+                device.destroy_buffer(chunk.buffer, None);
+                device.unmap_memory(chunk.memory);
+                device.free_memory(chunk.memory, None);
+            }
+        }
+    }
+}
+
+impl StagingChunk {
+
+    fn allocate(device: &GsDevice, size: vkbytes) -> VkResult<StagingChunk> {
+
+        let buffer_ci = vk::BufferCreateInfo {
+            s_type: vk::StructureType::BUFFER_CREATE_INFO,
+            p_next: ptr::null(),
+            flags : vk::BufferCreateFlags::empty(),
+            size,
+            usage : vk::BufferUsageFlags::TRANSFER_SRC,
+            sharing_mode: vk::SharingMode::EXCLUSIVE,
+            queue_family_index_count: 0,
+            p_queue_family_indices  : ptr::null(),
+        };
+
+        let buffer = unsafe {
+            device.logic.handle.create_buffer(&buffer_ci, None)
+                .or(Err(VkError::create("Staging Buffer")))?
+        };
+
+        let requirement = unsafe {
+            device.logic.handle.get_buffer_memory_requirements(buffer)
+        };
+
+        let memory_type_index = device.phys.memory.find_memory_type(
+            requirement.memory_type_bits,
+            GsMemoryType::StagingMemory.property_flags(),
+            None,
+        ).first().cloned()
+            .ok_or(VkError::device("Failed to find suitable memory type for staging buffer."))?;
+
+        let allocate_info = vk::MemoryAllocateInfo {
+            s_type: vk::StructureType::MEMORY_ALLOCATE_INFO,
+            p_next: ptr::null(),
+            allocation_size  : requirement.size,
+            memory_type_index: memory_type_index as _,
+        };
+
+        let memory = unsafe {
+            device.logic.handle.allocate_memory(&allocate_info, None)
+                .or(Err(VkError::device("Failed to allocate memory for staging buffer.")))?
+        };
+
+        let data_ptr = unsafe {
+            device.logic.handle.bind_buffer_memory(buffer, memory, 0)
+                .or(Err(VkError::device("Failed to bind staging buffer to memory.")))?;
+            // the staging memory is host coherent, so it's kept mapped during the whole lifetime.
+            device.logic.handle.map_memory(memory, 0, vk::WHOLE_SIZE, vk::MemoryMapFlags::empty())
+                .or(Err(VkError::device("Failed to map staging memory.")))?
+        };
+
+        let chunk = StagingChunk {
+            buffer, memory, size, data_ptr,
+            is_occupied: false,
+        };
+        Ok(chunk)
+    }
+}
+
+impl StagingLease {
+
+    /// Get the pointer to the mapped memory at `offset` bytes of this chunk.
+    pub fn data_ptr(&self, offset: vkbytes) -> vkptr {
+
+        debug_assert!(offset <= self.size);
+        unsafe { self.data_ptr.offset(offset as isize) }
+    }
+}
@@ -4,6 +4,7 @@ use ash::vk;
 use ash::version::DeviceV1_0;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
 use crate::memory::filter::MemoryFilter;
 
 use crate::error::{ VkResult, VkError };
@@ -47,10 +48,8 @@ impl GsMemory {
         self.typ.property_flags.contains(vk::MemoryPropertyFlags::HOST_COHERENT)
     }
 
+    /// Free the memory when device no longer uses it.
     pub fn discard(&self, device: &GsDevice) {
-
-        unsafe {
-            device.logic.handle.free_memory(self.handle, None);
-        }
+        device.logic.defer_destroy(DestroyHandle::Memory(self.handle));
     }
 }
@@ -44,9 +44,7 @@ pub trait GsMemoryAbstract {
     }
 
     fn discard(&mut self, device: &GsDevice) {
-        unsafe {
-            device.logic.handle.free_memory(self.target().handle, None);
-        }
+        self.target().discard(device);
     }
 }
 
@@ -0,0 +1,183 @@
+
+use ash::vk;
+
+use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
+
+use crate::buffer::{ BufferCopiable, BufferFullCopyInfo };
+use crate::memory::{ StagingLease, MemoryWritePtr };
+use crate::memory::transfer::DataCopyer;
+use crate::command::GsCmdTransferApi;
+
+use crate::error::VkResult;
+use crate::types::vkbytes;
+
+use std::mem;
+
+/// The minimum alignment of each staging data, which is required by the buffer offset of buffer-image copy.
+const STAGING_MIN_ALIGNMENT: vkbytes = 4;
+
+/// Collect the data uploading of multiple buffers and images into one command buffer and one submission.
+///
+/// The source data is written to the staging pool of device, which is reused between batches.
+/// `GsUploadBatch::finish` must be called to submit the commands before the destination resources are used.
+pub struct GsUploadBatch {
+
+    device: GsDevice,
+    copyer: DataCopyer,
+
+    /// the staging chunks lent from the staging pool of device.
+    leases: Vec<StagingLease>,
+    /// the offset of the unused space in the last lease.
+    cursor: vkbytes,
+    /// the temporary handles used by the recorded commands, which are destroyed after the batch completes.
+    transients: Vec<DestroyHandle>,
+}
+
+impl GsUploadBatch {
+
+    pub fn new(device: &GsDevice) -> VkResult<GsUploadBatch> {
+
+        let batch = GsUploadBatch {
+            device: device.clone(),
+            copyer: DataCopyer::new(device)?,
+            leases: vec![],
+            cursor: 0,
+            transients: vec![],
+        };
+        Ok(batch)
+    }
+
+    /// Upload `data` to the beginning of `to`.
+    ///
+    /// The buffer must be allocated in `Device` or `Cached` memory, which could be the destination of transfer.
+    pub fn upload_buffer(&mut self, to: &impl BufferCopiable, data: &[impl Copy]) -> VkResult<&mut GsUploadBatch> {
+        self.upload_buffer_range(to, 0, data)
+    }
+
+    /// Upload `data` to `to`, starting at `offset` bytes from the beginning of the buffer.
+    pub fn upload_buffer_range(&mut self, to: &impl BufferCopiable, offset: vkbytes, data: &[impl Copy]) -> VkResult<&mut GsUploadBatch> {
+
+        let dst = to.full_copy();
+        let src = self.stage(data)?;
+
+        debug_assert!(offset + src.size <= dst.size, "The data to upload is out of the range of buffer.");
+
+        let copy_region = vk::BufferCopy {
+            src_offset: src.offset,
+            dst_offset: dst.offset + offset,
+            size: src.size,
+        };
+
+        let _ = self.copyer.recorder().copy_buf2buf(src.handle, dst.handle, &[copy_region]);
+
+        Ok(self)
+    }
+
+    /// Write `data` to staging buffer, and return the range of staging buffer where the data locates.
+    pub(crate) fn stage<D: Copy>(&mut self, data: &[D]) -> VkResult<BufferFullCopyInfo> {
+        self.stage_texels(data, 1)
+    }
+
+    /// Write `data` to staging buffer for copying to an image whose texel block size is `block_size` bytes.
+    ///
+    /// The staging offset is a multiple of both `block_size` and 4, and respects `optimalBufferCopyOffsetAlignment` of device.
+    pub(crate) fn stage_texels<D: Copy>(&mut self, data: &[D], block_size: vkbytes) -> VkResult<BufferFullCopyInfo> {
+
+        let alignment = {
+            let optimal_alignment = self.device.phys.limits().optimal_buffer_copy_offset_alignment;
+            lcm(lcm(block_size.max(1), STAGING_MIN_ALIGNMENT), optimal_alignment.max(1))
+        };
+
+        let size = (data.len() * mem::size_of::<D>()) as vkbytes;
+        let aligned_offset = (self.cursor + alignment - 1) / alignment * alignment;
+
+        let is_out_of_space = match self.leases.last() {
+            | Some(lease) => aligned_offset + size > lease.size,
+            | None => true,
+        };
+
+        let offset = if is_out_of_space {
+            let lease = self.device.logic.staging_pool().acquire(&self.device, size)?;
+            self.leases.push(lease);
+            0
+        } else {
+            aligned_offset
+        };
+
+        let lease = self.leases.last().unwrap();
+        let writer = MemoryWritePtr::new(lease.data_ptr(offset), size);
+        writer.write_data(data);
+
+        self.cursor = offset + size;
+
+        let staging = BufferFullCopyInfo {
+            handle: lease.buffer,
+            offset, size,
+        };
+        Ok(staging)
+    }
+
+    #[inline]
+    pub(crate) fn copyer(&self) -> &DataCopyer {
+        &self.copyer
+    }
+
+    /// Keep `handle` alive until the recorded commands complete.
+    pub(crate) fn hold_transient(&mut self, handle: DestroyHandle) {
+        self.transients.push(handle);
+    }
+
+    /// Submit all the recorded commands in one submission, and wait for them to complete.
+    pub fn finish(&mut self) -> VkResult<()> {
+
+        let result = self.copyer.done();
+
+        if result.is_err() {
+            // the submitted commands may still read the staging data, so wait for the device before releasing them.
+            let _ = self.device.logic.wait_idle();
+        }
+
+        self.release_leases();
+        self.release_transients();
+
+        result
+    }
+
+    fn release_leases(&mut self) {
+
+        let mut pool = self.device.logic.staging_pool();
+        for lease in self.leases.drain(..) {
+            pool.release(lease);
+        }
+        self.cursor = 0;
+    }
+
+    fn release_transients(&mut self) {
+
+        for handle in self.transients.drain(..) {
+            self.device.logic.defer_destroy(handle);
+        }
+    }
+}
+
+impl Drop for GsUploadBatch {
+
+    /// The recorded commands are discarded if the batch drops without calling `GsUploadBatch::finish`.
+    fn drop(&mut self) {
+        self.release_leases();
+        self.release_transients();
+    }
+}
+
+fn lcm(a: vkbytes, b: vkbytes) -> vkbytes {
+
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        let r = x % y;
+        x = y;
+        y = r;
+    }
+
+    a / x * b
+}
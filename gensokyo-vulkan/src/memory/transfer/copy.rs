@@ -38,8 +38,8 @@ impl DataCopyer {
         debug_assert_eq!(src.size, dst.size, "The size of both buffers must be the same during copy.");
 
         let copy_region = vk::BufferCopy {
-            src_offset: 0,
-            dst_offset: 0,
+            src_offset: src.offset,
+            dst_offset: dst.offset,
             size: src.size,
         };
 
@@ -52,8 +52,8 @@ impl DataCopyer {
     pub fn copy_buffer_to_image(&self, src: BufferFullCopyInfo, dst: ImageFullCopyInfo) -> &DataCopyer {
 
         let copy_region = vk::BufferImageCopy {
-            // the image data must start at the beginning of buffer to be copied from.
-            buffer_offset: 0,
+            // the image data must start at the beginning of the copy range of buffer.
+            buffer_offset: src.offset,
             // TODO: the following options are not configurable.
             // Specifying 0 for both indicates that the pixels are simply tightly packed.
             buffer_row_length  : 0,
@@ -101,7 +101,7 @@ impl DataCopyer {
     pub fn copy_image_to_buffer(&self, src: ImageFullCopyInfo, dst: BufferFullCopyInfo) -> &DataCopyer {
 
         let copy_region = vk::BufferImageCopy {
-            buffer_offset: 0,
+            buffer_offset: dst.offset,
             buffer_row_length  : 0,
             buffer_image_height: 0,
             image_subresource: src.sub_resource_layers,
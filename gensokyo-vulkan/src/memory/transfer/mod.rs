@@ -2,9 +2,13 @@
 pub use self::copy::DataCopyer;
 pub use self::upload::{ GsBufferDataUploader, GsBufferUploadable };
 pub use self::update::{ GsBufferDataUpdater, GsBufferUpdatable };
+pub use self::read::GsBufferReader;
+pub use self::batch::GsUploadBatch;
 pub use self::traits::MemoryDataDelegate;
 
 mod copy;
 mod upload;
 mod update;
+mod read;
+mod batch;
 mod traits;
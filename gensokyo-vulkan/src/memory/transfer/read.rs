@@ -0,0 +1,187 @@
+
+use ash::vk;
+use ash::version::DeviceV1_0;
+
+use crate::core::GsDevice;
+use crate::core::device::GsLogicalDevice;
+use crate::core::device::queue::GsTransfer;
+
+use crate::buffer::{ GsBuffer, BufferCopiable };
+use crate::command::{ GsCmdRecorder, GsCmdTransferApi };
+use crate::memory::{ GsMemory, MemoryFilter, MemoryDstEntity };
+use crate::memory::types::GsMemoryType;
+use crate::sync::GsFence;
+
+use crate::error::{ VkResult, VkError };
+use crate::utils::phantom::Host;
+use crate::types::{ vkbytes, vkptr };
+
+use std::mem;
+use std::ptr;
+
+/// Read the data of buffer back to host memory.
+///
+/// The data is copied into a transient host visible buffer, which is released when the reader drops.
+/// The buffer prefers host cached memory for fast reading on host, and is invalidated after mapping if it is not coherent.
+///
+/// The device work writing the source buffer must have completed before the reader is created.
+pub struct GsBufferReader {
+
+    device: GsDevice,
+    transfer: GsTransfer,
+
+    buffer: GsBuffer,
+    memory: GsMemory,
+    /// the size of data to read.
+    size: vkbytes,
+
+    data_ptr: Option<vkptr>,
+    is_complete: bool,
+}
+
+impl GsBufferReader {
+
+    /// Start to copy the whole data of `from`. This function returns without waiting for the copy to complete.
+    pub fn read(device: &GsDevice, from: &impl BufferCopiable) -> VkResult<GsBufferReader> {
+
+        let size = from.full_copy().size;
+        GsBufferReader::read_range(device, from, 0, size)
+    }
+
+    /// Start to copy `size` bytes of `from` starting at `offset`. This function returns without waiting for the copy to complete.
+    pub fn read_range(device: &GsDevice, from: &impl BufferCopiable, offset: vkbytes, size: vkbytes) -> VkResult<GsBufferReader> {
+
+        let src = from.full_copy();
+        debug_assert!(offset + size <= src.size, "The range to read is out of the buffer.");
+
+        // prepare the host visible buffer to receive the data.
+        let buffer = GsBuffer::new(size, vk::BufferUsageFlags::TRANSFER_DST)
+            .build(device, Host)?;
+
+        let mut memory_filter = MemoryFilter::new(device, GsMemoryType::ReadbackMemory);
+        memory_filter.filter(&buffer)?;
+        let memory = GsMemory::allocate(device, buffer.aligned_size(), &memory_filter)?;
+
+        unsafe {
+            device.logic.handle.bind_buffer_memory(buffer.handle, memory.handle, 0)
+                .or(Err(VkError::device("Failed to bind memory to buffer object.")))?;
+        }
+
+        // record and submit the copy command.
+        let mut transfer = GsLogicalDevice::transfer(device)?;
+        let command = transfer.command()?;
+        let mut recorder = GsCmdRecorder::create_copy(device, command);
+
+        let copy_region = vk::BufferCopy {
+            src_offset: src.offset + offset,
+            dst_offset: 0,
+            size,
+        };
+
+        recorder.begin_record(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT)?
+            .copy_buf2buf(src.handle, buffer.handle, &[copy_region]);
+
+        let command = recorder.end_record()?;
+        transfer.commit(command);
+        transfer.submit()?;
+
+        let reader = GsBufferReader {
+            device: device.clone(),
+            transfer, buffer, memory, size,
+            data_ptr: None,
+            is_complete: false,
+        };
+        Ok(reader)
+    }
+
+    /// Return the fence which will be signaled when the copy completes.
+    pub fn fence(&self) -> &GsFence {
+        self.transfer.fence()
+    }
+
+    /// Block until the copy completes.
+    pub fn wait(&mut self) -> VkResult<()> {
+
+        if self.is_complete == false {
+            self.transfer.wait()?;
+            self.is_complete = true;
+        }
+
+        Ok(())
+    }
+
+    /// Wait for the copy and return a copy of the data.
+    pub fn to_vec<T: Copy>(&mut self) -> VkResult<Vec<T>> {
+
+        let data = self.mapped::<T>()?;
+        Ok(data.to_vec())
+    }
+
+    /// Wait for the copy and return the data in mapped memory directly.
+    ///
+    /// The data is interpreted as an array of `T`, and the trailing bytes less than the size of `T` are ignored.
+    pub fn mapped<T: Copy>(&mut self) -> VkResult<&[T]> {
+
+        self.wait()?;
+
+        let data_ptr = match self.data_ptr {
+            | Some(data_ptr) => data_ptr,
+            | None => {
+                let data_ptr = self.map_memory()?;
+                self.data_ptr = Some(data_ptr);
+                data_ptr
+            },
+        };
+
+        let element_count = (self.size as usize) / mem::size_of::<T>();
+        let data = unsafe {
+            ::std::slice::from_raw_parts(data_ptr as *const T, element_count)
+        };
+
+        Ok(data)
+    }
+
+    fn map_memory(&self) -> VkResult<vkptr> {
+
+        let data_ptr = unsafe {
+            self.device.logic.handle.map_memory(self.memory.handle, 0, vk::WHOLE_SIZE, vk::MemoryMapFlags::empty())
+                .or(Err(VkError::device("Failed to map device memory.")))?
+        };
+
+        if self.memory.is_coherent_memory() == false {
+
+            // make the device writes visible to host.
+            let invalidate_range = vk::MappedMemoryRange {
+                s_type: vk::StructureType::MAPPED_MEMORY_RANGE,
+                p_next: ptr::null(),
+                memory: self.memory.handle,
+                offset: 0,
+                size  : vk::WHOLE_SIZE,
+            };
+
+            unsafe {
+                self.device.logic.handle.invalidate_mapped_memory_ranges(&[invalidate_range])
+                    .or(Err(VkError::device("Failed to invalidate mapped memory range.")))?;
+            }
+        }
+
+        Ok(data_ptr)
+    }
+}
+
+impl Drop for GsBufferReader {
+
+    fn drop(&mut self) {
+
+        // the buffer must not be in use by device when destroying it.
+        let _ = self.wait();
+
+        if self.data_ptr.is_some() {
+            unsafe {
+                self.device.logic.handle.unmap_memory(self.memory.handle);
+            }
+        }
+
+        self.memory.discard(&self.device);
+    }
+}
@@ -39,6 +39,17 @@ impl GsBufferDataUpdater {
         Ok(self)
     }
 
+    /// Update the data of `to` start at `offset` bytes, leaving the rest of the buffer unchanged.
+    ///
+    /// This is useful for the buffer storing data of multiple frames, such as `GsInstanceBuffer`.
+    pub fn update_range(&mut self, to: &impl BufferInstance, offset: vkbytes, data: &[impl Copy]) -> VkResult<&mut GsBufferDataUpdater> {
+
+        let writer = to.acquire_write_ptr(&mut self.agency)?;
+        writer.write_data_offset(offset, data);
+
+        Ok(self)
+    }
+
     pub fn update_align(&mut self, to: &impl BufferInstance, data: &[impl Copy], alignment: vkbytes) -> VkResult<&mut GsBufferDataUpdater> {
 
         let writer = to.acquire_write_ptr(&mut self.agency)?;
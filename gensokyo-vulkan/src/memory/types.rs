@@ -7,6 +7,12 @@ pub enum GsMemoryType {
     CachedMemory,
     DeviceMemory,
     StagingMemory,
+    /// `TransientMemory` is the device memory which may be lazily allocated, used by the transient attachments.
+    TransientMemory,
+    /// `ReadbackMemory` is the host visible memory cached on host, used to read data back from device.
+    ///
+    /// It may be not coherent, and falls back to `HostMemory` if no such memory type is available.
+    ReadbackMemory,
 }
 
 impl GsMemoryType {
@@ -25,6 +31,12 @@ impl GsMemoryType {
             | GsMemoryType::StagingMemory => {
                 vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT
             },
+            | GsMemoryType::TransientMemory => {
+                vk::MemoryPropertyFlags::DEVICE_LOCAL | vk::MemoryPropertyFlags::LAZILY_ALLOCATED
+            },
+            | GsMemoryType::ReadbackMemory => {
+                vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_CACHED
+            },
         }
     }
 }
@@ -18,6 +18,10 @@ pub struct MemoryMapStatus {
     data_ptr: Option<vkptr>,
 }
 
+// the mapped pointer is only accessed through the memory object owning it, so it's safe to move the status between threads.
+unsafe impl Send for MemoryMapStatus {}
+unsafe impl Sync for MemoryMapStatus {}
+
 impl MemoryMapStatus {
 
     pub fn from_unmap() -> MemoryMapStatus {
@@ -84,13 +88,8 @@ impl MemoryWritePtr {
     // FIXME: This function haven't been well tested.
     pub fn write_data<D: Copy>(&self, data: &[D]) {
 
-        use std::slice::from_raw_parts_mut;
-
-        let mapped_copy_target = unsafe {
-            from_raw_parts_mut(self.ptr as *mut D, data.len())
-        };
-
-        mapped_copy_target.copy_from_slice(data);
+        // the ptr may be not aligned to `D`(such as a staging offset), so the data is copied by bytes.
+        self.write_data_offset(0, data);
 
         // Deprecated method.
         // let mut vert_align = unsafe {
@@ -104,6 +103,22 @@ impl MemoryWritePtr {
         // vert_align.copy_from_slice(data);
     }
 
+    /// Write `data` to the memory starting at `offset` bytes from the beginning of this ptr.
+    ///
+    /// The data is copied byte by byte, so `offset` is not required to be aligned to `D`.
+    pub fn write_data_offset<D: Copy>(&self, offset: vkbytes, data: &[D]) {
+
+        use std::ptr::copy_nonoverlapping;
+
+        let data_size = ::std::mem::size_of::<D>() * data.len();
+        debug_assert!(offset + data_size as vkbytes <= self.size, "Data writing is out of the range of memory.");
+
+        unsafe {
+            let dst = (self.ptr as *mut u8).offset(offset as isize);
+            copy_nonoverlapping(data.as_ptr() as *const u8, dst, data_size);
+        }
+    }
+
     // FIXME: This function haven't been well tested.
     pub fn write_data_with_alignment<D: Copy>(&self, data: &[D], alignment: vkbytes) {
 
@@ -6,6 +6,7 @@ use crate::core::GsDevice;
 
 use crate::pipeline::target::GsPipeline;
 use crate::pipeline::graphics::config::GfxPipelineConfig;
+use crate::pipeline::graphics::resize::GfxResizablePipeline;
 use crate::pipeline::shader::{ GsShaderModule, GsShaderCI };
 use crate::pipeline::shader::shaderc::{ GsShaderCompiler, ShaderCompilePrefab, ShadercConfiguration };
 
@@ -13,6 +14,7 @@ use crate::utils::phantom::Graphics;
 use crate::error::{ VkResult, VkError };
 
 use std::ops::{ BitAnd, BitAndAssign, BitOrAssign, BitOr };
+use std::sync::Arc;
 use std::ptr;
 
 
@@ -59,9 +61,8 @@ impl GfxPipelineBuilder {
             self.device.logic.handle.create_graphics_pipelines(vk::PipelineCache::null(), &[pipeline_ci.content], None)
                 .or(Err(VkError::create("Graphics Pipelines")))?
         };
-        destroy_modules(&self.device, &shader_modules);
 
-        let result = GsPipeline::new(self.device.clone(), handles[0], pipeline_ci.pipeline_layout, config.render_pass);
+        let result = wrap_pipeline(&self.device, handles[0], pipeline_ci.content.flags, pipeline_ci.pipeline_layout, shader_modules, config);
         Ok(result)
     }
 }
@@ -172,6 +173,30 @@ pub(super) fn destroy_modules(device: &GsDevice, modules: &[GsShaderModule]) {
     }
 }
 
+/// Wrap the built pipeline `handle` of `config`.
+///
+/// If the pipeline has fixed viewport or scissor and its render pass is built for swapchain, the shader modules and pipeline states are kept,
+/// so that the pipeline can be rebuilt when the swapchain is resized. Otherwise the shader modules are destroyed here.
+pub(super) fn wrap_pipeline(device: &GsDevice, handle: vk::Pipeline, flags: vk::PipelineCreateFlags, layout: vk::PipelineLayout, modules: Vec<GsShaderModule>, config: GfxPipelineConfig) -> GsPipeline<Graphics> {
+
+    let viewport = config.states.viewport_dynamics();
+
+    if config.render_pass.is_swapchain_sized() && viewport.is_fully_dynamic() == false {
+
+        let shaders = modules.into_iter()
+            .map(|module| module.retain()).collect();
+        let resizable = GfxResizablePipeline::new(handle, flags, layout, config.render_pass.handle, config.subpass, shaders, config.states, config.render_pass.dimension());
+        let resizable = Arc::new(resizable);
+        device.logic.track_swapchain_dependent(&resizable);
+
+        GsPipeline::resizable(device.clone(), resizable, layout, config.render_pass, viewport)
+    } else {
+
+        destroy_modules(device, &modules);
+        GsPipeline::new(device.clone(), handle, layout, config.render_pass, viewport)
+    }
+}
+
 
 // this function must be inline, or the ptr may be lost.
 #[inline(always)]
@@ -227,9 +252,8 @@ pub(super) fn pipeline_ci(device: &GsDevice, flag: &PipelineCIFlags, shader_modu
         // `layout` is the description of binding locations used by both the pipeline and descriptor sets used with the pipeline.
         layout     : pipeline_layout,
         render_pass: config.render_pass.handle,
-        // TODO: Add configuration for this field.
         // `subpass` the index of the subpass in the render pass where this pipeline will be used.
-        subpass: 0,
+        subpass: config.subpass,
         /// `base_pipeline_handle` is the pipeline to derive from.
         base_pipeline_handle: base_pipeline,
         base_pipeline_index: -1,
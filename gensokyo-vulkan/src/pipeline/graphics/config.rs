@@ -6,7 +6,7 @@ use crate::pipeline::{
     state::PipelineStates,
     state::vertex_input::GsVertexInputState,
     state::input_assembly::GsInputAssemblyState,
-    state::viewport::ViewportStateType,
+    state::viewport::{ ViewportStateType, GsViewportState, ViewportStateInfo },
     state::rasterizer::GsRasterizerState,
     state::multisample::GsMultisampleState,
     state::depth_stencil::GsDepthStencilState,
@@ -16,7 +16,7 @@ use crate::pipeline::{
     layout::{ GsPipelineLayout, PipelineLayoutBuilder, GsPushConstantRange },
 };
 
-use crate::types::vkDim2D;
+use crate::types::{ vkuint, vkDim2D };
 use crate::descriptor::DescriptorSet;
 
 // ------------------------------------------------------------------------------------------
@@ -27,18 +27,30 @@ pub struct GfxPipelineConfig {
     pub(super) render_pass: GsRenderPass,
 
     pub(super) layout_builder: PipelineLayoutBuilder,
+
+    /// the index of the subpass in `render_pass` where the pipeline is used.
+    pub(super) subpass: vkuint,
+
+    /// the dimension of the fixed viewport set by `reset_fixed_viewport`.
+    dimension: vkDim2D,
 }
 
 impl GfxPipelineConfig {
 
+    /// The viewport and scissor are dynamic by default, and `GsCmdGraphicsApi::begin_render_pass` sets them to the whole framebuffer.
+    ///
+    /// `dimension` is the dimension of the viewport and scissor set by `reset_fixed_viewport`.
     pub fn new(shaders: impl Into<Vec<GsShaderCI>>, input: VertexInputDescription, render_pass: GsRenderPass, dimension: vkDim2D) -> GfxPipelineConfig {
 
         GfxPipelineConfig {
             shaders : shaders.into(),
-            states  : PipelineStates::setup(input, dimension),
+            states  : PipelineStates::setup(input),
 
             render_pass,
             layout_builder: GsPipelineLayout::new(),
+
+            subpass: 0,
+            dimension,
         }
     }
 
@@ -81,6 +93,17 @@ impl GfxPipelineConfig {
         self
     }
 
+    /// Set the index of the subpass where the pipeline is used. The default value is 0.
+    pub fn reset_subpass(&mut self, subpass_index: vkuint) -> &mut GfxPipelineConfig {
+        self.subpass = subpass_index;
+        self
+    }
+
+    pub fn with_subpass(mut self, subpass_index: vkuint) -> GfxPipelineConfig {
+        self.reset_subpass(subpass_index);
+        self
+    }
+
     pub fn reset_input_vertex(&mut self, state: GsVertexInputState) -> &mut GfxPipelineConfig {
         self.states.vertex_input = state;
         self
@@ -103,6 +126,9 @@ impl GfxPipelineConfig {
 
     pub fn reset_viewport(&mut self, state: ViewportStateType) -> &mut GfxPipelineConfig {
 
+        self.states.dynamic.remove_state(vk::DynamicState::VIEWPORT);
+        self.states.dynamic.remove_state(vk::DynamicState::SCISSOR);
+
         match state {
             | ViewportStateType::Fixed { .. } => {},
             | ViewportStateType::Dynamic { .. } => {
@@ -125,6 +151,20 @@ impl GfxPipelineConfig {
         self
     }
 
+    /// Use a fixed viewport and scissor covering `dimension` passed to `GfxPipelineConfig::new`, instead of the dynamic ones.
+    ///
+    /// If the framebuffers of render pass are recreated with swapchain, the pipeline is rebuilt with the new dimension when the swapchain is resized.
+    pub fn reset_fixed_viewport(&mut self) -> &mut GfxPipelineConfig {
+
+        let state = GsViewportState::single(ViewportStateInfo::from(self.dimension));
+        self.reset_viewport(ViewportStateType::Fixed { state })
+    }
+
+    pub fn with_fixed_viewport(mut self) -> GfxPipelineConfig {
+        self.reset_fixed_viewport();
+        self
+    }
+
     pub fn reset_rasterizer(&mut self, state: GsRasterizerState) -> &mut GfxPipelineConfig {
 
         if state.is_dynamic_lindwidth() {
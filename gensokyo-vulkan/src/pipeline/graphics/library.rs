@@ -0,0 +1,293 @@
+
+use ash::vk;
+use ash::version::DeviceV1_0;
+
+use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
+
+use crate::pipeline::target::{ GsPipelineElement, PipelineIndex };
+use crate::pipeline::pass::GsRenderPass;
+use crate::pipeline::layout::GsPipelineLayout;
+use crate::pipeline::state::PipelineStates;
+use crate::pipeline::state::viewport::ViewportDynamics;
+use crate::pipeline::shader::shaderc::{ GsShaderCompiler, ShaderCompilePrefab, ShadercConfiguration };
+use crate::pipeline::graphics::builder;
+use crate::pipeline::graphics::builder::{ PipelineDeriveState, PipelineCIFlags };
+use crate::pipeline::graphics::config::GfxPipelineConfig;
+
+use crate::error::{ VkResult, VkError };
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{ Hash, Hasher };
+use std::slice;
+
+
+/// A runtime cache of graphics pipelines, which builds each pipeline lazily the first time its config is requested.
+///
+/// Each `GfxPipelineConfig` is identified by the hash of its shaders, specialization constants, pipeline layout, render pass compatibility, subpass index and all the pipeline states,
+/// so requesting the same config again returns the cached pipeline without building.
+///
+/// The pipelines sharing the same shaders and layout, which only differ in fixed-function states, are built as derivatives of the first one of them,
+/// so that the driver may create them faster.
+///
+/// All the configs must use render passes compatible with the render pass of the library.
+/// The content of shader files is not part of the hash, so modifying a shader file does not invalidate the cached pipelines.
+///
+/// The cached pipelines are not rebuilt when the swapchain is resized,
+/// so keep their viewport and scissor dynamic if the render pass is built for swapchain.
+pub struct PipelineLibrary {
+
+    device : GsDevice,
+    ci_flag: PipelineCIFlags,
+    shaderc: GsShaderCompiler,
+
+    pass: GsRenderPass,
+    pipelines: Vec<LibraryPipeline>,
+
+    /// map the hash of config to the index of its pipeline.
+    indices: HashMap<u64, usize>,
+    /// map the hash of shaders and layout to the base pipeline other pipelines derive from.
+    bases: HashMap<u64, vk::Pipeline>,
+
+    is_use_base_pipeline: bool,
+}
+
+struct LibraryPipeline {
+
+    handle: vk::Pipeline,
+    layout: GsPipelineLayout,
+    viewport: ViewportDynamics,
+}
+
+impl PipelineLibrary {
+
+    /// Create an empty library, whose pipelines are used in `render_pass`.
+    pub fn create(device: &GsDevice, render_pass: GsRenderPass) -> VkResult<PipelineLibrary> {
+
+        let library = PipelineLibrary {
+            device : device.clone(),
+            ci_flag: PipelineCIFlags::default(),
+            shaderc: GsShaderCompiler::setup(ShaderCompilePrefab::Vulkan)?,
+
+            pass: render_pass,
+            pipelines: vec![],
+
+            indices: HashMap::new(),
+            bases  : HashMap::new(),
+
+            is_use_base_pipeline: true,
+        };
+        Ok(library)
+    }
+
+    pub fn with_flag(&mut self, flags: PipelineCIFlags) {
+        self.ci_flag |= flags;
+    }
+
+    /// Set whether the pipelines differ only in fixed-function states are built as derivatives. The default value is true.
+    pub fn set_base_pipeline_use(&mut self, is_use_base_pipeline: bool) {
+        self.is_use_base_pipeline = is_use_base_pipeline;
+    }
+
+    pub fn set_shaderc(&mut self, configuration: ShadercConfiguration) -> VkResult<()> {
+
+        self.shaderc = GsShaderCompiler::from_configuration(configuration)?;
+
+        Ok(())
+    }
+
+    /// Return the index of the pipeline of `config`, and build the pipeline if it has not been built yet.
+    pub fn acquire(&mut self, config: &GfxPipelineConfig) -> VkResult<PipelineIndex> {
+
+        if self.pass.is_compatible(&config.render_pass) == false {
+            return Err(VkError::other("The render pass of Graphics Pipeline Config is not compatible with the render pass of Pipeline Library."))
+        }
+
+        let (base_hash, config_hash) = hash_config(config, self.pass.compatibility());
+
+        if let Some(&index) = self.indices.get(&config_hash) {
+            return Ok(PipelineIndex(index))
+        }
+
+        let layout = config.layout_builder.build(&self.device)?;
+
+        let derive_state = if self.is_use_base_pipeline {
+            match self.bases.get(&base_hash) {
+                | Some(&parent) => PipelineDeriveState::AsChildren { parent, layout },
+                | None => PipelineDeriveState::AsParent { layout },
+            }
+        } else {
+            PipelineDeriveState::IndependenceLayoutDefined { layout }
+        };
+
+        let handle = match self.build_pipeline(config, &derive_state) {
+            | Ok(handle) => handle,
+            | Err(e) => {
+                GsPipelineLayout { handle: layout }.discard(&self.device);
+                return Err(e)
+            },
+        };
+
+        if let PipelineDeriveState::AsParent { .. } = derive_state {
+            self.bases.insert(base_hash, handle);
+        }
+
+        let index = self.pipelines.len();
+        self.pipelines.push(LibraryPipeline {
+            handle,
+            layout: GsPipelineLayout { handle: layout },
+            viewport: config.states.viewport_dynamics(),
+        });
+        self.indices.insert(config_hash, index);
+
+        Ok(PipelineIndex(index))
+    }
+
+    /// Return the pipeline of `config`, and build the pipeline if it has not been built yet.
+    pub fn pipeline(&mut self, config: &GfxPipelineConfig) -> VkResult<GsPipelineElement> {
+
+        let index = self.acquire(config)?;
+        Ok(self.element(&index))
+    }
+
+    pub fn element(&self, at: &PipelineIndex) -> GsPipelineElement {
+
+        let pipeline = &self.pipelines[at.0];
+        GsPipelineElement::new(pipeline.handle, &pipeline.layout, &self.pass, pipeline.viewport)
+    }
+
+    /// The number of pipelines have been built in this library.
+    pub fn pipeline_count(&self) -> usize {
+        self.pipelines.len()
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.pass.frame_count()
+    }
+
+    fn build_pipeline(&mut self, config: &GfxPipelineConfig, derive_state: &PipelineDeriveState) -> VkResult<vk::Pipeline> {
+
+        // compile shader.
+        let shader_modules = builder::compile_shaders(&self.device, &mut self.shaderc, &config.shaders)?;
+        // generate create info.
+        let pipeline_ci = match builder::pipeline_ci(&self.device, &self.ci_flag, &shader_modules, config, derive_state) {
+            | Ok(pipeline_ci) => pipeline_ci,
+            | Err(e) => {
+                builder::destroy_modules(&self.device, &shader_modules);
+                return Err(e)
+            },
+        };
+
+        // build pipeline.
+        let handles = unsafe {
+            self.device.logic.handle.create_graphics_pipelines(vk::PipelineCache::null(), &[pipeline_ci.content], None)
+        };
+        builder::destroy_modules(&self.device, &shader_modules);
+
+        let mut handles = handles.or(Err(VkError::create("Graphics Pipelines")))?;
+        Ok(handles.pop().unwrap())
+    }
+}
+
+impl Drop for PipelineLibrary {
+
+    fn drop(&mut self) {
+
+        for pipeline in self.pipelines.iter() {
+            self.device.logic.defer_destroy(DestroyHandle::Pipeline(pipeline.handle));
+            pipeline.layout.discard(&self.device);
+        }
+        self.pass.discard(&self.device);
+    }
+}
+
+/// Return the hash of the shaders and layout of `config`, and the hash of the whole `config`.
+fn hash_config(config: &GfxPipelineConfig, pass_compatibility: u64) -> (u64, u64) {
+
+    let mut hasher = DefaultHasher::new();
+
+    pass_compatibility.hash(&mut hasher);
+    config.layout_builder.cache_key().hash(&mut hasher);
+
+    config.shaders.len().hash(&mut hasher);
+    for shader in config.shaders.iter() {
+        shader.hash_source(&mut hasher);
+    }
+
+    let base_hash = hasher.finish();
+
+    config.subpass.hash(&mut hasher);
+    hash_states(&config.states, &mut hasher);
+    let config_hash = hasher.finish();
+
+    (base_hash, config_hash)
+}
+
+fn hash_states(states: &PipelineStates, hasher: &mut impl Hasher) {
+
+    let vertex_input = states.vertex_input.ci();
+    for binding in raw_slice(vertex_input.p_vertex_binding_descriptions, vertex_input.vertex_binding_description_count).iter() {
+        (binding.binding, binding.stride, binding.input_rate.as_raw()).hash(hasher);
+    }
+    for attribute in raw_slice(vertex_input.p_vertex_attribute_descriptions, vertex_input.vertex_attribute_description_count).iter() {
+        (attribute.location, attribute.binding, attribute.format.as_raw(), attribute.offset).hash(hasher);
+    }
+
+    let input_assembly = states.input_assembly.ci();
+    (input_assembly.topology.as_raw(), input_assembly.primitive_restart_enable).hash(hasher);
+
+    states.viewport.hash_state(hasher);
+
+    let rasterizer = states.rasterizer.ci();
+    (rasterizer.depth_clamp_enable, rasterizer.rasterizer_discard_enable, rasterizer.polygon_mode.as_raw(),
+     rasterizer.cull_mode.as_raw(), rasterizer.front_face.as_raw(), rasterizer.depth_bias_enable).hash(hasher);
+    (rasterizer.depth_bias_constant_factor.to_bits(), rasterizer.depth_bias_clamp.to_bits(),
+     rasterizer.depth_bias_slope_factor.to_bits(), rasterizer.line_width.to_bits()).hash(hasher);
+
+    let multisample = states.multisample.ci();
+    (multisample.rasterization_samples.as_raw(), multisample.sample_shading_enable, multisample.min_sample_shading.to_bits(),
+     multisample.alpha_to_coverage_enable, multisample.alpha_to_one_enable).hash(hasher);
+    if multisample.p_sample_mask.is_null() == false {
+        unsafe { (*multisample.p_sample_mask).hash(hasher) };
+    }
+
+    let depth_stencil = states.depth_stencil.ci();
+    (depth_stencil.depth_test_enable, depth_stencil.depth_write_enable, depth_stencil.depth_compare_op.as_raw(),
+     depth_stencil.depth_bounds_test_enable, depth_stencil.min_depth_bounds.to_bits(), depth_stencil.max_depth_bounds.to_bits(),
+     depth_stencil.stencil_test_enable).hash(hasher);
+    for op_state in [depth_stencil.front, depth_stencil.back].iter() {
+        (op_state.fail_op.as_raw(), op_state.pass_op.as_raw(), op_state.depth_fail_op.as_raw(), op_state.compare_op.as_raw(),
+         op_state.compare_mask, op_state.write_mask, op_state.reference).hash(hasher);
+    }
+
+    let blend = states.blend.ci();
+    (blend.logic_op_enable, blend.logic_op.as_raw()).hash(hasher);
+    for attachment in raw_slice(blend.p_attachments, blend.attachment_count).iter() {
+        (attachment.blend_enable, attachment.src_color_blend_factor.as_raw(), attachment.dst_color_blend_factor.as_raw(), attachment.color_blend_op.as_raw(),
+         attachment.src_alpha_blend_factor.as_raw(), attachment.dst_alpha_blend_factor.as_raw(), attachment.alpha_blend_op.as_raw(),
+         attachment.color_write_mask.as_raw()).hash(hasher);
+    }
+    for constant in blend.blend_constants.iter() {
+        constant.to_bits().hash(hasher);
+    }
+
+    states.tessellation.as_ref()
+        .map(|tessellation| tessellation.ci().patch_control_points)
+        .hash(hasher);
+
+    let dynamic = states.dynamic.ci();
+    for state in raw_slice(dynamic.p_dynamic_states, dynamic.dynamic_state_count).iter() {
+        state.as_raw().hash(hasher);
+    }
+}
+
+/// View the array in a create info. The pointer comes from a `Vec` whose length is `count`.
+fn raw_slice<'a, T>(ptr: *const T, count: u32) -> &'a [T] {
+
+    if count == 0 {
+        &[]
+    } else {
+        unsafe { slice::from_raw_parts(ptr, count as usize) }
+    }
+}
@@ -4,8 +4,13 @@ pub use self::config::GfxPipelineConfig;
 pub use self::builder::GfxPipelineBuilder;
 pub use self::multi::GfxMultiPipelineBuilder;
 pub use self::set::GfxPipelineSetBuilder;
+pub use self::library::PipelineLibrary;
+
+pub(super) use self::resize::GfxResizablePipeline;
 
 mod builder;
 mod multi;
 mod set;
+mod library;
 mod config;
+mod resize;
@@ -72,6 +72,7 @@ impl GfxMultiPipelineBuilder {
         let derive_state = PipelineDeriveState::Independence;
 
         let mut layouts = Vec::with_capacity(pipeline_count);
+        let mut flags = Vec::with_capacity(pipeline_count);
         let mut _shader_cis = Vec::with_capacity(pipeline_count);
         let mut pipeline_cis = Vec::with_capacity(pipeline_count);
 
@@ -79,6 +80,7 @@ impl GfxMultiPipelineBuilder {
 
             let pipeline_ci = builder::pipeline_ci(&self.device, &self.ci_flag, &config.modules, &config.content, &derive_state)?;
 
+            flags.push(pipeline_ci.content.flags);
             pipeline_cis.push(pipeline_ci.content);
             layouts.push(pipeline_ci.pipeline_layout);
             // Notice: keep `shader_ci` outlive the loop, or the pointer will be invalid.
@@ -87,14 +89,17 @@ impl GfxMultiPipelineBuilder {
 
         let handles = unsafe {
             self.device.logic.handle.create_graphics_pipelines(vk::PipelineCache::null(), &pipeline_cis, None)
-                .or(Err(VkError::create("Graphics Pipelines")))?
         };
-        self.destroy_modules();
+        if handles.is_err() {
+            self.destroy_modules();
+        }
+        let handles = handles.or(Err(VkError::create("Graphics Pipelines")))?;
 
         let mut pipelines = Vec::with_capacity(self.configs.len());
         let device_cloned = self.device.clone();
         for (i, config) in self.configs.into_iter().enumerate() {
-            let pipeline = GsPipeline::new(device_cloned.clone(), handles[i], layouts[i], config.content.render_pass);
+            // the shader modules are destroyed or kept by the pipeline here.
+            let pipeline = builder::wrap_pipeline(&device_cloned, handles[i], flags[i], layouts[i], config.modules, config.content);
             pipelines.push(pipeline);
         }
 
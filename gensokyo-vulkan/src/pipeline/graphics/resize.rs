@@ -0,0 +1,148 @@
+
+use ash::vk;
+use ash::version::DeviceV1_0;
+
+use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
+use crate::core::swapchain::{ GsSwapchain, SwapchainDependent };
+
+use crate::pipeline::state::PipelineStates;
+use crate::pipeline::shader::GsRetainedShader;
+
+use crate::error::{ VkResult, VkError };
+use crate::types::{ vkuint, vkDim2D };
+
+use std::sync::RwLock;
+use std::ptr;
+
+
+/// The graphics pipeline with fixed viewport or scissor, which is used in a render pass built for swapchain.
+///
+/// It keeps its shader modules and pipeline states, so that it is rebuilt with the new dimension when the swapchain is resized.
+pub(in crate::pipeline) struct GfxResizablePipeline {
+
+    content: RwLock<ResizableContent>,
+}
+
+struct ResizableContent {
+
+    handle : vk::Pipeline,
+    flags  : vk::PipelineCreateFlags,
+    layout : vk::PipelineLayout,
+    render_pass: vk::RenderPass,
+    subpass: vkuint,
+
+    shaders: Vec<GsRetainedShader>,
+    states : PipelineStates,
+
+    /// the dimension of the framebuffers this pipeline is built for.
+    dimension: vkDim2D,
+    is_discarded: bool,
+}
+
+impl GfxResizablePipeline {
+
+    pub fn new(handle: vk::Pipeline, flags: vk::PipelineCreateFlags, layout: vk::PipelineLayout, render_pass: vk::RenderPass, subpass: vkuint, shaders: Vec<GsRetainedShader>, states: PipelineStates, dimension: vkDim2D) -> GfxResizablePipeline {
+
+        let content = ResizableContent {
+            handle, flags, layout, render_pass, subpass, shaders, states, dimension,
+            is_discarded: false,
+        };
+
+        GfxResizablePipeline {
+            content: RwLock::new(content),
+        }
+    }
+
+    pub fn handle(&self) -> vk::Pipeline {
+        self.content.read().unwrap().handle
+    }
+
+    /// Destroy the pipeline and its shader modules. The pipeline layout and render pass are destroyed by its owner.
+    pub fn discard(&self, device: &GsDevice) {
+
+        let mut content = self.content.write().unwrap();
+
+        device.logic.defer_destroy(DestroyHandle::Pipeline(content.handle));
+        for shader in content.shaders.iter() {
+            shader.discard(device);
+        }
+        content.is_discarded = true;
+    }
+}
+
+impl SwapchainDependent for GfxResizablePipeline {
+
+    fn resize(&self, device: &GsDevice, chain: &GsSwapchain) -> VkResult<()> {
+
+        let mut guard = self.content.write().unwrap();
+        let content = &mut *guard;
+
+        if content.is_discarded {
+            return Ok(())
+        }
+
+        let dimension = chain.dimension();
+        let is_resized = content.states.viewport.resize(content.dimension, dimension);
+        content.dimension = dimension;
+
+        if is_resized {
+            let handle = content.rebuild(device)?;
+            device.logic.defer_destroy(DestroyHandle::Pipeline(content.handle));
+            content.handle = handle;
+        }
+
+        Ok(())
+    }
+}
+
+impl ResizableContent {
+
+    fn rebuild(&self, device: &GsDevice) -> VkResult<vk::Pipeline> {
+
+        // keep the specialization info alive until the pipeline is created.
+        let specializations: Vec<Option<vk::SpecializationInfo>> = self.shaders.iter()
+            .map(|shader| shader.specialization_info()).collect();
+        let shader_cis: Vec<vk::PipelineShaderStageCreateInfo> = self.shaders.iter().zip(specializations.iter())
+            .map(|(shader, specialization)| shader.ci(specialization.as_ref())).collect();
+
+        let vertex_input_ci   = self.states.vertex_input.ci();
+        let input_assembly_ci = self.states.input_assembly.ci();
+        let viewport_ci       = self.states.viewport.ci();
+        let rasterization_ci  = self.states.rasterizer.ci();
+        let multisample_ci    = self.states.multisample.ci();
+        let depth_stencil_ci  = self.states.depth_stencil.ci();
+        let blend_ci          = self.states.blend.ci();
+        let tessellation_ci   = self.states.tessellation.as_ref().map(|t| t.ci());
+        let dynamic_ci        = self.states.dynamic.ci();
+
+        let pipeline_ci = vk::GraphicsPipelineCreateInfo {
+            s_type: vk::StructureType::GRAPHICS_PIPELINE_CREATE_INFO,
+            p_next: ptr::null(),
+            flags : self.flags,
+            stage_count: shader_cis.len() as _,
+            p_stages   : shader_cis.as_ptr(),
+            p_vertex_input_state  : &vertex_input_ci,
+            p_input_assembly_state: &input_assembly_ci,
+            p_viewport_state      : &viewport_ci,
+            p_rasterization_state : &rasterization_ci,
+            p_multisample_state   : &multisample_ci,
+            p_depth_stencil_state : &depth_stencil_ci,
+            p_color_blend_state   : &blend_ci,
+            p_tessellation_state  : tessellation_ci.as_ref().map_or(ptr::null(), |t| t as *const vk::PipelineTessellationStateCreateInfo),
+            p_dynamic_state       : if self.states.dynamic.is_contain_state() { &dynamic_ci } else { ptr::null() },
+            layout     : self.layout,
+            render_pass: self.render_pass,
+            subpass    : self.subpass,
+            // the rebuilt pipeline does not derive from other pipelines.
+            base_pipeline_handle: vk::Pipeline::null(),
+            base_pipeline_index: -1,
+        };
+
+        let mut handles = unsafe {
+            device.logic.handle.create_graphics_pipelines(vk::PipelineCache::null(), &[pipeline_ci], None)
+                .or(Err(VkError::create("Graphics Pipelines")))?
+        };
+        Ok(handles.pop().unwrap())
+    }
+}
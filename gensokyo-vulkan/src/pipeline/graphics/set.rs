@@ -9,12 +9,16 @@ use crate::pipeline::shader::shaderc::{ GsShaderCompiler, ShaderCompilePrefab, S
 use crate::pipeline::graphics::builder;
 use crate::pipeline::graphics::builder::{ PipelineDeriveState, PipelineCIFlags };
 use crate::pipeline::graphics::config::GfxPipelineConfig;
+use crate::pipeline::state::viewport::ViewportDynamics;
 
 use crate::error::{ VkResult, VkError };
 use crate::utils::phantom::Graphics;
 
 
 /// Graphics Pipeline Set Builder.
+///
+/// The pipelines in the set are not rebuilt when the swapchain is resized,
+/// so keep their viewport and scissor dynamic if the render pass is built for swapchain.
 pub struct GfxPipelineSetBuilder {
 
     device : GsDevice,
@@ -24,6 +28,7 @@ pub struct GfxPipelineSetBuilder {
     template: GfxPipelineConfig,
     layout: vk::PipelineLayout,
     pipelines: Vec<vk::Pipeline>,
+    viewports: Vec<ViewportDynamics>,
 
     is_use_base_pipeline: bool,
 }
@@ -40,6 +45,7 @@ impl GfxPipelineSetBuilder {
             shaderc: GsShaderCompiler::setup(ShaderCompilePrefab::Vulkan)?,
             template, layout,
             pipelines: vec![],
+            viewports: vec![],
             is_use_base_pipeline: true,
         };
         Ok(builder)
@@ -95,12 +101,13 @@ impl GfxPipelineSetBuilder {
 
         let index = PipelineIndex(self.pipelines.len());
         self.pipelines.push(handles.pop().unwrap());
+        self.viewports.push(self.template.states.viewport_dynamics());
 
         Ok(index)
     }
 
     pub fn collect_into_set(self) -> GsPipelineSet<Graphics> {
 
-        GsPipelineSet::<Graphics>::new(self.device, self.pipelines, self.layout, self.template.render_pass)
+        GsPipelineSet::<Graphics>::new(self.device, self.pipelines, self.layout, self.template.render_pass, self.viewports)
     }
 }
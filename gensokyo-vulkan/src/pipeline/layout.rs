@@ -3,6 +3,8 @@ use ash::vk;
 use ash::version::DeviceV1_0;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
+use crate::core::device::{ SetLayoutKey, PipelineLayoutKey };
 use crate::pipeline::target::GsPipelineStage;
 use crate::descriptor::GsDescriptorSetLayout;
 
@@ -14,6 +16,8 @@ use std::ptr;
 pub struct PipelineLayoutBuilder {
 
     descriptor_layouts: Vec<vk::DescriptorSetLayout>,
+    /// the contents of `descriptor_layouts`, used to identify the pipeline layout.
+    descriptor_keys   : Vec<SetLayoutKey>,
     push_constants    : Vec<vk::PushConstantRange>,
 }
 
@@ -22,6 +26,7 @@ impl GsPipelineLayout {
     pub fn new() -> PipelineLayoutBuilder {
         PipelineLayoutBuilder {
             descriptor_layouts: Vec::new(),
+            descriptor_keys   : Vec::new(),
             push_constants    : Vec::new(),
         }
     }
@@ -42,14 +47,23 @@ impl PipelineLayoutBuilder {
             p_push_constant_ranges    : self.push_constants.as_ptr(),
         };
 
-        unsafe {
+        // identical layouts share the same handle through the layout cache of device.
+        let handle = device.logic.layout_cache().acquire_pipeline_layout(self.cache_key(), || unsafe {
             device.logic.handle.create_pipeline_layout(&pipeline_layout_ci, None)
                 .or(Err(VkError::create("Pipeline Layout")))
-        }
+        })?;
+
+        Ok(handle)
+    }
+
+    /// The key identifying the pipeline layout this builder would create.
+    pub(crate) fn cache_key(&self) -> PipelineLayoutKey {
+        PipelineLayoutKey::new(&self.descriptor_keys, &self.push_constants)
     }
 
     pub fn add_descriptor_layout(&mut self, layout: &GsDescriptorSetLayout) {
         self.descriptor_layouts.push(layout.handle);
+        self.descriptor_keys.push(layout.key.clone());
     }
 
     pub fn add_push_constant(&mut self, range: GsPushConstantRange) {
@@ -65,10 +79,9 @@ pub struct GsPipelineLayout {
 
 impl GsPipelineLayout {
 
+    /// Release this layout. The handle is destroyed once all the pipelines using it have been discarded.
     pub fn discard(&self, device: &GsDevice) {
-        unsafe {
-            device.logic.handle.destroy_pipeline_layout(self.handle, None);
-        }
+        device.logic.release_layout(DestroyHandle::PipelineLayout(self.handle));
     }
 }
 
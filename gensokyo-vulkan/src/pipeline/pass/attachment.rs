@@ -2,6 +2,7 @@
 use ash::vk;
 
 use crate::pipeline::pass::subpass::AttachmentRawType;
+use crate::image::{ DepthStencilImageFormat, GsImageFormat };
 use crate::types::format::Format;
 
 pub trait RenderAttType: Sized {
@@ -17,10 +18,18 @@ pub trait RenderAttType: Sized {
 pub enum AttachmentView {
     Present,
     DepthStencil(vk::ImageView),
+    /// the image views for each framebuffer, or a single image view shared by all framebuffers.
+    Color(Vec<vk::ImageView>),
+    /// the depth stencil image created by the render pass, whose dimension follows the swapchain.
+    SwapchainDepth { format: DepthStencilImageFormat, samples: vk::SampleCountFlags },
 }
 
 pub struct Present;
 pub struct DepthStencil(pub(crate) vk::ImageView);
+/// The offscreen color attachment, whose content is usually sampled by the following render passes.
+pub struct Color(pub(crate) Vec<vk::ImageView>);
+/// The depth stencil attachment whose image is created by the render pass, and recreated when the swapchain is resized.
+pub struct SwapchainDepth(DepthStencilImageFormat);
 
 /// Wrapper class of vk::Attachment.
 pub struct RenderAttachmentCI<T>
@@ -112,7 +121,28 @@ impl<T> RenderAttachmentCI<T>
     }
 
     pub(super) fn take(self) -> (vk::AttachmentDescription, AttachmentView, vk::ClearValue) {
-        (self.content, self.phantom.frame_view(), self.clear_value)
+
+        let frame_view = match self.phantom.frame_view() {
+            // the depth image is created with the sample count of this attachment.
+            | AttachmentView::SwapchainDepth { format, .. } => {
+                AttachmentView::SwapchainDepth { format, samples: self.content.samples }
+            },
+            | frame_view => frame_view,
+        };
+
+        (self.content, frame_view, self.clear_value)
+    }
+}
+
+impl RenderAttachmentCI<SwapchainDepth> {
+
+    /// Create a depth stencil attachment whose image is created by the render pass.
+    ///
+    /// The image keeps the same dimension with the swapchain, so it's only available in the render pass built for swapchain.
+    pub fn swapchain_depth(format: DepthStencilImageFormat) -> RenderAttachmentCI<SwapchainDepth> {
+
+        let attachment_format: GsImageFormat = format.into();
+        RenderAttachmentCI::create(SwapchainDepth(format), attachment_format.into())
     }
 }
 
@@ -166,3 +196,43 @@ impl RenderAttType for DepthStencil {
         AttachmentView::DepthStencil(self.0)
     }
 }
+
+impl RenderAttType for Color {
+    const IMAGE_LAYOUT: vk::ImageLayout = vk::ImageLayout::COLOR_ATTACHMENT_OPTIMAL;
+    const CLEAR_VALUE: vk::ClearValue = vk::ClearValue { color: vk::ClearColorValue { float32: [0.0, 0.0, 0.0, 1.0] } };
+    const RAW_TYPE: AttachmentRawType = AttachmentRawType::Color;
+
+    fn build() -> vk::AttachmentDescription {
+
+        vk::AttachmentDescription {
+            flags            : vk::AttachmentDescriptionFlags::empty(),
+            format           : vk::Format::UNDEFINED,
+            samples          : vk::SampleCountFlags::TYPE_1,
+            load_op          : vk::AttachmentLoadOp::CLEAR,
+            store_op         : vk::AttachmentStoreOp::STORE,
+            stencil_load_op  : vk::AttachmentLoadOp::DONT_CARE,
+            stencil_store_op : vk::AttachmentStoreOp::DONT_CARE,
+            initial_layout   : vk::ImageLayout::UNDEFINED,
+            // make the result ready to be sampled after the render pass.
+            final_layout     : vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+        }
+    }
+
+    fn frame_view(self) -> AttachmentView {
+        AttachmentView::Color(self.0)
+    }
+}
+
+impl RenderAttType for SwapchainDepth {
+    const IMAGE_LAYOUT: vk::ImageLayout = vk::ImageLayout::DEPTH_STENCIL_ATTACHMENT_OPTIMAL;
+    const CLEAR_VALUE: vk::ClearValue = vk::ClearValue { depth_stencil: vk::ClearDepthStencilValue { depth: 1.0, stencil: 0 } };
+    const RAW_TYPE: AttachmentRawType = AttachmentRawType::DepthStencil;
+
+    fn build() -> vk::AttachmentDescription {
+        DepthStencil::build()
+    }
+
+    fn frame_view(self) -> AttachmentView {
+        AttachmentView::SwapchainDepth { format: self.0, samples: vk::SampleCountFlags::TYPE_1 }
+    }
+}
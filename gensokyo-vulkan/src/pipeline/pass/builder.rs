@@ -8,22 +8,32 @@ use crate::core::swapchain::GsChain;
 use crate::pipeline::pass::render::GsRenderPass;
 use crate::pipeline::pass::attachment::{ RenderAttachmentCI, RenderAttType, AttachmentView };
 use crate::pipeline::pass::subpass::{ RenderSubpass, AttachmentRawType };
-use crate::pipeline::pass::dependency::RenderDependencyCI;
-use crate::pipeline::pass::framebuffer::GsFramebuffer;
+use crate::pipeline::pass::dependency::{ RenderDependencyCI, SubpassStage };
+use crate::pipeline::pass::framebuffer;
+use crate::pipeline::pass::framebuffer::GsFramebufferSet;
 
 use crate::error::{ VkResult, VkError };
-use crate::types::vkuint;
+use crate::types::{ vkuint, vkDim2D };
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{ Hash, Hasher };
+use std::sync::{ Arc, RwLock };
 use std::ptr;
 
 pub struct RenderPassBuilder {
 
     device: GsDevice,
-    chain : GsChain,
+    /// the swapchain whose images are used as `Present` attachments. It's `None` for offscreen render pass.
+    chain : Option<GsChain>,
+
+    framebuffer_extent: vkDim2D,
+    framebuffer_count : usize,
 
     attachments : Vec<vk::AttachmentDescription>,
     frame_views : Vec<AttachmentView>,
     clear_values: Vec<vk::ClearValue>,
+    /// the type of each attachment, which decides its layout when it's read as input attachment.
+    raw_types   : Vec<AttachmentRawType>,
 
     subpasses   : Vec<RenderSubpass>,
     dependencies: Vec<RenderDependencyCI>,
@@ -31,15 +41,31 @@ pub struct RenderPassBuilder {
 
 impl GsRenderPass {
 
+    /// Create a builder whose framebuffers are created for each swapchain image.
     pub fn builder(device: &GsDevice, chain: &GsChain) -> RenderPassBuilder {
 
+        let mut builder = GsRenderPass::offscreen_builder(device, chain.dimension(), chain.image_count());
+        builder.chain = Some(chain.clone());
+        builder
+    }
+
+    /// Create a builder for the render pass which does not render to swapchain.
+    ///
+    /// `extent` is the dimension of framebuffers, and `framebuffer_count` is the number of framebuffers to create.
+    /// `Present` attachment can not be used in this render pass.
+    pub fn offscreen_builder(device: &GsDevice, extent: vkDim2D, framebuffer_count: usize) -> RenderPassBuilder {
+
         RenderPassBuilder {
             device: device.clone(),
-            chain : chain.clone(),
+            chain : None,
+
+            framebuffer_extent: extent,
+            framebuffer_count,
 
             attachments : vec!(),
             frame_views : vec![],
             clear_values: vec![],
+            raw_types   : vec![],
             subpasses   : vec!(),
             dependencies: vec!(),
         }
@@ -60,8 +86,10 @@ impl RenderPassBuilder {
         subpass_index as _
     }
 
-    /// create a attachment and set its reference to subpass.
-    pub fn add_attachment<A>(&mut self, attachment: RenderAttachmentCI<A>, subpass_index: vkuint)
+    /// create a attachment and set its reference to subpass, return the index of the attachment.
+    ///
+    /// The returned index is used to read the attachment in other subpasses by `add_input_attachment`.
+    pub fn add_attachment<A>(&mut self, attachment: RenderAttachmentCI<A>, subpass_index: vkuint) -> vkuint
         where
             A: RenderAttType {
 
@@ -74,7 +102,12 @@ impl RenderPassBuilder {
                 self.subpasses[subpass_index as usize].inputs.push(attachment_ref)
             },
             | AttachmentRawType::Color => {
-                self.subpasses[subpass_index as usize].colors.push(attachment_ref)
+                let subpass = &mut self.subpasses[subpass_index as usize];
+                subpass.colors.push(attachment_ref);
+                // keep each resolve attachment corresponding to the color attachment at the same index.
+                if subpass.resolves.is_empty() == false {
+                    subpass.resolves.push(unused_reference());
+                }
             },
             | AttachmentRawType::Resolve => {
                 self.subpasses[subpass_index as usize].resolves.push(attachment_ref)
@@ -88,18 +121,89 @@ impl RenderPassBuilder {
         self.attachments.push(attachment);
         self.frame_views.push(frame_view);
         self.clear_values.push(clear_value);
+        self.raw_types.push(A::RAW_TYPE);
+
+        attachment_index as _
     }
 
+    /// read the attachment at `attachment_index` as input attachment in the subpass at `subpass_index`.
+    ///
+    /// `attachment_index` is returned by `add_attachment`, and the attachment is usually written by a previous subpass.
+    /// The input attachments of a subpass are numbered by the order they are added, which is the `input_attachment_index` declared in fragment shader.
+    ///
+    /// The dependency from the subpass writing the attachment to this subpass is generated during `build`,
+    /// and the attachment is preserved through the subpasses between them.
+    pub fn add_input_attachment(&mut self, attachment_index: vkuint, subpass_index: vkuint) {
+
+        let layout = match self.raw_types[attachment_index as usize] {
+            | AttachmentRawType::DepthStencil => vk::ImageLayout::DEPTH_STENCIL_READ_ONLY_OPTIMAL,
+            | AttachmentRawType::Input
+            | AttachmentRawType::Color
+            | AttachmentRawType::Resolve => vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+        };
+
+        let input_ref = vk::AttachmentReference {
+            attachment: attachment_index,
+            layout,
+        };
+        self.subpasses[subpass_index as usize].inputs.push(input_ref);
+    }
+
+    /// create a multisampled color attachment, and the attachment where its samples are resolved to at the end of subpass.
+    ///
+    /// `resolve` is usually the `Present` attachment or a single-sampled `Color` attachment.
+    pub fn add_multisample_attachment<A, R>(&mut self, attachment: RenderAttachmentCI<A>, resolve: RenderAttachmentCI<R>, subpass_index: vkuint)
+        where
+            A: RenderAttType,
+            R: RenderAttType {
+
+        debug_assert_eq!(A::RAW_TYPE, AttachmentRawType::Color);
+        debug_assert_eq!(R::RAW_TYPE, AttachmentRawType::Color);
+
+        self.add_attachment(attachment, subpass_index);
+
+        // the resolve operation overwrites the whole render area, so the previous content is not needed.
+        let resolve = resolve.op(vk::AttachmentLoadOp::DONT_CARE, vk::AttachmentStoreOp::STORE);
+
+        let attachment_index = self.attachments.len();
+        let resolve_ref = resolve.reference(attachment_index);
+
+        let subpass = &mut self.subpasses[subpass_index as usize];
+        // the resolve attachment corresponds to the last added color attachment.
+        // `add_attachment` may have padded an unused reference for it, which is replaced here.
+        while subpass.resolves.len() < subpass.colors.len() {
+            subpass.resolves.push(unused_reference());
+        }
+        let color_index = subpass.colors.len() - 1;
+        subpass.resolves[color_index] = resolve_ref;
+        debug_assert_eq!(subpass.resolves.len(), subpass.colors.len());
+
+        let (attachment, frame_view, clear_value) = resolve.take();
+        self.attachments.push(attachment);
+        self.frame_views.push(frame_view);
+        self.clear_values.push(clear_value);
+        self.raw_types.push(AttachmentRawType::Resolve);
+    }
+
+    /// Add the dependency between subpasses, or between a subpass and the commands outside the render pass.
+    ///
+    /// The dependencies for input attachments are generated during `build`, unless a dependency between the same subpasses has been added here.
     pub fn add_dependency(&mut self, dependency: RenderDependencyCI) {
         self.dependencies.push(dependency);
     }
 
-    pub fn build(self) -> VkResult<GsRenderPass> {
+    pub fn build(mut self) -> VkResult<GsRenderPass> {
+
+        framebuffer::check_frame_views(&self.frame_views, self.chain.is_some(), self.framebuffer_count)?;
+
+        preserve_attachments(&mut self.subpasses, self.attachments.len());
 
         let subpasses: Vec<vk::SubpassDescription> = self.subpasses.iter()
             .map(|r| r.build()).collect();
-        let dependencies: Vec<vk::SubpassDependency> = self.dependencies.into_iter()
+        let mut dependencies: Vec<vk::SubpassDependency> = self.dependencies.into_iter()
             .map(|d| d.take()).collect();
+        let generated_dependencies = input_dependencies(&self.subpasses, &dependencies);
+        dependencies.extend(generated_dependencies);
 
         let render_pass_ci = vk::RenderPassCreateInfo {
             s_type: vk::StructureType::RENDER_PASS_CREATE_INFO,
@@ -118,28 +222,119 @@ impl RenderPassBuilder {
             self.device.logic.handle.create_render_pass(&render_pass_ci, None)
                 .or(Err(VkError::create("Render Pass")))?
         };
+        let compatibility = compatibility_hash(&self.attachments, &self.subpasses, &dependencies);
 
         // generate framebuffers ---------------------------------------
+        // only the framebuffers using swapchain attachments alone are recreated when the swapchain is resized.
+        // the render pass using caller-provided attachment views must be rebuilt by its owner.
+        let is_swapchain_sized = self.chain.is_some() && framebuffer::is_auto_resizable(&self.frame_views);
+
+        let chain = self.chain.as_ref().map(|chain| chain.as_ref());
+        let frames = GsFramebufferSet::build(&self.device, handle, self.frame_views, chain, self.framebuffer_extent, self.framebuffer_count)?;
+        let frames = Arc::new(RwLock::new(frames));
+
+        if is_swapchain_sized {
+            self.device.logic.track_swapchain_dependent(&frames);
+        }
+        // ------------------------------------------------------------
+
+        let render_pass = GsRenderPass::build(handle, frames, self.clear_values, compatibility, is_swapchain_sized);
+        Ok(render_pass)
+    }
+}
 
-        let framebuffer_count = self.chain.image_count();
-        let mut framebuffers = Vec::with_capacity(framebuffer_count);
+/// Hash the properties of render pass that decide its compatibility, ignoring image layouts and load/store operations.
+fn compatibility_hash(attachments: &[vk::AttachmentDescription], subpasses: &[RenderSubpass], dependencies: &[vk::SubpassDependency]) -> u64 {
 
-        for i in 0..framebuffer_count {
-            let mut builder = GsFramebuffer::new(self.chain.dimension(), 1);
+    let mut hasher = DefaultHasher::new();
+
+    for attachment in attachments.iter() {
+        (attachment.flags.as_raw(), attachment.format.as_raw(), attachment.samples.as_raw()).hash(&mut hasher);
+    }
+
+    subpasses.len().hash(&mut hasher);
+    for subpass in subpasses.iter() {
+        subpass.hash_compatibility(&mut hasher);
+    }
+
+    for dependency in dependencies.iter() {
+        (dependency.src_subpass, dependency.dst_subpass,
+         dependency.src_stage_mask.as_raw(), dependency.dst_stage_mask.as_raw(),
+         dependency.src_access_mask.as_raw(), dependency.dst_access_mask.as_raw(),
+         dependency.dependency_flags.as_raw()).hash(&mut hasher);
+    }
 
-            for frame_view in self.frame_views.iter() {
-                match frame_view {
-                    | AttachmentView::Present => builder.add_attachment(self.chain.view_at(i)),
-                    | AttachmentView::DepthStencil(view) => builder.add_attachment(view.clone()),
+    hasher.finish()
+}
+
+/// Preserve the content of each attachment through the subpasses between its first and last use, which do not use it.
+fn preserve_attachments(subpasses: &mut [RenderSubpass], attachment_count: usize) {
+
+    for attachment in 0..(attachment_count as vkuint) {
+
+        let first_use = subpasses.iter().position(|subpass| subpass.is_use(attachment));
+        let last_use = subpasses.iter().rposition(|subpass| subpass.is_use(attachment));
+
+        if let (Some(first), Some(last)) = (first_use, last_use) {
+            for subpass in subpasses[first..last].iter_mut() {
+                if subpass.is_use(attachment) == false {
+                    subpass.preserves.push(attachment);
                 }
             }
+        }
+    }
+}
 
-            let framebuffer = builder.build(&self.device, handle)?;
-            framebuffers.push(framebuffer);
+/// Generate the dependencies from the subpasses writing attachments to the subpasses reading them as input attachments.
+///
+/// The pairs of subpasses which already have a dependency in `declared` are skipped.
+fn input_dependencies(subpasses: &[RenderSubpass], declared: &[vk::SubpassDependency]) -> Vec<vk::SubpassDependency> {
+
+    let mut generated: Vec<vk::SubpassDependency> = vec![];
+
+    for (dst_index, subpass) in subpasses.iter().enumerate() {
+        for input in subpass.inputs.iter() {
+
+            // the last previous subpass writing the attachment.
+            let writer = subpasses[..dst_index].iter().enumerate().rev()
+                .filter_map(|(src_index, src)| src.write_access(input.attachment).map(|access| (src_index, access)))
+                .next();
+
+            if let Some((src_index, (src_stage, src_access))) = writer {
+
+                let (src_index, dst_index) = (src_index as vkuint, dst_index as vkuint);
+                let is_same_pair = |dependency: &vk::SubpassDependency| {
+                    dependency.src_subpass == src_index && dependency.dst_subpass == dst_index
+                };
+
+                if declared.iter().any(|d| is_same_pair(d)) {
+                    continue
+                }
+
+                match generated.iter_mut().find(|d| is_same_pair(d)) {
+                    | Some(dependency) => {
+                        dependency.src_stage_mask  |= src_stage;
+                        dependency.src_access_mask |= src_access;
+                    },
+                    | None => {
+                        let dependency = RenderDependencyCI::new(SubpassStage::AtIndex(src_index), SubpassStage::AtIndex(dst_index))
+                            .stage(src_stage, vk::PipelineStageFlags::FRAGMENT_SHADER)
+                            .access(src_access, vk::AccessFlags::INPUT_ATTACHMENT_READ)
+                            .with_flags(vk::DependencyFlags::BY_REGION);
+                        generated.push(dependency.take());
+                    },
+                }
+            }
         }
-        // ------------------------------------------------------------
+    }
 
-        let render_pass = GsRenderPass::build(handle, framebuffers, self.chain.dimension(), self.clear_values);
-        Ok(render_pass)
+    generated
+}
+
+fn unused_reference() -> vk::AttachmentReference {
+
+    vk::AttachmentReference {
+        attachment: vk::ATTACHMENT_UNUSED,
+        layout    : vk::ImageLayout::UNDEFINED,
     }
 }
@@ -3,10 +3,22 @@ use ash::vk;
 use ash::version::DeviceV1_0;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
+use crate::core::swapchain::{ GsSwapchain, SwapchainDependent };
+
+use crate::pipeline::pass::attachment::AttachmentView;
+use crate::image::{ DepthStencilImageFormat, GsImageRepository };
+use crate::image::allocator::GsImageAllocator;
+use crate::image::allocator::types::ImageStorageType;
+use crate::image::instance::depth::GsDSAttachment;
+use crate::image::instance::traits::ImageViewable;
 
 use crate::error::{ VkResult, VkError };
+use crate::utils::allot::{ GsAllocatorApi, GsAllotIntoDistributor, GsDistributeApi, GsDistIntoRepository };
+use crate::utils::phantom::Device;
 use crate::types::{ vkuint, vkDim2D };
 
+use std::sync::RwLock;
 use std::ptr;
 
 #[derive(Clone)]
@@ -26,9 +38,7 @@ impl GsFramebuffer {
     }
 
     pub fn discard(&self, device: &GsDevice) {
-        unsafe {
-            device.logic.handle.destroy_framebuffer(self.handle, None);
-        }
+        device.logic.defer_destroy(DestroyHandle::Framebuffer(self.handle));
     }
 }
 
@@ -71,3 +81,200 @@ impl FramebufferBuilder {
         self.attachments.push(attachment);
     }
 }
+
+/// The framebuffers of a render pass, which are shared by all the clones of the render pass.
+///
+/// The framebuffers built only from swapchain attachments are recreated when the swapchain is resized, together with the depth images they use.
+pub(crate) struct GsFramebufferSet {
+
+    render_pass: vk::RenderPass,
+
+    framebuffers: Vec<GsFramebuffer>,
+    extent: vkDim2D,
+
+    /// the attachment views of each framebuffer, kept to recreate the framebuffers.
+    frame_views: Vec<AttachmentView>,
+    /// the images created for `AttachmentView::SwapchainDepth` attachments, in the order of `frame_views`.
+    depth_images: Vec<SwapchainDepthImage>,
+
+    is_discarded: bool,
+}
+
+struct SwapchainDepthImage {
+
+    view: vk::ImageView,
+    /// the repository owning the depth image and its memory.
+    #[allow(dead_code)]
+    repository: GsImageRepository<Device>,
+}
+
+impl GsFramebufferSet {
+
+    /// `chain` provides the `Present` attachments and the dimension of `SwapchainDepth` attachments. It's `None` for offscreen render pass.
+    pub fn build(device: &GsDevice, render_pass: vk::RenderPass, frame_views: Vec<AttachmentView>, chain: Option<&GsSwapchain>, extent: vkDim2D, framebuffer_count: usize) -> VkResult<GsFramebufferSet> {
+
+        let mut frames = GsFramebufferSet {
+            render_pass,
+            framebuffers: Vec::with_capacity(framebuffer_count),
+            extent,
+            frame_views,
+            depth_images: vec![],
+            is_discarded: false,
+        };
+
+        frames.create_framebuffers(device, chain, framebuffer_count)?;
+        Ok(frames)
+    }
+
+    pub fn framebuffer(&self, index: usize) -> vk::Framebuffer {
+        self.framebuffers[index].handle
+    }
+
+    pub fn extent(&self) -> vkDim2D {
+        self.extent
+    }
+
+    pub fn count(&self) -> usize {
+        self.framebuffers.len()
+    }
+
+    /// Return true if all the attachments are `Present` or `SwapchainDepth`, so that the framebuffers can be recreated with the new swapchain.
+    ///
+    /// The attachment views provided by caller keep their original dimension, so these framebuffers must be rebuilt by their owner.
+    pub fn is_auto_resizable(&self) -> bool {
+        is_auto_resizable(&self.frame_views)
+    }
+
+    /// Destroy the framebuffers and depth images. The framebuffers are not recreated any more.
+    pub fn discard(&mut self, device: &GsDevice) {
+
+        self.destroy_framebuffers(device);
+        self.is_discarded = true;
+    }
+
+    fn create_framebuffers(&mut self, device: &GsDevice, chain: Option<&GsSwapchain>, framebuffer_count: usize) -> VkResult<()> {
+
+        check_frame_views(&self.frame_views, chain.is_some(), framebuffer_count)?;
+
+        for frame_view in self.frame_views.iter() {
+            if let AttachmentView::SwapchainDepth { format, samples } = frame_view {
+                let depth_image = create_depth_image(device, self.extent, *format, *samples)?;
+                self.depth_images.push(depth_image);
+            }
+        }
+
+        for i in 0..framebuffer_count {
+            let mut builder = GsFramebuffer::new(self.extent, 1);
+            let mut depth_images = self.depth_images.iter();
+
+            for frame_view in self.frame_views.iter() {
+                match frame_view {
+                    | AttachmentView::Present => {
+                        let chain = chain.unwrap();
+                        builder.add_attachment(chain.view_at(i))
+                    },
+                    | AttachmentView::DepthStencil(view) => builder.add_attachment(view.clone()),
+                    | AttachmentView::Color(views) => {
+                        let view = if views.len() == 1 { views[0] } else { views[i] };
+                        builder.add_attachment(view)
+                    },
+                    | AttachmentView::SwapchainDepth { .. } => {
+                        let depth_image = depth_images.next().unwrap();
+                        builder.add_attachment(depth_image.view)
+                    },
+                }
+            }
+
+            let framebuffer = builder.build(device, self.render_pass)?;
+            self.framebuffers.push(framebuffer);
+        }
+
+        Ok(())
+    }
+
+    fn destroy_framebuffers(&mut self, device: &GsDevice) {
+
+        self.framebuffers.drain(..)
+            .for_each(|f| f.discard(device));
+        // the images are pushed to the deferred destruction queue of device.
+        self.depth_images.clear();
+    }
+}
+
+impl SwapchainDependent for RwLock<GsFramebufferSet> {
+
+    fn resize(&self, device: &GsDevice, chain: &GsSwapchain) -> VkResult<()> {
+
+        let mut frames = self.write().unwrap();
+        if frames.is_discarded {
+            return Ok(())
+        }
+
+        if frames.is_auto_resizable() == false {
+            return Err(VkError::other("The framebuffers with caller-provided attachment views can not be resized with swapchain, please rebuild the render pass."))
+        }
+
+        frames.destroy_framebuffers(device);
+        frames.extent = chain.dimension();
+        frames.create_framebuffers(device, Some(chain), chain.image_count())
+    }
+}
+
+pub(super) fn is_auto_resizable(frame_views: &[AttachmentView]) -> bool {
+
+    frame_views.iter().all(|frame_view| {
+        match frame_view {
+            | AttachmentView::Present
+            | AttachmentView::SwapchainDepth { .. } => true,
+            | AttachmentView::DepthStencil(_)
+            | AttachmentView::Color(_) => false,
+        }
+    })
+}
+
+pub(super) fn check_frame_views(frame_views: &[AttachmentView], is_swapchain: bool, framebuffer_count: usize) -> VkResult<()> {
+
+    for frame_view in frame_views.iter() {
+        match frame_view {
+            | AttachmentView::Present => {
+                if is_swapchain == false {
+                    return Err(VkError::other("Present attachment is not available in offscreen render pass."))
+                }
+            },
+            | AttachmentView::SwapchainDepth { .. } => {
+                if is_swapchain == false {
+                    return Err(VkError::other("Swapchain depth attachment is not available in offscreen render pass."))
+                }
+            },
+            | AttachmentView::DepthStencil(_) => {},
+            | AttachmentView::Color(views) => {
+                if views.len() != 1 && views.len() != framebuffer_count {
+                    return Err(VkError::other("The count of color attachment views must be 1 or equal to the framebuffer count."))
+                }
+            },
+        }
+    }
+
+    Ok(())
+}
+
+fn create_depth_image(device: &GsDevice, extent: vkDim2D, format: DepthStencilImageFormat, samples: vk::SampleCountFlags) -> VkResult<SwapchainDepthImage> {
+
+    let depth_ci = if samples == vk::SampleCountFlags::TYPE_1 {
+        GsDSAttachment::new(extent, format)
+    } else {
+        GsDSAttachment::multisample(extent, format, samples)
+    };
+
+    let mut image_allocator = GsImageAllocator::create(device, ImageStorageType::DEVICE);
+    let depth_index = image_allocator.assign(depth_ci)?;
+
+    let image_distributor = image_allocator.allocate()?;
+    let depth_image: GsDSAttachment = image_distributor.acquire(depth_index);
+
+    let image = SwapchainDepthImage {
+        view: depth_image.entity().view,
+        repository: image_distributor.into_repository(),
+    };
+    Ok(image)
+}
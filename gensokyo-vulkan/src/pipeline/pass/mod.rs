@@ -1,7 +1,7 @@
 
 pub use self::builder::RenderPassBuilder;
 pub use self::framebuffer::{ GsFramebuffer, FramebufferBuilder };
-pub use self::attachment::{ RenderAttachmentCI, RenderAttType, Present, DepthStencil };
+pub use self::attachment::{ RenderAttachmentCI, RenderAttType, Present, DepthStencil, Color, SwapchainDepth };
 pub use self::dependency::{ RenderDependencyCI, SubpassStage };
 pub use self::render::GsRenderPass;
 
@@ -1,12 +1,13 @@
 
 use ash::vk;
-use ash::version::DeviceV1_0;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
 
-use crate::pipeline::pass::framebuffer::GsFramebuffer;
+use crate::pipeline::pass::framebuffer::GsFramebufferSet;
 use crate::types::vkDim2D;
 
+use std::sync::{ Arc, RwLock };
 use std::ptr;
 
 #[derive(Clone)]
@@ -15,32 +16,40 @@ pub struct GsRenderPass {
     pub(crate) handle: vk::RenderPass,
 
     clear_values: Vec<vk::ClearValue>,
-    framebuffers: Vec<GsFramebuffer>,
-    framebuffer_extent: vkDim2D,
+    /// the framebuffers shared by all the clones of this render pass, which are recreated when the swapchain is resized.
+    frames: Arc<RwLock<GsFramebufferSet>>,
+
+    /// the hash of the properties deciding render pass compatibility.
+    compatibility: u64,
+    /// whether the framebuffers are built only from swapchain attachments, and are recreated when the swapchain is resized.
+    is_swapchain_sized: bool,
 }
 
 impl GsRenderPass {
 
-    pub(crate) fn build(handle: vk::RenderPass, framebuffers: Vec<GsFramebuffer>, dimension: vkDim2D, clear_values: Vec<vk::ClearValue>) -> GsRenderPass {
+    pub(crate) fn build(handle: vk::RenderPass, frames: Arc<RwLock<GsFramebufferSet>>, clear_values: Vec<vk::ClearValue>, compatibility: u64, is_swapchain_sized: bool) -> GsRenderPass {
 
         GsRenderPass {
             handle,
-            framebuffers,
-            framebuffer_extent: dimension,
+            frames,
             clear_values,
+            compatibility,
+            is_swapchain_sized,
         }
     }
 
     pub fn begin_info(&self, framebuffer_index: usize) -> vk::RenderPassBeginInfo {
 
+        let frames = self.frames.read().unwrap();
+
         vk::RenderPassBeginInfo {
             s_type: vk::StructureType::RENDER_PASS_BEGIN_INFO,
             p_next: ptr::null(),
             render_pass: self.handle,
-            framebuffer: self.framebuffers[framebuffer_index].handle,
+            framebuffer: frames.framebuffer(framebuffer_index),
             render_area: vk::Rect2D {
                 offset: vk::Offset2D { x: 0, y: 0 },
-                extent: self.framebuffer_extent,
+                extent: frames.extent(),
             },
             clear_value_count: self.clear_values.len() as _,
             p_clear_values   : self.clear_values.as_ptr(),
@@ -48,16 +57,36 @@ impl GsRenderPass {
     }
 
     pub fn frame_count(&self) -> usize {
-        self.framebuffers.len()
+        self.frames.read().unwrap().count()
+    }
+
+    /// The current dimension of framebuffers, which changes when the swapchain is resized.
+    pub fn dimension(&self) -> vkDim2D {
+        self.frames.read().unwrap().extent()
+    }
+
+    /// Return true if the framebuffers are built only from `Present` and swapchain depth attachments, so that they are recreated when the swapchain is resized.
+    ///
+    /// The render pass using other attachment views must be rebuilt after the swapchain is resized.
+    pub fn is_swapchain_sized(&self) -> bool {
+        self.is_swapchain_sized
+    }
+
+    /// Return true if the pipelines created with this render pass can also be used with `other`.
+    ///
+    /// Two render passes are compatible if they only differ in image layouts and load/store operations of their attachments.
+    pub fn is_compatible(&self, other: &GsRenderPass) -> bool {
+        self.handle == other.handle || self.compatibility == other.compatibility
+    }
+
+    pub(crate) fn compatibility(&self) -> u64 {
+        self.compatibility
     }
 
     pub fn discard(&self, device: &GsDevice) {
 
-        unsafe {
-            device.logic.handle.destroy_render_pass(self.handle, None);
-        }
+        device.logic.defer_destroy(DestroyHandle::RenderPass(self.handle));
 
-        self.framebuffers.iter()
-            .for_each(|f| f.discard(device));
+        self.frames.write().unwrap().discard(device);
     }
 }
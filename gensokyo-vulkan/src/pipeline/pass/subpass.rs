@@ -3,12 +3,12 @@ use ash::vk;
 
 use crate::types::vkuint;
 
+use std::hash::{ Hash, Hasher };
 use std::ptr;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum AttachmentRawType {
 
-    #[allow(dead_code)]
     Input,
     Color,
     #[allow(dead_code)]
@@ -62,6 +62,40 @@ impl RenderSubpass {
         }
     }
 
+    /// Hash the attachment references of this subpass, ignoring their image layouts.
+    pub(super) fn hash_compatibility(&self, hasher: &mut impl Hasher) {
+
+        self.bind_point.as_raw().hash(hasher);
+
+        for references in [&self.inputs, &self.colors, &self.resolves, &self.depth_stencils].iter() {
+            references.len().hash(hasher);
+            for reference in references.iter() {
+                reference.attachment.hash(hasher);
+            }
+        }
+
+        self.preserves.hash(hasher);
+    }
+
+    /// Return true if the attachment at `attachment` is referenced by this subpass.
+    pub(super) fn is_use(&self, attachment: vkuint) -> bool {
+
+        [&self.inputs, &self.colors, &self.resolves, &self.depth_stencils].iter()
+            .any(|references| is_contain(references, attachment))
+    }
+
+    /// Return the stage and access mask writing the attachment at `attachment` in this subpass, or `None` if this subpass does not write it.
+    pub(super) fn write_access(&self, attachment: vkuint) -> Option<(vk::PipelineStageFlags, vk::AccessFlags)> {
+
+        if is_contain(&self.colors, attachment) || is_contain(&self.resolves, attachment) {
+            Some((vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT, vk::AccessFlags::COLOR_ATTACHMENT_WRITE))
+        } else if is_contain(&self.depth_stencils, attachment) {
+            Some((vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS | vk::PipelineStageFlags::LATE_FRAGMENT_TESTS, vk::AccessFlags::DEPTH_STENCIL_ATTACHMENT_WRITE))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     pub(super) fn build(&self) -> vk::SubpassDescription {
 
@@ -81,3 +115,7 @@ impl RenderSubpass {
         }
     }
 }
+
+fn is_contain(references: &[vk::AttachmentReference], attachment: vkuint) -> bool {
+    references.iter().any(|reference| reference.attachment == attachment)
+}
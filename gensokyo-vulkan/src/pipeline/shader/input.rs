@@ -5,7 +5,7 @@ use crate::pipeline::state::vertex_input::GsVertexInputState;
 
 use crate::types::vkuint;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GsVertexInputBinding {
 
     pub binding: vkuint,
@@ -13,7 +13,7 @@ pub struct GsVertexInputBinding {
     pub rate   : vk::VertexInputRate,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GsVertexInputAttribute {
 
     pub binding : vkuint,
@@ -22,7 +22,7 @@ pub struct GsVertexInputAttribute {
     pub offset  : vkuint,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VertexInputDescription {
 
     pub bindings:   Vec<GsVertexInputBinding>,
@@ -31,6 +31,28 @@ pub struct VertexInputDescription {
 
 impl VertexInputDescription {
 
+    /// Combine multiple vertex input descriptions into one.
+    ///
+    /// This is used to set up a pipeline reading both per-vertex and per-instance data, where each description comes from different binding(such as `Vertex::desc()` and `InstanceData::desc()` generated by `define_input!`).
+    pub fn compose(descriptions: Vec<VertexInputDescription>) -> VertexInputDescription {
+
+        descriptions.into_iter()
+            .fold(VertexInputDescription { bindings: vec![], attributes: vec![] }, |composed, desc| composed.merge(desc))
+    }
+
+    /// Append the bindings and attributes of `other` to this description.
+    ///
+    /// The binding index and location of `other` must not conflict with the existing ones.
+    pub fn merge(mut self, other: VertexInputDescription) -> VertexInputDescription {
+
+        debug_assert!(other.bindings.iter().all(|b| self.bindings.iter().all(|exist| exist.binding != b.binding)), "Duplicate vertex input binding index.");
+        debug_assert!(other.attributes.iter().all(|a| self.attributes.iter().all(|exist| exist.location != a.location)), "Duplicate vertex input attribute location.");
+
+        self.bindings.extend(other.bindings);
+        self.attributes.extend(other.attributes);
+        self
+    }
+
     pub fn into_desc(self) -> GsVertexInputState {
 
         let bindings = self.bindings.iter()
@@ -2,9 +2,9 @@
 pub use self::input::{ GsVertexInputAttribute, GsVertexInputBinding, VertexInputDescription };
 pub use self::module::GsShaderCI;
 
-pub(super) use self::module::GsShaderModule;
+pub(super) use self::module::{ GsShaderModule, GsRetainedShader };
 
-pub(super) mod shaderc;
+pub(crate) mod shaderc;
 
 mod module;
 mod input;
@@ -14,6 +14,8 @@ use std::path::{ Path, PathBuf };
 use std::ffi::CString;
 use std::fs::File;
 use std::io::Read;
+use std::hash::{ Hash, Hasher };
+use std::slice;
 use std::ptr;
 
 pub struct GsShaderCI {
@@ -69,6 +71,42 @@ impl GsShaderCI {
         self.specialization = Some(info);
     }
 
+    /// Hash the source location, entry point and specialization constants of this shader.
+    ///
+    /// The content of the shader file is not hashed.
+    pub(crate) fn hash_source(&self, hasher: &mut impl Hasher) {
+
+        self.stage.0.as_raw().hash(hasher);
+        self.path.hash(hasher);
+        self.main.hash(hasher);
+        self.tag_name.hash(hasher);
+
+        match self.pattern {
+            | ShaderSourcePattern::SourceCode => 0_u8.hash(hasher),
+            | ShaderSourcePattern::SprivCode  => 1_u8.hash(hasher),
+        }
+
+        self.specialization.is_some().hash(hasher);
+        if let Some(ref specialization) = self.specialization {
+
+            if specialization.map_entry_count > 0 {
+                let entries = unsafe {
+                    slice::from_raw_parts(specialization.p_map_entries, specialization.map_entry_count as usize)
+                };
+                for entry in entries.iter() {
+                    (entry.constant_id, entry.offset, entry.size).hash(hasher);
+                }
+            }
+
+            if specialization.data_size > 0 {
+                let data = unsafe {
+                    slice::from_raw_parts(specialization.p_data as *const u8, specialization.data_size)
+                };
+                data.hash(hasher);
+            }
+        }
+    }
+
     pub fn build(&self, device: &GsDevice, compiler: &mut GsShaderCompiler) -> VkResult<GsShaderModule> {
 
         use crate::pipeline::shader::shaderc::cast_shaderc_kind;
@@ -157,6 +195,89 @@ impl GsShaderModule {
             device.logic.handle.destroy_shader_module(self.handle, None);
         }
     }
+
+    /// Keep this shader module to rebuild the pipeline later.
+    ///
+    /// The specialization constants are copied, since the data they point to may be released after the pipeline is built.
+    pub(crate) fn retain(self) -> GsRetainedShader {
+
+        let specialization = self.specialization.map(|specialization| {
+            RetainedSpecialization {
+                map_entries: raw_slice(specialization.p_map_entries, specialization.map_entry_count as usize).to_vec(),
+                data: raw_slice(specialization.p_data as *const u8, specialization.data_size).to_vec(),
+            }
+        });
+
+        GsRetainedShader {
+            main  : self.main,
+            stage : self.stage,
+            handle: self.handle,
+            specialization,
+        }
+    }
+}
+
+/// The shader module kept alive after the pipeline creation, so that the pipeline can be rebuilt.
+pub(crate) struct GsRetainedShader {
+
+    main   : CString,
+    stage  : vk::ShaderStageFlags,
+    handle : vk::ShaderModule,
+
+    specialization: Option<RetainedSpecialization>,
+}
+
+struct RetainedSpecialization {
+
+    map_entries: Vec<vk::SpecializationMapEntry>,
+    data: Vec<u8>,
+}
+
+impl GsRetainedShader {
+
+    pub fn specialization_info(&self) -> Option<vk::SpecializationInfo> {
+
+        self.specialization.as_ref().map(|specialization| {
+            vk::SpecializationInfo {
+                map_entry_count: specialization.map_entries.len() as _,
+                p_map_entries  : specialization.map_entries.as_ptr(),
+                data_size      : specialization.data.len(),
+                p_data         : specialization.data.as_ptr() as _,
+            }
+        })
+    }
+
+    /// `specialization` is the value returned by `specialization_info`, which must outlive the returned create info.
+    pub fn ci(&self, specialization: Option<&vk::SpecializationInfo>) -> vk::PipelineShaderStageCreateInfo {
+
+        vk::PipelineShaderStageCreateInfo {
+            s_type : vk::StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
+            p_next : ptr::null(),
+            // flags is reserved for future use in API version 1.1.82.
+            flags  : vk::PipelineShaderStageCreateFlags::empty(),
+            stage  : self.stage,
+            module : self.handle,
+            p_name : self.main.as_ptr(),
+            p_specialization_info: specialization.map_or(ptr::null(), |s| s as *const vk::SpecializationInfo),
+        }
+    }
+
+    pub fn discard(&self, device: &GsDevice) {
+
+        unsafe {
+            device.logic.handle.destroy_shader_module(self.handle, None);
+        }
+    }
+}
+
+/// View the array pointed by a create info, which may be null if `count` is 0.
+fn raw_slice<'a, T>(ptr: *const T, count: usize) -> &'a [T] {
+
+    if count == 0 {
+        &[]
+    } else {
+        unsafe { slice::from_raw_parts(ptr, count) }
+    }
 }
 
 
@@ -1,5 +1,6 @@
 
-pub use self::blending::GsBlendState;
+pub use self::blending::{ GsBlendState, GsBlendPrefab };
+pub use self::attachment::{ BlendAttachment, BlendAttachmentPrefab };
 
 mod blending;
 mod attachment;
@@ -34,8 +34,20 @@ impl GsDynamicState {
         }
     }
 
+    /// Add `state` as a dynamic state. Each state is only added once.
     pub fn add_state(&mut self, state: vk::DynamicState) {
-        self.states.push(state);
+
+        if self.contains(state) == false {
+            self.states.push(state);
+        }
+    }
+
+    pub fn remove_state(&mut self, state: vk::DynamicState) {
+        self.states.retain(|&s| s != state);
+    }
+
+    pub fn contains(&self, state: vk::DynamicState) -> bool {
+        self.states.contains(&state)
     }
 
     pub fn is_contain_state(&self) -> bool {
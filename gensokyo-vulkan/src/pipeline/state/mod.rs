@@ -22,22 +22,38 @@ pub(super) struct PipelineStates {
     pub dynamic       : self::dynamic::GsDynamicState,
 }
 
+use ash::vk;
+
 use crate::pipeline::shader::VertexInputDescription;
-use crate::types::vkDim2D;
 
 impl PipelineStates {
 
-    pub(super) fn setup(input: VertexInputDescription, dimension: vkDim2D) -> PipelineStates {
+    /// The viewport and scissor are dynamic by default, so that the pipeline keeps valid when the framebuffers are resized.
+    pub(super) fn setup(input: VertexInputDescription) -> PipelineStates {
+
+        let mut dynamic = self::dynamic::GsDynamicState::default();
+        dynamic.add_state(vk::DynamicState::VIEWPORT);
+        dynamic.add_state(vk::DynamicState::SCISSOR);
+
         PipelineStates {
             vertex_input  : input.into_desc(),
             input_assembly: self::input_assembly::GsInputAssemblyState::default(),
-            viewport      : self::viewport::GsViewportState::single(self::viewport::ViewportStateInfo::from(dimension)),
+            viewport      : self::viewport::ViewportStateType::Dynamic { count: 1 }.into(),
             rasterizer    : self::rasterizer::GsRasterizerState::default(),
             multisample   : self::multisample::GsMultisampleState::default(),
             depth_stencil : self::depth_stencil::GsDepthStencilState::default(),
             blend         : self::blend::GsBlendState::default(),
             tessellation  : None,
-            dynamic       : self::dynamic::GsDynamicState::default(),
+            dynamic,
+        }
+    }
+
+    pub(crate) fn viewport_dynamics(&self) -> self::viewport::ViewportDynamics {
+
+        self::viewport::ViewportDynamics {
+            is_viewport_dynamic: self.dynamic.contains(vk::DynamicState::VIEWPORT),
+            is_scissor_dynamic : self.dynamic.contains(vk::DynamicState::SCISSOR),
+            count: self.viewport.count(),
         }
     }
 }
@@ -9,6 +9,8 @@ use crate::types::{ vkuint, vkbool, vkfloat, VK_FALSE, VK_TRUE };
 pub enum MultisamplePrefab {
     /// Disable multisample configuration.
     Disable,
+    /// Rasterize with the given number of samples per pixel, which must match the sample count of the attachments.
+    Multisample(vk::SampleCountFlags),
 }
 
 impl  MultisamplePrefab {
@@ -16,6 +18,7 @@ impl  MultisamplePrefab {
     fn generate(&self) -> GsMultisampleState {
         match self {
             | MultisamplePrefab::Disable => GsMultisampleState { ..Default::default() },
+            | MultisamplePrefab::Multisample(count) => GsMultisampleState { sample_count: *count, ..Default::default() },
         }
     }
 }
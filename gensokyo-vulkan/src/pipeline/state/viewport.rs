@@ -2,6 +2,7 @@
 use ash::vk;
 
 use crate::types::{ vkuint, vksint, vkfloat, vkDim2D };
+use std::hash::{ Hash, Hasher };
 use std::ptr;
 
 pub struct GsViewportState {
@@ -47,6 +48,52 @@ impl GsViewportState {
         self.length += 1;
     }
 
+    pub(crate) fn count(&self) -> vkuint {
+        self.length as _
+    }
+
+    /// Replace the fixed viewports and scissors covering the whole `from` area with the ones covering `to`.
+    ///
+    /// Return true if any viewport or scissor is replaced.
+    pub(crate) fn resize(&mut self, from: vkDim2D, to: vkDim2D) -> bool {
+
+        let mut is_resized = false;
+
+        let full_port = ViewportInfo::from(from).0;
+        for port in self.ports.iter_mut() {
+            if port.x == full_port.x && port.y == full_port.y && port.width == full_port.width && port.height == full_port.height {
+                port.width  = to.width  as _;
+                port.height = to.height as _;
+                is_resized = true;
+            }
+        }
+
+        for scissor in self.scissors.iter_mut() {
+            if scissor.offset.x == 0 && scissor.offset.y == 0 && scissor.extent.width == from.width && scissor.extent.height == from.height {
+                scissor.extent = to;
+                is_resized = true;
+            }
+        }
+
+        is_resized
+    }
+
+    /// Hash the fixed viewports and scissors, and the count of viewports.
+    pub(crate) fn hash_state(&self, hasher: &mut impl Hasher) {
+
+        self.length.hash(hasher);
+
+        for port in self.ports.iter() {
+            for value in [port.x, port.y, port.width, port.height, port.min_depth, port.max_depth].iter() {
+                value.to_bits().hash(hasher);
+            }
+        }
+
+        for scissor in self.scissors.iter() {
+            (scissor.offset.x, scissor.offset.y, scissor.extent.width, scissor.extent.height).hash(hasher);
+        }
+    }
+
     pub(crate) fn ci(&self) -> vk::PipelineViewportStateCreateInfo {
 
         vk::PipelineViewportStateCreateInfo {
@@ -62,6 +109,26 @@ impl GsViewportState {
     }
 }
 
+/// Record which parts of the viewport state of a pipeline are set in command buffer.
+///
+/// `GsCmdGraphicsApi::begin_render_pass` sets the dynamic viewports and scissors to the whole framebuffer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ViewportDynamics {
+
+    pub(crate) is_viewport_dynamic: bool,
+    pub(crate) is_scissor_dynamic : bool,
+    /// the count of viewports and scissors.
+    pub(crate) count: vkuint,
+}
+
+impl ViewportDynamics {
+
+    /// Return true if the viewports and scissors are all dynamic, so that the pipeline does not depend on the dimension of framebuffers.
+    pub fn is_fully_dynamic(&self) -> bool {
+        self.is_viewport_dynamic && self.is_scissor_dynamic
+    }
+}
+
 pub enum ViewportStateType {
 
     /// `Fixed` specifies that viewports and scissors is set to fixed value during the whole pipeline.
@@ -1,16 +1,19 @@
 
 use ash::vk;
-use ash::version::DeviceV1_0;
 
 use crate::core::GsDevice;
+use crate::core::device::DestroyHandle;
 
 use crate::pipeline::layout::GsPipelineLayout;
 use crate::pipeline::pass::GsRenderPass;
+use crate::pipeline::state::viewport::ViewportDynamics;
+use crate::pipeline::graphics::GfxResizablePipeline;
 
 use crate::command::CmdPipelineAbs;
 use crate::utils::phantom::{ Graphics, Compute };
 
 use std::marker::PhantomData;
+use std::sync::Arc;
 use std::ops::{ BitAnd, BitAndAssign, BitOr, BitOrAssign };
 
 
@@ -35,23 +38,41 @@ pub struct GsPipeline<T>
 
     phantom_type: PhantomData<T>,
 
-    pub(crate) handle: vk::Pipeline,
+    handle: PipelineHandle,
     pub(crate) pass  : GsRenderPass,
     pub(crate) layout: GsPipelineLayout,
+    viewport: ViewportDynamics,
 
     device: GsDevice,
 }
 
+enum PipelineHandle {
+    Fixed(vk::Pipeline),
+    /// the pipeline rebuilt when the swapchain is resized.
+    Resizable(Arc<GfxResizablePipeline>),
+}
+
 impl<T> GsPipeline<T>
     where
         T: GsVkPipelineType {
 
-    pub(super) fn new(device: GsDevice, handle: vk::Pipeline, layout: vk::PipelineLayout, pass: GsRenderPass) -> GsPipeline<T> {
+    pub(super) fn new(device: GsDevice, handle: vk::Pipeline, layout: vk::PipelineLayout, pass: GsRenderPass, viewport: ViewportDynamics) -> GsPipeline<T> {
+
+        GsPipeline {
+            phantom_type: PhantomData,
+            handle: PipelineHandle::Fixed(handle),
+            layout: GsPipelineLayout { handle: layout },
+            device, pass, viewport,
+        }
+    }
+
+    pub(super) fn resizable(device: GsDevice, resizable: Arc<GfxResizablePipeline>, layout: vk::PipelineLayout, pass: GsRenderPass, viewport: ViewportDynamics) -> GsPipeline<T> {
 
         GsPipeline {
             phantom_type: PhantomData,
+            handle: PipelineHandle::Resizable(resizable),
             layout: GsPipelineLayout { handle: layout },
-            device, handle, pass,
+            device, pass, viewport,
         }
     }
 
@@ -66,8 +87,13 @@ impl<T> Drop for GsPipeline<T>
 
     fn drop(&mut self) {
 
-        unsafe {
-            self.device.logic.handle.destroy_pipeline(self.handle, None);
+        match self.handle {
+            | PipelineHandle::Fixed(handle) => {
+                self.device.logic.defer_destroy(DestroyHandle::Pipeline(handle));
+            },
+            | PipelineHandle::Resizable(ref resizable) => {
+                resizable.discard(&self.device);
+            },
         }
 
         self.layout.discard(&self.device);
@@ -83,13 +109,20 @@ impl<T> CmdPipelineAbs for GsPipeline<T>
         &self.layout.handle
     }
 
-    fn pipeline(&self) -> &vk::Pipeline {
-        &self.handle
+    fn pipeline(&self) -> vk::Pipeline {
+        match self.handle {
+            | PipelineHandle::Fixed(handle) => handle,
+            | PipelineHandle::Resizable(ref resizable) => resizable.handle(),
+        }
     }
 
     fn render_pass(&self) -> &GsRenderPass {
         &self.pass
     }
+
+    fn viewport_dynamics(&self) -> ViewportDynamics {
+        self.viewport
+    }
 }
 // -------------------------------------------------------------------------------------
 
@@ -103,6 +136,7 @@ pub struct GsPipelineSet<T>
     pub(crate) handles: Vec<vk::Pipeline>,
     pub(crate) pass   : GsRenderPass,
     pub(crate) layout : GsPipelineLayout,
+    viewports: Vec<ViewportDynamics>,
 
     device: GsDevice,
 }
@@ -113,18 +147,19 @@ pub struct GsPipelineElement<'a> {
     pipeline: vk::Pipeline,
     layout  : &'a GsPipelineLayout,
     pass    : &'a GsRenderPass,
+    viewport: ViewportDynamics,
 }
 
 impl<T> GsPipelineSet<T>
     where
         T: GsVkPipelineType {
 
-    pub(crate) fn new(device: GsDevice, handles: Vec<vk::Pipeline>, layout: vk::PipelineLayout, pass: GsRenderPass) -> GsPipelineSet<Graphics> {
+    pub(crate) fn new(device: GsDevice, handles: Vec<vk::Pipeline>, layout: vk::PipelineLayout, pass: GsRenderPass, viewports: Vec<ViewportDynamics>) -> GsPipelineSet<Graphics> {
 
         GsPipelineSet {
             phantom_type: PhantomData,
             layout: GsPipelineLayout { handle: layout },
-            device, handles, pass,
+            device, handles, pass, viewports,
         }
     }
 
@@ -134,6 +169,7 @@ impl<T> GsPipelineSet<T>
             pipeline: self.handles[at.0],
             layout  : &self.layout,
             pass    : &self.pass,
+            viewport: self.viewports[at.0],
         }
     }
 
@@ -149,28 +185,37 @@ impl<T> Drop for GsPipelineSet<T>
     fn drop(&mut self) {
 
         for &handle in self.handles.iter() {
-            unsafe {
-                self.device.logic.handle.destroy_pipeline(handle, None);
-            }
+            self.device.logic.defer_destroy(DestroyHandle::Pipeline(handle));
         }
         self.layout.discard(&self.device);
         self.pass.discard(&self.device);
     }
 }
 
+impl<'a> GsPipelineElement<'a> {
+
+    pub(crate) fn new(pipeline: vk::Pipeline, layout: &'a GsPipelineLayout, pass: &'a GsRenderPass, viewport: ViewportDynamics) -> GsPipelineElement<'a> {
+        GsPipelineElement { pipeline, layout, pass, viewport }
+    }
+}
+
 impl<'a> CmdPipelineAbs for GsPipelineElement<'a> {
 
     fn layout(&self) -> &vk::PipelineLayout {
         &self.layout.handle
     }
 
-    fn pipeline(&self) -> &vk::Pipeline {
-        &self.pipeline
+    fn pipeline(&self) -> vk::Pipeline {
+        self.pipeline
     }
 
     fn render_pass(&self) -> &GsRenderPass {
         &self.pass
     }
+
+    fn viewport_dynamics(&self) -> ViewportDynamics {
+        self.viewport
+    }
 }
 // -------------------------------------------------------------------------------------
 
@@ -5,10 +5,15 @@ pub use crate::buffer::GsBufferRepository;
 
 pub use crate::buffer::instance::GsVertexBuffer;
 pub use crate::buffer::instance::GsIndexBuffer;
+pub use crate::buffer::instance::GsIndexType;
+pub use crate::buffer::instance::GsInstanceBuffer;
 pub use crate::buffer::instance::GsUniformBuffer;
+pub use crate::buffer::instance::GsTexelBuffer;
 
 pub use crate::buffer::allocator::types::BufferStorageType;
 
 pub use crate::buffer::instance::IVertex;
 pub use crate::buffer::instance::IIndices;
+pub use crate::buffer::instance::IInstance;
 pub use crate::buffer::instance::IUniform;
+pub use crate::buffer::instance::ITexel;
@@ -9,3 +9,4 @@ pub use crate::utils::phantom::Host;
 pub use crate::utils::phantom::Cached;
 pub use crate::utils::phantom::Device;
 pub use crate::utils::phantom::Staging;
+pub use crate::utils::phantom::Transient;
@@ -2,6 +2,9 @@
 pub use crate::descriptor::allocator::GsDescriptorAllocator;
 pub use crate::descriptor::allocator::GsDescriptorDistributor;
 pub use crate::descriptor::GsDescriptorRepository;
+pub use crate::descriptor::GsDescriptorPoolChain;
 
 pub use crate::descriptor::DescriptorSetConfig;
 pub use crate::descriptor::DescriptorSet;
+pub use crate::descriptor::DescriptorSetUpdater;
+pub use crate::descriptor::BindlessTextureTable;
@@ -7,15 +7,20 @@ pub use crate::image::GsImageRepository;
 pub use crate::image::instance::combinedimg::{ GsCombinedImgSampler, ICombinedImg };
 pub use crate::image::instance::sampledimg::{ GsSampledImage, ISampledImg };
 pub use crate::image::instance::cubemap::{ GsCubeMapImg, ICubeMap };
+pub use crate::image::instance::array::{ GsTexture2DArray, ITexture2DArray };
+pub use crate::image::instance::volume::{ GsTexture3D, ITexture3D };
 pub use crate::image::instance::depth::{ GsDSAttachment, IDepthStencilImg };
+pub use crate::image::instance::color::{ GsColorAttachment, IColorAttachment };
+pub use crate::image::instance::storage::{ GsStorageImage, IStorageImg };
 pub use crate::image::instance::sampler::{ GsSampler, GsSamplerArray };
 
 pub use crate::image::allocator::types::ImageStorageType;
 
-pub use crate::image::MipmapMethod;
+pub use crate::image::{ MipmapMethod, MipmapFilter };
 pub use crate::image::GsImageFormat;
 
 pub use crate::image::ImagePipelineStage;
 pub use crate::image::DepthStencilImageFormat;
 
-pub use crate::image::instance::traits::{ ImageCICommonApi, ImageTgtCIApi, ImageViewCIApi };
+pub use crate::image::instance::traits::{ ImageCICommonApi, ImageTgtCIApi, ImageViewCIApi, ImageViewable };
+pub use crate::image::{ ImageSubViewCI, ImageSubRange };
@@ -1,7 +1,7 @@
 
 pub use crate::pipeline::target::{ GsPipeline, GsPipelineStage, PipelineIndex, GsPipelineSet };
 pub use crate::pipeline::graphics::GfxPipelineConfig;
-pub use crate::pipeline::graphics::{ GfxPipelineBuilder, GfxMultiPipelineBuilder, GfxPipelineSetBuilder };
+pub use crate::pipeline::graphics::{ GfxPipelineBuilder, GfxMultiPipelineBuilder, GfxPipelineSetBuilder, PipelineLibrary };
 pub use crate::pipeline::shader::GsShaderCI;
 pub use crate::pipeline::shader::{ VertexInputDescription, GsVertexInputAttribute, GsVertexInputBinding };
 
@@ -12,13 +12,13 @@ pub use crate::pipeline::state::{
     rasterizer::{ GsRasterizerState, RasterizerPrefab, DepthBiasInfo },
     multisample::{ GsMultisampleState, MultisamplePrefab, SampleShading },
     depth_stencil::{ GsDepthStencilState, GsDepthStencilPrefab, DepthTest, DepthBoundInfo, StencilTest, StencilOpState },
-    blend::GsBlendState,
+    blend::{ GsBlendState, GsBlendPrefab, BlendAttachment, BlendAttachmentPrefab },
     tessellation::GsTessellationState,
     dynamic::DynamicableValue,
 };
 
 pub use crate::pipeline::pass::{ GsRenderPass, RenderAttachmentCI, RenderDependencyCI, SubpassStage };
-pub use crate::pipeline::pass::{ Present, DepthStencil };
+pub use crate::pipeline::pass::{ Present, DepthStencil, Color, SwapchainDepth };
 pub use crate::pipeline::layout::GsPushConstantRange;
 
 
@@ -35,6 +35,9 @@ pub struct Format(vk::Format);
 impl Format {
     pub const UNDEFINED          : Format = Format(vk::Format::UNDEFINED);
     pub const RGBA8_UNORM        : Format = Format(vk::Format::R8G8B8A8_UNORM);
+    pub const RGBA8_SRGB         : Format = Format(vk::Format::R8G8B8A8_SRGB);
+    pub const RGBA16_SFLOAT      : Format = Format(vk::Format::R16G16B16A16_SFLOAT);
+    pub const RGBA32_SFLOAT      : Format = Format(vk::Format::R32G32B32A32_SFLOAT);
     pub const D32_SFLOAT         : Format = Format(vk::Format::D32_SFLOAT);
     pub const D24_UNORM_S8_UINT  : Format = Format(vk::Format::D24_UNORM_S8_UINT);
     pub const D32_SFLOAT_S8_UINT : Format = Format(vk::Format::D32_SFLOAT_S8_UINT);
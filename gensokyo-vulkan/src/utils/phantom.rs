@@ -7,6 +7,8 @@ pub struct Cached;
 pub struct Device;
 #[derive(Debug, Clone, Copy)]
 pub struct Staging;
+#[derive(Debug, Clone, Copy)]
+pub struct Transient;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Graphics;
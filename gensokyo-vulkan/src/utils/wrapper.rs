@@ -38,7 +38,6 @@ impl<C, I> VKWrapperInfo<C, I> {
         }
     }
 
-    #[allow(dead_code)]
     pub fn push(&mut self, pair: VKWrapperPair<C, I>) {
         self.contents.push(pair.content);
         self.infos.push(pair.info);
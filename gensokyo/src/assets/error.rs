@@ -3,12 +3,33 @@ use failure_derive::Fail;
 
 use crate::error::GsError;
 
+use std::path::{ Path, PathBuf };
+
 #[derive(Debug, Fail)]
 pub enum AssetsError {
     #[fail(display = "{}", _0)]
     Image(#[cause] image::ImageError),
     #[fail(display = "{}", _0)]
     Gltf(#[cause] GltfError),
+    #[fail(display = "{}", _0)]
+    Texture(#[cause] TextureError),
+    #[fail(display = "{}", _0)]
+    Pipeline(#[cause] PipelineError),
+}
+
+#[derive(Debug, Fail)]
+pub enum TextureError {
+    #[fail(display = "Failed to read texture file: {}", _0)]
+    Io(#[cause] std::io::Error),
+    #[fail(display = "{}", description)]
+    Parsing { description: &'static str },
+}
+
+impl TextureError {
+
+    pub fn parsing(description: &'static str) -> TextureError {
+        TextureError::Parsing { description }
+    }
 }
 
 #[derive(Debug, Fail)]
@@ -28,6 +49,27 @@ impl GltfError {
     }
 }
 
+#[derive(Debug, Fail)]
+pub enum PipelineError {
+    #[fail(display = "Failed to read pipeline description file: {}", _0)]
+    Io(#[cause] std::io::Error),
+    #[fail(display = "Failed to parse pipeline description {:?}: {}", path, error)]
+    Toml { path: PathBuf, #[cause] error: toml::de::Error },
+    #[fail(display = "Invalid pipeline description {:?} at `{}`: {}", path, key, description)]
+    Invalid { path: PathBuf, key: String, description: String },
+}
+
+impl PipelineError {
+
+    pub fn invalid(path: impl AsRef<Path>, key: impl AsRef<str>, description: impl AsRef<str>) -> PipelineError {
+        PipelineError::Invalid {
+            path: path.as_ref().to_path_buf(),
+            key : key.as_ref().to_string(),
+            description: description.as_ref().to_string(),
+        }
+    }
+}
+
 impl From<AssetsError> for GsError {
 
     fn from(error: AssetsError) -> GsError {
@@ -1,5 +1,4 @@
 
-// TODO: Remove #[allow(dead_code)]
 
 use gsvk::core::GsDevice;
 use gsvk::types::vkuint;
@@ -16,7 +15,6 @@ pub(super) struct GsglTFAssetLib<Asset, AssetData>
         Asset: GsglTFAsset<AssetData> {
 
     phantom_type: PhantomData<AssetData>,
-    #[allow(dead_code)]
     indices: HashMap<ReferenceIndex, StorageIndex>,
     assets: Vec<Asset>,
 }
@@ -38,7 +36,6 @@ impl<Asset, AssetData> GsglTFAssetLib<Asset, AssetData>
     where
         Asset: GsglTFAsset<AssetData> {
 
-    #[allow(dead_code)]
     pub fn load<Document>(&mut self, doc: Document, ref_index: ReferenceIndex) -> StorageIndex
         where
             Asset: From<Document> {
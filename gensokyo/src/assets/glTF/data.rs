@@ -4,7 +4,8 @@ use crate::assets::glTF::asset::{ GsglTFAssetLib, GsglTFPhyLimits };
 use crate::assets::error::GltfError;
 
 use crate::assets::glTF::material::sampler::GsglTFSamplerData;
-use crate::assets::glTF::material::texture::GsglTFTextureData;
+use crate::assets::glTF::material::texture::{ GsglTFTextureData, GsglTFTextureSource };
+use crate::assets::io::TextureUsage;
 
 use crate::assets::glTF::primitive::attributes::{ GsglTFAttributesData, GsglTFAttrFlags };
 use crate::assets::glTF::primitive::transforms::{ GsglTFNodesData, GsglTFNodeUniformFlags };
@@ -97,6 +98,28 @@ impl GsglTFLoadingData {
         Ok(extend_info)
     }
 
+    /// Load the textures referenced by `material`, each texture is tagged as color or data by its usage in material.
+    pub fn extend_textures(&mut self, material: &gltf::Material, source: &IntermediateglTFData) {
+
+        let raw_pbr = material.pbr_metallic_roughness();
+
+        let references = vec![
+            (raw_pbr.base_color_texture().map(|info| info.texture()), TextureUsage::BaseColor),
+            (raw_pbr.metallic_roughness_texture().map(|info| info.texture()), TextureUsage::MetallicRoughness),
+            (material.normal_texture().map(|normal| normal.texture()), TextureUsage::Normal),
+            (material.occlusion_texture().map(|occlusion| occlusion.texture()), TextureUsage::Occlusion),
+            (material.emissive_texture().map(|info| info.texture()), TextureUsage::Emissive),
+        ];
+
+        for (texture, usage) in references.into_iter() {
+            if let Some(texture) = texture {
+                // the texture shared by multiple materials is only loaded once, keeping the usage where it's first referenced.
+                let texture_index = texture.index();
+                let _ = self.textures.load(GsglTFTextureSource { texture, usage, source }, texture_index);
+            }
+        }
+    }
+
     pub fn extend_transforms(&mut self, node: &GsglTFNodeEntity) {
 
         let data_content = self.node_transforms.data_content_mut();
@@ -123,7 +146,6 @@ pub struct GsglTFDataStorage {
     pub(super) indices: GsglTFIndicesData,
     pub(super) node_transforms: GsglTFNodesData,
 
-    #[allow(dead_code)]
     textures : Vec<GsglTFTextureData>,
     #[allow(dead_code)]
     samplers : Vec<GsglTFSamplerData>,
@@ -143,6 +165,11 @@ pub struct GUDADelegate<'d> {
 
 impl<'d, 's: 'd> GsglTFDataStorage {
 
+    /// Take the textures referenced by the materials of model, in the order they are first referenced.
+    pub fn take_textures(&mut self) -> Vec<GsglTFTextureData> {
+        self.textures.drain(..).collect()
+    }
+
     pub fn vertex_allot_delegate(&'s self) -> GVDADelegate<'d> {
        GVDADelegate {
            attributes: &self.attributes,
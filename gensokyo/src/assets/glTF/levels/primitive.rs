@@ -9,7 +9,7 @@ use crate::assets::error::GltfError;
 
 use gsvk::command::{ GsCmdRecorder, GsCmdGraphicsApi };
 use gsvk::utils::phantom::Graphics;
-use gsvk::types::{ vkbytes, vkuint };
+use gsvk::types::{ vkbytes, vkuint, vksint };
 
 // --------------------------------------------------------------------------------------
 /// A wrapper class for primitive level in glTF, containing the render parameters read from glTF file.
@@ -51,7 +51,7 @@ impl<'a> GsglTFLevelEntity<'a> for GsglTFPrimitiveEntity {
 
         // the draw parameters will be set in `Self::read_data` method, so fill 0 here.
         let draw_method = match level.indices() {
-            | Some(_) => DrawMethod::DrawIndex {  index_count: 0,  first_index: 0 },
+            | Some(_) => DrawMethod::DrawIndex {  index_count: 0,  first_index: 0, vertex_offset: 0 },
             | None    => DrawMethod::DrawArray { vertex_count: 0, first_vertex: 0 },
         };
 
@@ -78,9 +78,13 @@ impl<'a> GsglTFLevelEntity<'a> for GsglTFPrimitiveEntity {
         let indices_extend_info = data.extend_indices(&level, source)?;
 
         // load material.
-        let raw_material = GsglTFMaterialData::from(&level.material());
+        let material = level.material();
+        let raw_material = GsglTFMaterialData::from(&material);
         self.material = raw_material.into_data(&source.limits)?;
 
+        // load the textures referenced by material.
+        data.extend_textures(&material, source);
+
         // set the draw parameter.
         self.method = match self.method {
             | DrawMethod::DrawArray { .. } => {
@@ -93,6 +97,8 @@ impl<'a> GsglTFLevelEntity<'a> for GsglTFPrimitiveEntity {
                 DrawMethod::DrawIndex {
                     index_count: indices_extend_info.extend_indices_count,
                     first_index: indices_extend_info.start_index,
+                    // the indices of primitive start from its first vertex.
+                    vertex_offset: vertex_extend_info.start_vertex as vksint,
                 }
             },
         };
@@ -113,8 +119,8 @@ impl GsglTFPrimitiveEntity {
             | DrawMethod::DrawArray { vertex_count, first_vertex } => {
                 recorder.draw(vertex_count, 1, first_vertex, 0);
             },
-            | DrawMethod::DrawIndex { index_count, first_index } => {
-                recorder.draw_indexed(index_count, 1, first_index, 0, 0);
+            | DrawMethod::DrawIndex { index_count, first_index, vertex_offset } => {
+                recorder.draw_indexed(index_count, 1, first_index, vertex_offset, 0);
             },
         }
     }
@@ -125,6 +131,6 @@ impl GsglTFPrimitiveEntity {
 #[derive(Debug, Clone, Eq, PartialEq)]
 enum DrawMethod {
     DrawArray { vertex_count: vkuint, first_vertex: vkuint },
-    DrawIndex {  index_count: vkuint,  first_index: vkuint },
+    DrawIndex {  index_count: vkuint,  first_index: vkuint, vertex_offset: vksint },
 }
 // --------------------------------------------------------------------------------------
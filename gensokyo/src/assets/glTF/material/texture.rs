@@ -1,8 +1,25 @@
 
+use gsvk::image::storage::{ ImageStorageInfo, ImageSource, ImageData };
+use gsvk::image::GsImageFormat;
+use gsvk::types::format::Format;
+use gsvk::types::{ vkbytes, vkDim3D };
+
 use crate::assets::glTF::asset::GsglTFAsset;
+use crate::assets::glTF::data::IntermediateglTFData;
+use crate::assets::io::{ TextureUsage, ImageColorSpace };
 
 pub struct GsglTFTextureData {
 
+    usage: TextureUsage,
+    storage: ImageStorageInfo,
+}
+
+/// The texture referenced by a material, and the usage deciding the color space of its image.
+pub(crate) struct GsglTFTextureSource<'a> {
+
+    pub texture: gltf::Texture<'a>,
+    pub usage: TextureUsage,
+    pub source: &'a IntermediateglTFData,
 }
 
 impl GsglTFAsset<Self> for GsglTFTextureData {
@@ -13,11 +30,65 @@ impl GsglTFAsset<Self> for GsglTFTextureData {
     }
 }
 
-impl From<&gltf::Texture<'_>> for GsglTFTextureData {
+impl<'a> From<GsglTFTextureSource<'a>> for GsglTFTextureData {
 
-    fn from(_raw_texture: &gltf::Texture) -> GsglTFTextureData {
+    fn from(raw_texture: GsglTFTextureSource<'a>) -> GsglTFTextureData {
 
-        unimplemented!()
+        let image = &raw_texture.source.data_image[raw_texture.texture.source().index()];
+
+        // all the images are expanded to 4 channels, since the 1~3 channel formats are rarely supported for sampling.
+        let pixels: Vec<u8> = match image.format {
+            | gltf::image::Format::R8 => {
+                image.pixels.iter()
+                    .flat_map(|&l| vec![l, l, l, 255])
+                    .collect()
+            },
+            | gltf::image::Format::R8G8 => {
+                image.pixels.chunks(2)
+                    .flat_map(|la| vec![la[0], la[0], la[0], la[1]])
+                    .collect()
+            },
+            | gltf::image::Format::R8G8B8 => {
+                image.pixels.chunks(3)
+                    .flat_map(|rgb| vec![rgb[0], rgb[1], rgb[2], 255])
+                    .collect()
+            },
+            | gltf::image::Format::R8G8B8A8 => {
+                image.pixels.clone()
+            },
+        };
+
+        let format = GsImageFormat::Uncompressed(Format::RGBA8_UNORM)
+            .with_srgb(raw_texture.usage.color_space() == ImageColorSpace::Srgb);
+
+        let image_size = pixels.len() as vkbytes;
+
+        let storage = ImageStorageInfo {
+            source: ImageSource::UploadData(ImageData::new(pixels, image_size)),
+            dimension: vkDim3D {
+                width : image.width,
+                height: image.height,
+                depth : 1,
+            },
+            format,
+        };
+
+        GsglTFTextureData {
+            usage: raw_texture.usage,
+            storage,
+        }
+    }
+}
+
+impl GsglTFTextureData {
+
+    /// The usage of this texture in material, which decides whether its format is sRGB or UNORM.
+    pub fn usage(&self) -> TextureUsage {
+        self.usage
+    }
+
+    pub fn into_storage(self) -> ImageStorageInfo {
+        self.storage
     }
 }
 
@@ -2,14 +2,16 @@
 use crate::assets::glTF::data::IntermediateglTFData;
 use crate::assets::error::GltfError;
 
-use gsvk::buffer::instance::{ GsIndexBuffer, IndicesBufferCI };
+use gsvk::buffer::instance::{ GsIndexBuffer, IndicesBufferCI, GsIndexType };
 use gsvk::memory::transfer::GsBufferDataUploader;
 use gsvk::types::vkuint;
 use gsvk::error::VkResult;
 
 pub(crate) struct GsglTFIndicesData {
 
-    start_index: u32,
+    /// the maximum index value of all primitives.
+    max_index: u32,
+    /// the indices of each primitive are relative to its first vertex, which is applied by the vertex offset of draw command.
     data: Vec<vkuint>,
 }
 
@@ -18,26 +20,27 @@ impl GsglTFIndicesData {
     pub fn extend(&mut self, primitive: &gltf::Primitive, source: &IntermediateglTFData) -> Result<usize, GltfError> {
 
         let reader = primitive.reader(|b| Some(&source.data_buffer[b.index()]));
-        let indices_range = get_indices_range(primitive)?;
-        let start_index = self.start_index.clone();
+        let start_count = self.data.len();
 
-        // TODO: Support other integer type.
+        // indices of any integer type are widened to u32 here, and will be narrowed in upload if possible.
         let index_iter = reader.read_indices()
             .ok_or(GltfError::loading("Missing indices property in glTF primitive."))?
-            .into_u32()
-            .map(move |index_element| index_element + start_index);
-        let (extend_count, _) = index_iter.size_hint();
+            .into_u32();
 
-        self.data.extend(index_iter);
-        self.start_index += indices_range as u32;
+        for index_element in index_iter {
+            self.max_index = self.max_index.max(index_element);
+            self.data.push(index_element);
+        }
 
-        Ok(extend_count)
+        Ok(self.data.len() - start_count)
     }
 
     pub fn indices_info(&self) -> Option<IndicesBufferCI> {
 
         if self.is_contain_indices() {
-            Some(GsIndexBuffer::new(self.data.len() as _))
+            let mut indices_ci = GsIndexBuffer::new(self.data.len() as _);
+            indices_ci.set_indices_type(self.indices_type());
+            Some(indices_ci)
         } else {
             None
         }
@@ -53,11 +56,33 @@ impl GsglTFIndicesData {
         self.data.len()
     }
 
+    /// Use 16-bit indices if the indices of every primitive can be addressed by u16, to save index memory.
+    ///
+    /// 8-bit indices are not used, since `VK_EXT_index_type_uint8` may not be enabled.
+    #[inline]
+    fn indices_type(&self) -> GsIndexType {
+        GsIndexType::fit(self.max_index, false)
+    }
+
     pub fn upload(&self, to: &Option<GsIndexBuffer>, by: &mut GsBufferDataUploader) -> VkResult<()> {
 
         if self.is_contain_indices() {
             if let Some(ref index_block) = to {
-                let _ = by.upload(index_block, &self.data)?;
+                match index_block.indices_type() {
+                    | GsIndexType::Uint8 => {
+                        let data: Vec<u8> = self.data.iter()
+                            .map(|&index| index as u8).collect();
+                        let _ = by.upload(index_block, &data)?;
+                    },
+                    | GsIndexType::Uint16 => {
+                        let data: Vec<u16> = self.data.iter()
+                            .map(|&index| index as u16).collect();
+                        let _ = by.upload(index_block, &data)?;
+                    },
+                    | GsIndexType::Uint32 => {
+                        let _ = by.upload(index_block, &self.data)?;
+                    },
+                }
             } else {
                 unreachable!()
             }
@@ -71,30 +96,8 @@ impl Default for GsglTFIndicesData {
 
     fn default() -> GsglTFIndicesData {
         GsglTFIndicesData {
-            start_index: 0,
+            max_index: 0,
             data: Vec::new(),
         }
     }
 }
-
-fn get_indices_range(primitive: &gltf::Primitive) -> Result<u64, GltfError> {
-
-    let indices_accessor = primitive.indices()
-        .ok_or(GltfError::loading("Failed to get indices property of gltf::Primitive"))?;
-
-    // Get the maximum index of this primitive.
-    let index_max = get_index(indices_accessor.max())?;
-    let index_min = get_index(indices_accessor.min())?;
-    let indices_range = index_max - index_min + 1;
-
-    Ok(indices_range)
-}
-
-fn get_index(value: Option<gltf::json::Value>) -> Result<u64, GltfError> {
-
-    value
-        .and_then(|v| v.as_array().cloned())
-        .and_then(|v| v.first().cloned())
-        .and_then(|v| v.as_u64())
-        .ok_or(GltfError::loading(""))
-}
@@ -0,0 +1,51 @@
+
+/// The color space in which the texels of an image are encoded.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ImageColorSpace {
+    /// `Srgb` specifies the texels are gamma-encoded, which is common for the images authored as color.
+    ///
+    /// The image is created with `*_SRGB` format, so the texels are decoded to linear space when sampled,
+    /// and the mip levels are filtered in linear space.
+    Srgb,
+    /// `Linear` specifies the texels are stored as is, which is required for the images storing data rather than color.
+    Linear,
+}
+
+/// The purpose of texture, which determines the color space of its image.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TextureUsage {
+    /// Base color(albedo) map, stored in sRGB color space.
+    BaseColor,
+    /// Emissive map, stored in sRGB color space.
+    Emissive,
+    /// Tangent space normal map, stored in linear color space.
+    Normal,
+    /// Metallic-roughness map, stored in linear color space.
+    MetallicRoughness,
+    /// Ambient occlusion map, stored in linear color space.
+    Occlusion,
+}
+
+impl TextureUsage {
+
+    pub fn color_space(&self) -> ImageColorSpace {
+
+        match self {
+            | TextureUsage::BaseColor
+            | TextureUsage::Emissive => ImageColorSpace::Srgb,
+            | TextureUsage::Normal
+            | TextureUsage::MetallicRoughness
+            | TextureUsage::Occlusion => ImageColorSpace::Linear,
+        }
+    }
+}
+
+/// Decode a sRGB encoded component in [0.0, 1.0] to linear space.
+pub(super) fn srgb_to_linear(component: f32) -> f32 {
+
+    if component <= 0.04045 {
+        component / 12.92
+    } else {
+        ((component + 0.055) / 1.055).powf(2.4)
+    }
+}
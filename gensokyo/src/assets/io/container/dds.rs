@@ -0,0 +1,205 @@
+
+use ash::vk;
+
+use gsvk::image::storage::{ ImageStorageInfo, ImageSource, ImageData, ImageDataRegion };
+use gsvk::image::GsImageFormat;
+use gsvk::types::{ vkuint, vkbytes, vkDim3D };
+
+use crate::assets::io::container::{ ByteReader, max_mip_levels };
+use crate::assets::error::TextureError;
+
+use std::cmp::max;
+
+/// "DDS " in little-endian.
+const DDS_MAGIC: u32 = 0x2053_4444;
+const DDS_HEADER_SIZE: u32 = 124;
+/// The data offset of the file without DX10 header(magic + header).
+const DDS_DATA_OFFSET: usize = 128;
+/// The data offset of the file with DX10 header(magic + header + DX10 header).
+const DDS_DX10_DATA_OFFSET: usize = 148;
+
+const DDPF_RGB: u32 = 0x40;
+const DDSCAPS2_CUBEMAP: u32 = 0x200;
+const DDS_RESOURCE_MISC_TEXTURECUBE: u32 = 0x4;
+
+/// The format of texel data and the size of each texel block.
+struct DdsFormat {
+
+    format: vk::Format,
+    /// the byte count of each block(or each pixel for uncompressed format).
+    block_bytes: vkuint,
+    /// the pixel count in width and height of each block.
+    block_dim: vkuint,
+}
+
+/// Read the DDS file content, which contains all the mip levels, array layers and cubemap faces.
+///
+/// visit https://docs.microsoft.com/en-us/windows/desktop/direct3ddds/dx-graphics-dds-pguide for detail.
+pub fn parse_dds(bytes: &[u8]) -> Result<ImageStorageInfo, TextureError> {
+
+    let reader = ByteReader::new(bytes);
+
+    if reader.u32(0)? != DDS_MAGIC || reader.u32(4)? != DDS_HEADER_SIZE {
+        return Err(TextureError::parsing("Invalid DDS file header."))
+    }
+
+    let height    = reader.u32(12)?;
+    let width     = reader.u32(16)?;
+    let depth     = max(reader.u32(24)?, 1);
+    let levels    = max(reader.u32(28)?, 1);
+    let pf_flags  = reader.u32(80)?;
+    let four_cc   = reader.u32(84)?;
+    let caps2     = reader.u32(112)?;
+
+    if width == 0 || height == 0 {
+        return Err(TextureError::parsing("Invalid dimension of DDS texture."))
+    }
+    if levels > max_mip_levels(width, height, depth) {
+        return Err(TextureError::parsing("The mip level count of DDS texture exceeds its dimension."))
+    }
+
+    let (dds_format, layers, data_offset) = if four_cc == make_four_cc(b"DX10") {
+
+        let dxgi_format = reader.u32(128)?;
+        let misc_flag   = reader.u32(136)?;
+        let array_size  = max(reader.u32(140)?, 1);
+
+        let faces = if misc_flag & DDS_RESOURCE_MISC_TEXTURECUBE != 0 { 6 } else { 1 };
+        let layers = array_size.checked_mul(faces)
+            .ok_or(TextureError::parsing("The array size of DDS texture is too large."))?;
+        (dxgi_format_info(dxgi_format)?, layers, DDS_DX10_DATA_OFFSET)
+    } else {
+
+        let format = if four_cc != 0 {
+            four_cc_format_info(four_cc)?
+        } else if pf_flags & DDPF_RGB != 0 {
+            rgb_format_info(&reader)?
+        } else {
+            return Err(TextureError::parsing("Unsupported DDS pixel format."))
+        };
+
+        let faces = if caps2 & DDSCAPS2_CUBEMAP != 0 { 6 } else { 1 };
+        (format, faces, DDS_DATA_OFFSET)
+    };
+
+    // the layer count is not trusted to reserve the regions, since each region is checked to be inside the file below.
+    let file_data_size = bytes.len().saturating_sub(data_offset) as vkbytes;
+
+    // the data is sorted by array layer(or cubemap face), and then by mip level.
+    let mut regions = Vec::new();
+    let mut offset: vkbytes = 0;
+
+    for layer in 0..layers {
+        for level in 0..levels {
+
+            regions.push(ImageDataRegion {
+                offset,
+                mip_level: level,
+                array_layer: layer,
+            });
+
+            let size = level_size(&dds_format, width >> level, height >> level, depth >> level)?;
+            offset = offset.checked_add(size)
+                .filter(|&data_end| data_end <= file_data_size)
+                .ok_or(TextureError::parsing("The image data of DDS texture is out of file."))?;
+        }
+    }
+
+    let data_end = data_offset + offset as usize;
+    let data = bytes[data_offset..data_end].to_vec();
+
+    let info = ImageStorageInfo {
+        source: ImageSource::UploadData(ImageData::with_regions(data, offset, regions)),
+        dimension: vkDim3D { width, height, depth },
+        format: GsImageFormat::from(dds_format.format),
+    };
+
+    Ok(info)
+}
+
+fn level_size(format: &DdsFormat, width: vkuint, height: vkuint, depth: vkuint) -> Result<vkbytes, TextureError> {
+
+    let block_dim = format.block_dim as vkbytes;
+    let block_count_x = max((width  as vkbytes + block_dim - 1) / block_dim, 1);
+    let block_count_y = max((height as vkbytes + block_dim - 1) / block_dim, 1);
+
+    block_count_x.checked_mul(block_count_y)
+        .and_then(|count| count.checked_mul(max(depth, 1) as vkbytes))
+        .and_then(|count| count.checked_mul(format.block_bytes as vkbytes))
+        .ok_or(TextureError::parsing("The level size of DDS texture is too large."))
+}
+
+fn make_four_cc(code: &[u8; 4]) -> u32 {
+    (code[0] as u32) | ((code[1] as u32) << 8) | ((code[2] as u32) << 16) | ((code[3] as u32) << 24)
+}
+
+fn block_format(format: vk::Format, block_bytes: vkuint) -> DdsFormat {
+    DdsFormat { format, block_bytes, block_dim: 4 }
+}
+
+fn pixel_format(format: vk::Format, pixel_bytes: vkuint) -> DdsFormat {
+    DdsFormat { format, block_bytes: pixel_bytes, block_dim: 1 }
+}
+
+fn four_cc_format_info(four_cc: u32) -> Result<DdsFormat, TextureError> {
+
+    let format = match four_cc {
+        | _ if four_cc == make_four_cc(b"DXT1") => block_format(vk::Format::BC1_RGBA_UNORM_BLOCK, 8),
+        | _ if four_cc == make_four_cc(b"DXT3") => block_format(vk::Format::BC2_UNORM_BLOCK, 16),
+        | _ if four_cc == make_four_cc(b"DXT5") => block_format(vk::Format::BC3_UNORM_BLOCK, 16),
+        | _ if four_cc == make_four_cc(b"ATI1")
+            || four_cc == make_four_cc(b"BC4U") => block_format(vk::Format::BC4_UNORM_BLOCK, 8),
+        | _ if four_cc == make_four_cc(b"BC4S") => block_format(vk::Format::BC4_SNORM_BLOCK, 8),
+        | _ if four_cc == make_four_cc(b"ATI2")
+            || four_cc == make_four_cc(b"BC5U") => block_format(vk::Format::BC5_UNORM_BLOCK, 16),
+        | _ if four_cc == make_four_cc(b"BC5S") => block_format(vk::Format::BC5_SNORM_BLOCK, 16),
+        // D3DFMT_A16B16G16R16F.
+        | 113 => pixel_format(vk::Format::R16G16B16A16_SFLOAT, 8),
+        // D3DFMT_A32B32G32R32F.
+        | 116 => pixel_format(vk::Format::R32G32B32A32_SFLOAT, 16),
+        | _ => return Err(TextureError::parsing("Unsupported DDS FourCC format.")),
+    };
+
+    Ok(format)
+}
+
+fn rgb_format_info(reader: &ByteReader) -> Result<DdsFormat, TextureError> {
+
+    let rgb_bit_count = reader.u32(88)?;
+    let r_mask = reader.u32(92)?;
+
+    match (rgb_bit_count, r_mask) {
+        | (32, 0x0000_00FF) => Ok(pixel_format(vk::Format::R8G8B8A8_UNORM, 4)),
+        | (32, 0x00FF_0000) => Ok(pixel_format(vk::Format::B8G8R8A8_UNORM, 4)),
+        | _ => Err(TextureError::parsing("Unsupported DDS RGB pixel format.")),
+    }
+}
+
+fn dxgi_format_info(dxgi_format: u32) -> Result<DdsFormat, TextureError> {
+
+    let format = match dxgi_format {
+        | 2  => pixel_format(vk::Format::R32G32B32A32_SFLOAT, 16),
+        | 10 => pixel_format(vk::Format::R16G16B16A16_SFLOAT, 8),
+        | 28 => pixel_format(vk::Format::R8G8B8A8_UNORM, 4),
+        | 29 => pixel_format(vk::Format::R8G8B8A8_SRGB, 4),
+        | 87 => pixel_format(vk::Format::B8G8R8A8_UNORM, 4),
+        | 91 => pixel_format(vk::Format::B8G8R8A8_SRGB, 4),
+        | 71 => block_format(vk::Format::BC1_RGBA_UNORM_BLOCK, 8),
+        | 72 => block_format(vk::Format::BC1_RGBA_SRGB_BLOCK, 8),
+        | 74 => block_format(vk::Format::BC2_UNORM_BLOCK, 16),
+        | 75 => block_format(vk::Format::BC2_SRGB_BLOCK, 16),
+        | 77 => block_format(vk::Format::BC3_UNORM_BLOCK, 16),
+        | 78 => block_format(vk::Format::BC3_SRGB_BLOCK, 16),
+        | 80 => block_format(vk::Format::BC4_UNORM_BLOCK, 8),
+        | 81 => block_format(vk::Format::BC4_SNORM_BLOCK, 8),
+        | 83 => block_format(vk::Format::BC5_UNORM_BLOCK, 16),
+        | 84 => block_format(vk::Format::BC5_SNORM_BLOCK, 16),
+        | 95 => block_format(vk::Format::BC6H_UFLOAT_BLOCK, 16),
+        | 96 => block_format(vk::Format::BC6H_SFLOAT_BLOCK, 16),
+        | 98 => block_format(vk::Format::BC7_UNORM_BLOCK, 16),
+        | 99 => block_format(vk::Format::BC7_SRGB_BLOCK, 16),
+        | _ => return Err(TextureError::parsing("Unsupported DXGI format in DDS file.")),
+    };
+
+    Ok(format)
+}
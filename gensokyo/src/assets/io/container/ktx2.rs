@@ -0,0 +1,117 @@
+
+use ash::vk;
+
+use gsvk::image::storage::{ ImageStorageInfo, ImageSource, ImageData, ImageDataRegion };
+use gsvk::image::GsImageFormat;
+use gsvk::types::{ vkbytes, vkDim3D };
+
+use crate::assets::io::container::{ ByteReader, max_mip_levels };
+use crate::assets::error::TextureError;
+
+use std::cmp::max;
+
+const KTX2_IDENTIFIER: [u8; 12] = [0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A];
+/// The size of identifier, header and the index of data format descriptor, key/value data and supercompression global data.
+const KTX2_LEVEL_INDEX_OFFSET: usize = 80;
+/// Each level index contains `byteOffset`, `byteLength` and `uncompressedByteLength`.
+const KTX2_LEVEL_INDEX_SIZE: usize = 24;
+
+/// Read the KTX2 file content, which contains all the mip levels, array layers and cubemap faces.
+///
+/// visit https://github.khronos.org/KTX-Specification/ for detail.
+pub fn parse_ktx2(bytes: &[u8]) -> Result<ImageStorageInfo, TextureError> {
+
+    if bytes.len() < KTX2_LEVEL_INDEX_OFFSET || bytes[0..12] != KTX2_IDENTIFIER {
+        return Err(TextureError::parsing("Invalid KTX2 file identifier."))
+    }
+
+    let reader = ByteReader::new(bytes);
+
+    let vk_format = reader.u32(12)?;
+    let width     = reader.u32(20)?;
+    let height    = reader.u32(24)?;
+    let depth     = reader.u32(28)?;
+    let layer_count = reader.u32(32)?;
+    let face_count  = reader.u32(36)?;
+    let level_count = reader.u32(40)?;
+    let supercompression_scheme = reader.u32(44)?;
+
+    if vk_format == 0 {
+        return Err(TextureError::parsing("KTX2 texture with undefined format(such as Basis Universal) is not supported."))
+    }
+    if supercompression_scheme != 0 {
+        return Err(TextureError::parsing("Supercompressed KTX2 texture is not supported."))
+    }
+
+    if width == 0 {
+        return Err(TextureError::parsing("Invalid dimension of KTX2 texture."))
+    }
+
+    // layer count and level count may be 0 to specify a non-array texture or the mip levels should be generated.
+    let layers = max(layer_count, 1);
+    let faces  = max(face_count, 1);
+    let levels = max(level_count, 1);
+
+    if levels > max_mip_levels(width, max(height, 1), depth) {
+        return Err(TextureError::parsing("The mip level count of KTX2 texture exceeds its dimension."))
+    }
+
+    let images_per_level = layers.checked_mul(faces)
+        .ok_or(TextureError::parsing("The layer count of KTX2 texture is too large."))? as usize;
+
+    // read the position of each level. The levels do not overlap, so their total length is also inside the file.
+    let mut level_ranges = Vec::with_capacity(levels as usize);
+    let mut total_length: u64 = 0;
+    for level in 0..(levels as usize) {
+        let index_offset = KTX2_LEVEL_INDEX_OFFSET + level * KTX2_LEVEL_INDEX_SIZE;
+        let byte_offset = reader.u64(index_offset)?;
+        let byte_length = reader.u64(index_offset + 8)?;
+
+        total_length = total_length.saturating_add(byte_length);
+        let is_in_file = byte_offset.checked_add(byte_length)
+            .map_or(false, |level_end| level_end <= bytes.len() as u64) && total_length <= bytes.len() as u64;
+        if is_in_file == false {
+            return Err(TextureError::parsing("The level data of KTX2 texture is out of file."))
+        }
+
+        // each image of the level takes at least one byte, which also bounds the region count by the file size.
+        if (byte_length as usize) < images_per_level {
+            return Err(TextureError::parsing("The level data of KTX2 texture is smaller than its image count."))
+        }
+        level_ranges.push((byte_offset as usize, byte_length as usize));
+    }
+
+    // only copy the image data, and locate each level relative to the first byte of data.
+    let data_start = level_ranges.iter().map(|&(offset, _)| offset).min().unwrap_or(0);
+    let data_end   = level_ranges.iter().map(|&(offset, length)| offset + length).max().unwrap_or(0);
+
+    // the level data is sorted by array layer, and then by cubemap face.
+    let mut regions = Vec::new();
+
+    for (level, &(byte_offset, byte_length)) in level_ranges.iter().enumerate() {
+
+        let image_size = byte_length / images_per_level;
+
+        for image_index in 0..images_per_level {
+            regions.push(ImageDataRegion {
+                offset: (byte_offset - data_start + image_index * image_size) as vkbytes,
+                mip_level: level as _,
+                array_layer: image_index as _,
+            });
+        }
+    }
+
+    let data = bytes[data_start..data_end].to_vec();
+    let data_size = data.len() as vkbytes;
+
+    let info = ImageStorageInfo {
+        source: ImageSource::UploadData(ImageData::with_regions(data, data_size, regions)),
+        dimension: vkDim3D {
+            width, height,
+            depth: max(depth, 1),
+        },
+        format: GsImageFormat::from(vk::Format::from_raw(vk_format as _)),
+    };
+
+    Ok(info)
+}
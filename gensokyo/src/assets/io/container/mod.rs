@@ -0,0 +1,51 @@
+
+pub(super) use self::ktx2::parse_ktx2;
+pub(super) use self::dds::parse_dds;
+
+mod ktx2;
+mod dds;
+
+use crate::assets::error::TextureError;
+
+use std::cmp::max;
+
+/// Return the max mip level count of texture with this dimension, that is `floor(log2(max_dimension)) + 1`.
+///
+/// The level counts read from file are checked against it, so that the dimension of each level can be calculated by shifting without overflow.
+fn max_mip_levels(width: u32, height: u32, depth: u32) -> u32 {
+
+    let max_dimension = max(max(width, height), max(depth, 1));
+    32 - max_dimension.leading_zeros()
+}
+
+/// A little-endian reader for the binary headers of texture containers.
+struct ByteReader<'a> {
+
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+
+    fn new(bytes: &'a [u8]) -> ByteReader<'a> {
+        ByteReader { bytes }
+    }
+
+    fn u32(&self, offset: usize) -> Result<u32, TextureError> {
+
+        let bytes = self.bytes.get(offset..(offset + 4))
+            .ok_or(TextureError::parsing("Unexpected end of texture file."))?;
+
+        let value = (bytes[0] as u32)
+            | ((bytes[1] as u32) << 8)
+            | ((bytes[2] as u32) << 16)
+            | ((bytes[3] as u32) << 24);
+        Ok(value)
+    }
+
+    fn u64(&self, offset: usize) -> Result<u64, TextureError> {
+
+        let low  = self.u32(offset)? as u64;
+        let high = self.u32(offset + 4)? as u64;
+        Ok(low | (high << 32))
+    }
+}
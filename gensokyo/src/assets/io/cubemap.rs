@@ -0,0 +1,104 @@
+
+use crate::assets::io::hdr::HdrImage;
+
+use std::f32::consts::PI;
+
+/// The count of faces of cubemap, sorted as +X, -X, +Y, -Y, +Z, -Z.
+pub(super) const CUBEMAP_FACE_COUNT: usize = 6;
+
+/// Project the equirectangular panorama to the 6 faces of cubemap on CPU.
+///
+/// The faces are stacked vertically in the returned image, following the +X, -X, +Y, -Y, +Z, -Z order of Vulkan.
+/// The top row of panorama maps to +Y, and the center column of panorama maps to +X.
+pub(super) fn project_equirect(panorama: &HdrImage, face_size: u32) -> HdrImage {
+
+    let face_size = face_size as usize;
+    let mut pixels = Vec::with_capacity(face_size * face_size * CUBEMAP_FACE_COUNT);
+
+    for face in 0..CUBEMAP_FACE_COUNT {
+        for row in 0..face_size {
+            for column in 0..face_size {
+
+                // sample at the center of each texel, map to [-1.0, 1.0].
+                let u = ((column as f32 + 0.5) / face_size as f32) * 2.0 - 1.0;
+                let v = ((row    as f32 + 0.5) / face_size as f32) * 2.0 - 1.0;
+
+                let direction = face_direction(face, u, v);
+                pixels.push(sample_equirect(panorama, direction));
+            }
+        }
+    }
+
+    HdrImage {
+        width : face_size as u32,
+        height: (face_size * CUBEMAP_FACE_COUNT) as u32,
+        pixels,
+    }
+}
+
+/// Return the direction from the center of cube to the texel at (u, v) of the face.
+///
+/// This is the inverse of the cube map face selection table in Vulkan specification.
+fn face_direction(face: usize, u: f32, v: f32) -> [f32; 3] {
+
+    match face {
+        | 0 => [ 1.0,  -v,  -u], // +X
+        | 1 => [-1.0,  -v,   u], // -X
+        | 2 => [   u, 1.0,   v], // +Y
+        | 3 => [   u,-1.0,  -v], // -Y
+        | 4 => [   u,  -v, 1.0], // +Z
+        | 5 => [  -u,  -v,-1.0], // -Z
+        | _ => unreachable!("Cubemap only has 6 faces."),
+    }
+}
+
+fn sample_equirect(panorama: &HdrImage, direction: [f32; 3]) -> [f32; 4] {
+
+    let [x, y, z] = direction;
+    let length = (x * x + y * y + z * z).sqrt();
+
+    // longitude in [-PI, PI] and latitude in [-PI/2, PI/2].
+    let longitude = z.atan2(x);
+    let latitude  = (y / length).asin();
+
+    let s = longitude / (2.0 * PI) + 0.5;
+    let t = 0.5 - latitude / PI;
+
+    bilinear_sample(panorama, s, t)
+}
+
+/// Sample the panorama with wrapping horizontally and clamping vertically.
+fn bilinear_sample(image: &HdrImage, s: f32, t: f32) -> [f32; 4] {
+
+    let width  = image.width  as i64;
+    let height = image.height as i64;
+
+    let x = s * image.width  as f32 - 0.5;
+    let y = t * image.height as f32 - 0.5;
+
+    let x0 = x.floor();
+    let y0 = y.floor();
+    let fx = x - x0;
+    let fy = y - y0;
+
+    let texel = |column: i64, row: i64| -> [f32; 4] {
+        let column = ((column % width) + width) % width;
+        let row = if row < 0 { 0 } else if row >= height { height - 1 } else { row };
+        image.pixels[(row * width + column) as usize]
+    };
+
+    let (x0, y0) = (x0 as i64, y0 as i64);
+    let top_left     = texel(x0,     y0);
+    let top_right    = texel(x0 + 1, y0);
+    let bottom_left  = texel(x0,     y0 + 1);
+    let bottom_right = texel(x0 + 1, y0 + 1);
+
+    let mut result = [0.0; 4];
+    for i in 0..4 {
+        let top    = top_left[i]    * (1.0 - fx) + top_right[i]    * fx;
+        let bottom = bottom_left[i] * (1.0 - fx) + bottom_right[i] * fx;
+        result[i] = top * (1.0 - fy) + bottom * fy;
+    }
+
+    result
+}
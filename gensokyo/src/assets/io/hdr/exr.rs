@@ -0,0 +1,369 @@
+
+use inflate;
+
+use crate::assets::io::hdr::{ HdrImage, f16_to_f32 };
+use crate::assets::error::TextureError;
+
+use std::cmp::min;
+
+const EXR_MAGIC: u32 = 0x0131_2F76;
+/// The flag in version field indicating the file is stored as tiles.
+const EXR_TILED_FLAG: u32 = 0x200;
+/// The flag in version field indicating the file contains deep data.
+const EXR_DEEP_DATA_FLAG: u32 = 0x800;
+/// The flag in version field indicating the file contains multiple parts.
+const EXR_MULTIPART_FLAG: u32 = 0x1000;
+/// The max pixel count of the image, which limits the memory allocated for the decoded pixels(1GB).
+const EXR_MAX_PIXEL_COUNT: i64 = 1 << 26;
+
+const EXR_COMPRESSION_NONE: u8 = 0;
+const EXR_COMPRESSION_RLE : u8 = 1;
+const EXR_COMPRESSION_ZIPS: u8 = 2;
+const EXR_COMPRESSION_ZIP : u8 = 3;
+
+const EXR_PIXEL_UINT : i32 = 0;
+const EXR_PIXEL_HALF : i32 = 1;
+const EXR_PIXEL_FLOAT: i32 = 2;
+
+struct ExrChannel {
+
+    /// the index of RGBA component this channel is written to, or `None` if the channel is ignored.
+    component: Option<usize>,
+    pixel_type: i32,
+}
+
+impl ExrChannel {
+
+    fn byte_size(&self) -> usize {
+        if self.pixel_type == EXR_PIXEL_HALF { 2 } else { 4 }
+    }
+}
+
+/// Read the scanline OpenEXR file.
+///
+/// Only the uncompressed, RLE, ZIPS and ZIP compressed single-part scanline images are supported.
+/// Channels `R`, `G`, `B` and `A` are read, the missing alpha channel is filled with 1.0.
+///
+/// visit https://www.openexr.com/documentation/openexrfilelayout.pdf for detail.
+pub fn parse_exr(bytes: &[u8]) -> Result<HdrImage, TextureError> {
+
+    let mut reader = ExrReader { bytes, position: 0 };
+
+    if reader.u32()? != EXR_MAGIC {
+        return Err(TextureError::parsing("Invalid OpenEXR file magic number."))
+    }
+
+    let version = reader.u32()?;
+    if version & (EXR_TILED_FLAG | EXR_DEEP_DATA_FLAG | EXR_MULTIPART_FLAG) != 0 {
+        return Err(TextureError::parsing("Tiled, deep data or multi-part OpenEXR file is not supported."))
+    }
+
+    let mut channels = None;
+    let mut compression = None;
+    let mut data_window = None;
+
+    // read header attributes until an empty attribute name.
+    loop {
+        let name = reader.string()?;
+        if name.is_empty() {
+            break
+        }
+
+        let _attribute_type = reader.string()?;
+        let attribute_size = reader.size()?;
+        let value = reader.slice(attribute_size)?;
+
+        match name {
+            | b"channels" => {
+                channels = Some(parse_channels(value)?);
+            },
+            | b"compression" => {
+                compression = value.first().cloned();
+            },
+            | b"dataWindow" => {
+                let mut window_reader = ExrReader { bytes: value, position: 0 };
+                data_window = Some([
+                    window_reader.i32()?, window_reader.i32()?, // x_min, y_min
+                    window_reader.i32()?, window_reader.i32()?, // x_max, y_max
+                ]);
+            },
+            | _ => {},
+        }
+    }
+
+    let channels    = channels.ok_or(TextureError::parsing("Missing channels attribute in OpenEXR file."))?;
+    let compression = compression.ok_or(TextureError::parsing("Missing compression attribute in OpenEXR file."))?;
+    let data_window = data_window.ok_or(TextureError::parsing("Missing dataWindow attribute in OpenEXR file."))?;
+
+    // calculate in i64, since the range of data window may exceed i32.
+    let width  = data_window[2] as i64 - data_window[0] as i64 + 1;
+    let height = data_window[3] as i64 - data_window[1] as i64 + 1;
+
+    if width <= 0 || height <= 0 {
+        return Err(TextureError::parsing("Invalid data window of OpenEXR file."))
+    }
+    if width * height > EXR_MAX_PIXEL_COUNT {
+        return Err(TextureError::parsing("The image of OpenEXR file is too large."))
+    }
+
+    let width  = width  as usize;
+    let height = height as usize;
+
+    let lines_per_block = match compression {
+        | EXR_COMPRESSION_NONE
+        | EXR_COMPRESSION_RLE
+        | EXR_COMPRESSION_ZIPS => 1,
+        | EXR_COMPRESSION_ZIP  => 16,
+        | _ => return Err(TextureError::parsing("Unsupported compression method of OpenEXR file.")),
+    };
+
+    let line_size: usize = channels.iter()
+        .map(|channel| channel.byte_size() * width)
+        .sum();
+
+    let mut pixels = vec![[0.0, 0.0, 0.0, 1.0]; width * height];
+
+    let block_count = (height + lines_per_block - 1) / lines_per_block;
+    let mut offsets = Vec::with_capacity(block_count);
+    for _ in 0..block_count {
+        offsets.push(reader.u64()? as usize);
+    }
+
+    for offset in offsets {
+
+        let mut block_reader = ExrReader { bytes, position: offset };
+
+        let block_y = block_reader.i32()? as i64 - data_window[1] as i64;
+        if block_y < 0 || block_y as usize >= height {
+            return Err(TextureError::parsing("Invalid scanline position in OpenEXR file."))
+        }
+        let block_y = block_y as usize;
+
+        let data_size = block_reader.size()?;
+        let data = block_reader.slice(data_size)?;
+
+        let line_count = min(lines_per_block, height - block_y);
+        let expected_size = line_count * line_size;
+
+        // the block is stored uncompressed if the compression does not reduce its size.
+        let block_data = if data_size == expected_size {
+            data.to_vec()
+        } else {
+            match compression {
+                | EXR_COMPRESSION_RLE => reconstruct_bytes(decompress_rle(data)?),
+                | EXR_COMPRESSION_ZIPS
+                | EXR_COMPRESSION_ZIP => {
+                    let decompressed = inflate::inflate_bytes_zlib(data)
+                        .or(Err(TextureError::parsing("Failed to decompress OpenEXR data.")))?;
+                    reconstruct_bytes(decompressed)
+                },
+                | _ => return Err(TextureError::parsing("Unexpected block size of OpenEXR file.")),
+            }
+        };
+
+        if block_data.len() != expected_size {
+            return Err(TextureError::parsing("Unexpected block size of OpenEXR file."))
+        }
+
+        // each scanline stores all the values of the first channel, then the second channel, and so on.
+        let mut position = 0;
+        for line in 0..line_count {
+
+            let row_start = (block_y + line) * width;
+
+            for channel in channels.iter() {
+                for x in 0..width {
+
+                    let value = read_pixel_value(&block_data[position..], channel.pixel_type);
+                    position += channel.byte_size();
+
+                    if let Some(component) = channel.component {
+                        pixels[row_start + x][component] = value;
+                    }
+                }
+            }
+        }
+    }
+
+    let image = HdrImage {
+        width : width as u32,
+        height: height as u32,
+        pixels,
+    };
+
+    Ok(image)
+}
+
+fn parse_channels(value: &[u8]) -> Result<Vec<ExrChannel>, TextureError> {
+
+    let mut reader = ExrReader { bytes: value, position: 0 };
+    let mut channels = Vec::new();
+
+    loop {
+        let name = reader.string()?;
+        if name.is_empty() {
+            break
+        }
+
+        let pixel_type = reader.i32()?;
+        // skip pLinear and the reserved bytes.
+        reader.slice(4)?;
+        let x_sampling = reader.i32()?;
+        let y_sampling = reader.i32()?;
+
+        if pixel_type != EXR_PIXEL_UINT && pixel_type != EXR_PIXEL_HALF && pixel_type != EXR_PIXEL_FLOAT {
+            return Err(TextureError::parsing("Unknown pixel type of OpenEXR channel."))
+        }
+        if x_sampling != 1 || y_sampling != 1 {
+            return Err(TextureError::parsing("Subsampled OpenEXR channel is not supported."))
+        }
+
+        let component = match name {
+            | b"R" => Some(0),
+            | b"G" => Some(1),
+            | b"B" => Some(2),
+            | b"A" => Some(3),
+            | _ => None,
+        };
+
+        channels.push(ExrChannel { component, pixel_type });
+    }
+
+    Ok(channels)
+}
+
+fn read_pixel_value(bytes: &[u8], pixel_type: i32) -> f32 {
+
+    match pixel_type {
+        | EXR_PIXEL_HALF => {
+            let half = (bytes[0] as u16) | ((bytes[1] as u16) << 8);
+            f16_to_f32(half)
+        },
+        | _ => {
+            let bits = (bytes[0] as u32)
+                | ((bytes[1] as u32) << 8)
+                | ((bytes[2] as u32) << 16)
+                | ((bytes[3] as u32) << 24);
+
+            if pixel_type == EXR_PIXEL_FLOAT {
+                f32::from_bits(bits)
+            } else {
+                bits as f32
+            }
+        },
+    }
+}
+
+fn decompress_rle(data: &[u8]) -> Result<Vec<u8>, TextureError> {
+
+    let mut result = Vec::new();
+    let mut position = 0;
+
+    while position < data.len() {
+
+        let count = data[position] as i8;
+        position += 1;
+
+        if count < 0 {
+            // copy the following `-count` bytes.
+            let end = position + (-(count as i32)) as usize;
+            let run = data.get(position..end)
+                .ok_or(TextureError::parsing("Invalid RLE data of OpenEXR file."))?;
+            result.extend_from_slice(run);
+            position = end;
+        } else {
+            // repeat the next byte `count + 1` times.
+            let value = *data.get(position)
+                .ok_or(TextureError::parsing("Invalid RLE data of OpenEXR file."))?;
+            for _ in 0..(count as usize + 1) {
+                result.push(value);
+            }
+            position += 1;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Undo the delta predictor and the byte splitting applied to the data before compression.
+fn reconstruct_bytes(mut data: Vec<u8>) -> Vec<u8> {
+
+    for i in 1..data.len() {
+        data[i] = (data[i - 1] as i32 + data[i] as i32 - 128) as u8;
+    }
+
+    // the first half contains the even bytes and the second half contains the odd bytes.
+    let half = (data.len() + 1) / 2;
+    let mut result = Vec::with_capacity(data.len());
+    for i in 0..half {
+        result.push(data[i]);
+        if half + i < data.len() {
+            result.push(data[half + i]);
+        }
+    }
+
+    result
+}
+
+struct ExrReader<'a> {
+
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ExrReader<'a> {
+
+    fn slice(&mut self, size: usize) -> Result<&'a [u8], TextureError> {
+
+        let end = self.position.checked_add(size)
+            .ok_or(TextureError::parsing("Unexpected end of OpenEXR file."))?;
+        let bytes = self.bytes.get(self.position..end)
+            .ok_or(TextureError::parsing("Unexpected end of OpenEXR file."))?;
+        self.position = end;
+        Ok(bytes)
+    }
+
+    /// Read a size field stored as i32, which must not be negative.
+    fn size(&mut self) -> Result<usize, TextureError> {
+
+        let size = self.i32()?;
+        if size < 0 {
+            return Err(TextureError::parsing("Negative size field in OpenEXR file."))
+        }
+        Ok(size as usize)
+    }
+
+    fn u32(&mut self) -> Result<u32, TextureError> {
+
+        let bytes = self.slice(4)?;
+        let value = (bytes[0] as u32)
+            | ((bytes[1] as u32) << 8)
+            | ((bytes[2] as u32) << 16)
+            | ((bytes[3] as u32) << 24);
+        Ok(value)
+    }
+
+    fn i32(&mut self) -> Result<i32, TextureError> {
+        self.u32().map(|value| value as i32)
+    }
+
+    fn u64(&mut self) -> Result<u64, TextureError> {
+
+        let low  = self.u32()? as u64;
+        let high = self.u32()? as u64;
+        Ok(low | (high << 32))
+    }
+
+    /// Read a null-terminated string, without the terminating byte.
+    fn string(&mut self) -> Result<&'a [u8], TextureError> {
+
+        let remain = self.bytes.get(self.position..)
+            .ok_or(TextureError::parsing("Unexpected end of OpenEXR file."))?;
+        let length = remain.iter().position(|&byte| byte == 0)
+            .ok_or(TextureError::parsing("Unexpected end of OpenEXR file."))?;
+
+        let string = &remain[0..length];
+        self.position += length + 1;
+        Ok(string)
+    }
+}
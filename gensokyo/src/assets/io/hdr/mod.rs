@@ -0,0 +1,172 @@
+
+pub(super) use self::exr::parse_exr;
+
+mod exr;
+
+use gsvk::image::storage::{ ImageStorageInfo, ImageSource, ImageData };
+use gsvk::image::GsImageFormat;
+use gsvk::types::format::Format;
+use gsvk::types::{ vkbytes, vkDim3D };
+
+/// The texel format of the image data loaded from HDR files.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum HdrTexelFormat {
+    /// Convert the texels to half floats on CPU, and use `vk::Format::R16G16B16A16_SFLOAT` as image format.
+    Float16,
+    /// Keep the texels as 32-bit floats, and use `vk::Format::R32G32B32A32_SFLOAT` as image format.
+    Float32,
+}
+
+/// The decoded content of HDR file, each pixel is stored as linear RGBA floats.
+pub(super) struct HdrImage {
+
+    pub width : u32,
+    pub height: u32,
+    pub pixels: Vec<[f32; 4]>,
+}
+
+impl HdrImage {
+
+    pub fn flip_vertical(&mut self) {
+
+        let width  = self.width  as usize;
+        let height = self.height as usize;
+
+        for row in 0..(height / 2) {
+            for column in 0..width {
+                self.pixels.swap(row * width + column, (height - row - 1) * width + column);
+            }
+        }
+    }
+
+    pub fn flip_horizontal(&mut self) {
+
+        let width = self.width as usize;
+
+        for row in self.pixels.chunks_mut(width) {
+            row.reverse();
+        }
+    }
+
+    pub fn into_storage(self, texel_format: HdrTexelFormat) -> ImageStorageInfo {
+
+        let (data, format) = match texel_format {
+            | HdrTexelFormat::Float16 => {
+
+                let mut data = Vec::with_capacity(self.pixels.len() * 4 * 2);
+                for pixel in self.pixels.iter() {
+                    for &component in pixel.iter() {
+                        let half = f32_to_f16(component);
+                        data.push((half & 0xFF) as u8);
+                        data.push((half >> 8) as u8);
+                    }
+                }
+
+                (data, Format::RGBA16_SFLOAT)
+            },
+            | HdrTexelFormat::Float32 => {
+
+                let mut data = Vec::with_capacity(self.pixels.len() * 4 * 4);
+                for pixel in self.pixels.iter() {
+                    for &component in pixel.iter() {
+                        let bits = component.to_bits();
+                        data.push((bits & 0xFF) as u8);
+                        data.push(((bits >> 8)  & 0xFF) as u8);
+                        data.push(((bits >> 16) & 0xFF) as u8);
+                        data.push((bits >> 24) as u8);
+                    }
+                }
+
+                (data, Format::RGBA32_SFLOAT)
+            },
+        };
+
+        let image_size = data.len() as vkbytes;
+
+        ImageStorageInfo {
+            source: ImageSource::UploadData(ImageData::new(data, image_size)),
+            dimension: vkDim3D {
+                width: self.width, height: self.height, depth: 1
+            },
+            format: GsImageFormat::Uncompressed(format),
+        }
+    }
+}
+
+/// Convert a 32-bit float to the bits of half float, rounding to the nearest value.
+///
+/// The values out of the range of half float are clamped to infinity.
+pub(super) fn f32_to_f16(value: f32) -> u16 {
+
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xFF) as i32;
+    let mantissa = bits & 0x007F_FFFF;
+
+    if exponent == 0xFF {
+        // infinity or NaN.
+        let nan_bit = if mantissa != 0 { 0x0200 } else { 0 };
+        return sign | 0x7C00 | nan_bit
+    }
+
+    let half_exponent = exponent - 127 + 15;
+
+    if half_exponent >= 0x1F {
+        // overflow.
+        sign | 0x7C00
+    } else if half_exponent <= 0 {
+        // the value is too small to be represented as half float.
+        if half_exponent < -10 {
+            return sign
+        }
+
+        // subnormal half float.
+        let mantissa = mantissa | 0x0080_0000;
+        let shift = (14 - half_exponent) as u32;
+        let half_mantissa = (mantissa >> shift) as u16;
+
+        if mantissa & (1 << (shift - 1)) != 0 {
+            sign | (half_mantissa + 1)
+        } else {
+            sign | half_mantissa
+        }
+    } else {
+
+        let half = sign | ((half_exponent as u16) << 10) | ((mantissa >> 13) as u16);
+        // the carry of rounding may increase the exponent, which is still the correct result.
+        if mantissa & 0x1000 != 0 {
+            half + 1
+        } else {
+            half
+        }
+    }
+}
+
+/// Convert the bits of half float to 32-bit float.
+pub(super) fn f16_to_f32(half: u16) -> f32 {
+
+    let sign = ((half & 0x8000) as u32) << 16;
+    let exponent = ((half >> 10) & 0x1F) as u32;
+    let mantissa = (half & 0x03FF) as u32;
+
+    let bits = match exponent {
+        | 0 => {
+            if mantissa == 0 {
+                sign
+            } else {
+                // normalize the subnormal half float.
+                let mut exponent = 127 - 15 + 1;
+                let mut mantissa = mantissa;
+                while mantissa & 0x0400 == 0 {
+                    mantissa <<= 1;
+                    exponent -= 1;
+                }
+                sign | (exponent << 23) | ((mantissa & 0x03FF) << 13)
+            }
+        },
+        | 0x1F => sign | 0x7F80_0000 | (mantissa << 13),
+        | _ => sign | ((exponent + 127 - 15) << 23) | (mantissa << 13),
+    };
+
+    f32::from_bits(bits)
+}
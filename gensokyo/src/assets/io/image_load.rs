@@ -4,13 +4,20 @@ use image::GenericImageView;
 
 use gsvk::image::storage::{ ImageStorageInfo, ImageSource, ImageData };
 use gsvk::image::GsImageFormat;
+use gsvk::types::format::Format;
 
 use gsvk::types::{ vkbytes, vkDim3D };
 
-use crate::assets::error::AssetsError;
+use crate::assets::io::container::{ parse_ktx2, parse_dds };
+use crate::assets::io::hdr::{ HdrImage, HdrTexelFormat, parse_exr };
+use crate::assets::io::cubemap::{ project_equirect, CUBEMAP_FACE_COUNT };
+use crate::assets::io::color::{ ImageColorSpace, TextureUsage, srgb_to_linear };
+use crate::assets::error::{ AssetsError, TextureError };
 use crate::error::{ GsResult, GsError };
 
 use std::path::Path;
+use std::io::BufReader;
+use std::fs;
 use std::mem;
 
 pub struct ImageLoader {
@@ -27,6 +34,21 @@ impl From<ImageLoadConfig> for ImageLoader {
 
 impl ImageLoader {
 
+    /// Load texture for `usage`, the format is `RGBA8_SRGB` or `RGBA8_UNORM` depending on the color space of `usage`.
+    ///
+    /// The color space can be overridden by `ImageLoadConfig::color_space`. `force_rgba` of `ImageLoadConfig` should be enabled.
+    pub fn load_texture(&self, path: &Path, usage: TextureUsage) -> GsResult<ImageStorageInfo> {
+
+        let format = GsImageFormat::Uncompressed(Format::RGBA8_UNORM)
+            .with_srgb(self.color_space(usage) == ImageColorSpace::Srgb);
+        self.load_2d(path, format)
+    }
+
+    /// Return the color space of the texture for `usage`, taking the override of `ImageLoadConfig` into account.
+    pub fn color_space(&self, usage: TextureUsage) -> ImageColorSpace {
+        self.config.color_space.unwrap_or(usage.color_space())
+    }
+
     // Load texture with default format.
     pub fn load_2d(&self, path: &Path, format: GsImageFormat) -> GsResult<ImageStorageInfo> {
 
@@ -69,6 +91,214 @@ impl ImageLoader {
         Ok(info)
     }
 
+    /// Load texture from KTX2 file, including all its mip levels, array layers and cubemap faces.
+    ///
+    /// The format of image is read from the file, unless `ImageLoadConfig::color_space` is set. The flip settings of `ImageLoadConfig` are ignored.
+    pub fn load_ktx2(&self, path: &Path) -> GsResult<ImageStorageInfo> {
+
+        let bytes = read_texture_file(path)?;
+        let info = parse_ktx2(&bytes)
+            .map_err(|e| GsError::assets(AssetsError::Texture(e)))?;
+
+        Ok(self.override_color_space(info))
+    }
+
+    /// Load texture from DDS file, including all its mip levels, array layers and cubemap faces.
+    ///
+    /// The format of image is read from the file, unless `ImageLoadConfig::color_space` is set. The flip settings of `ImageLoadConfig` are ignored.
+    pub fn load_dds(&self, path: &Path) -> GsResult<ImageStorageInfo> {
+
+        let bytes = read_texture_file(path)?;
+        let info = parse_dds(&bytes)
+            .map_err(|e| GsError::assets(AssetsError::Texture(e)))?;
+
+        Ok(self.override_color_space(info))
+    }
+
+    /// Load texture from KTX2 or DDS file, the container type is determined by the file content.
+    pub fn load_container(&self, path: &Path) -> GsResult<ImageStorageInfo> {
+
+        let bytes = read_texture_file(path)?;
+
+        let result = if bytes.starts_with(b"DDS ") {
+            parse_dds(&bytes)
+        } else if bytes.starts_with(b"\xABKTX 20\xBB") {
+            parse_ktx2(&bytes)
+        } else {
+            Err(TextureError::parsing("Unknown texture container type."))
+        };
+
+        let info = result.map_err(|e| GsError::assets(AssetsError::Texture(e)))?;
+
+        Ok(self.override_color_space(info))
+    }
+
+    /// Load texture from Radiance HDR(.hdr) or OpenEXR(.exr) file, the file type is determined by the extension.
+    ///
+    /// Files with other extensions are loaded as LDR images and normalized to [0.0, 1.0].
+    ///
+    /// `texel_format` specifies whether to store the texels as half floats or 32-bit floats.
+    /// The alpha channel is filled with 1.0 if the file does not contain it.
+    pub fn load_hdr(&self, path: &Path, texel_format: HdrTexelFormat) -> GsResult<ImageStorageInfo> {
+
+        let mut hdr_image = read_hdr_image(path, self.ldr_color_space())?;
+
+        if self.config.flip_vertical {
+            hdr_image.flip_vertical();
+        }
+        if self.config.flip_horizontal {
+            hdr_image.flip_horizontal();
+        }
+
+        Ok(hdr_image.into_storage(texel_format))
+    }
+
+    /// Load cubemap from 6 image files, which are sorted as +X, -X, +Y, -Y, +Z, -Z.
+    ///
+    /// Each face is loaded by `load_2d`, and all the faces must have the same dimension.
+    pub fn load_cubemap(&self, faces: [&Path; 6], format: GsImageFormat) -> GsResult<ImageStorageInfo> {
+
+        let mut cubemap_data = Vec::new();
+        let mut face_dimension = None;
+
+        for face_path in faces.iter() {
+
+            let face = self.load_2d(face_path, format.clone())?;
+
+            match face_dimension {
+                | None => face_dimension = Some(face.dimension),
+                | Some(dimension) => {
+                    if dimension.width != face.dimension.width || dimension.height != face.dimension.height {
+                        return Err(GsError::assets(AssetsError::Texture(TextureError::parsing("The faces of cubemap must have the same dimension."))))
+                    }
+                },
+            }
+
+            if let ImageSource::UploadData(face_data) = face.source {
+                cubemap_data.extend(face_data.data);
+            }
+        }
+
+        let image_size = cubemap_data.len() as vkbytes;
+
+        let info = ImageStorageInfo {
+            source: ImageSource::UploadData(ImageData::new(cubemap_data, image_size)),
+            // the dimension of each face.
+            dimension: face_dimension.unwrap(),
+            format,
+        };
+
+        Ok(info)
+    }
+
+    /// Load cubemap from an equirectangular panorama, the projection is done on CPU.
+    ///
+    /// The panorama can be Radiance HDR, OpenEXR or any LDR format supported by `load_2d`.
+    /// `face_size` is the width and height of each face, and the faces are sorted as +X, -X, +Y, -Y, +Z, -Z.
+    /// The flip settings of `ImageLoadConfig` are ignored.
+    pub fn load_equirect_cubemap(&self, path: &Path, face_size: u32, texel_format: HdrTexelFormat) -> GsResult<ImageStorageInfo> {
+
+        let panorama = read_hdr_image(path, self.ldr_color_space())?;
+        let faces = project_equirect(&panorama, face_size);
+
+        let mut info = faces.into_storage(texel_format);
+        // the faces are stacked in data, but the dimension is for each face.
+        info.dimension.height /= CUBEMAP_FACE_COUNT as u32;
+
+        Ok(info)
+    }
+
+    /// Convert the format read from texture container to the color space set in `ImageLoadConfig`.
+    fn override_color_space(&self, mut info: ImageStorageInfo) -> ImageStorageInfo {
+
+        if let Some(color_space) = self.config.color_space {
+            info.format = info.format.with_srgb(color_space == ImageColorSpace::Srgb);
+        }
+        info
+    }
+
+    /// The LDR image loaded as float texels is treated as sRGB encoded color by default.
+    fn ldr_color_space(&self) -> ImageColorSpace {
+        self.config.color_space.unwrap_or(ImageColorSpace::Srgb)
+    }
+}
+
+/// Read the image file into float texels in linear color space.
+///
+/// The LDR image is normalized to [0.0, 1.0], and decoded to linear space if its `color_space` is `ImageColorSpace::Srgb`.
+fn read_hdr_image(path: &Path, color_space: ImageColorSpace) -> GsResult<HdrImage> {
+
+    let extension = path.extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_ascii_lowercase())
+        .unwrap_or(String::new());
+
+    match extension.as_str() {
+        | "exr" => {
+            let bytes = read_texture_file(path)?;
+            parse_exr(&bytes)
+                .map_err(|e| GsError::assets(AssetsError::Texture(e)))
+        },
+        | "hdr" => {
+            read_radiance(path)
+        },
+        | _ => {
+            let image_obj = image::open(path)
+                .map_err(|e| GsError::assets(AssetsError::Image(e)))?;
+
+            let width  = image_obj.width();
+            let height = image_obj.height();
+
+            let decode = |component: u8| {
+                let normalized = component as f32 / 255.0;
+                match color_space {
+                    | ImageColorSpace::Srgb   => srgb_to_linear(normalized),
+                    | ImageColorSpace::Linear => normalized,
+                }
+            };
+
+            // alpha channel is always stored linearly.
+            let pixels = image_obj.to_rgba().into_raw()
+                .chunks(4)
+                .map(|rgba| [
+                    decode(rgba[0]), decode(rgba[1]),
+                    decode(rgba[2]), rgba[3] as f32 / 255.0,
+                ])
+                .collect();
+
+            Ok(HdrImage { width, height, pixels })
+        },
+    }
+}
+
+fn read_radiance(path: &Path) -> GsResult<HdrImage> {
+
+    let file = fs::File::open(path)
+        .map_err(|e| GsError::assets(AssetsError::Texture(TextureError::Io(e))))?;
+
+    let decoder = image::hdr::HDRDecoder::new(BufReader::new(file))
+        .map_err(|e| GsError::assets(AssetsError::Image(e)))?;
+    let metadata = decoder.metadata();
+
+    let pixels = decoder.read_image_hdr()
+        .map_err(|e| GsError::assets(AssetsError::Image(e)))?
+        .into_iter()
+        .map(|rgb| [rgb.data[0], rgb.data[1], rgb.data[2], 1.0])
+        .collect();
+
+    let image = HdrImage {
+        width : metadata.width,
+        height: metadata.height,
+        pixels,
+    };
+
+    Ok(image)
+}
+
+fn read_texture_file(path: &Path) -> GsResult<Vec<u8>> {
+
+    fs::read(path)
+        .map_err(|e| GsError::assets(AssetsError::Texture(TextureError::Io(e))))
 }
 
 #[derive(Debug, Clone)]
@@ -82,4 +312,8 @@ pub struct ImageLoadConfig {
     pub byte_per_pixel: u32,
     /// force_rgba define whether to load the image from file with rgba channel.
     pub force_rgba: bool,
+    /// color_space overrides the color space of loaded textures.
+    ///
+    /// If it's `None`, the color space is decided by the usage of texture, or read from the texture container.
+    pub color_space: Option<ImageColorSpace>,
 }
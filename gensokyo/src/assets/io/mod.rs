@@ -1,4 +1,10 @@
 
 pub use self::image_load::{ ImageLoader, ImageLoadConfig };
+pub use self::hdr::HdrTexelFormat;
+pub use self::color::{ ImageColorSpace, TextureUsage };
 
 mod image_load;
+mod container;
+mod hdr;
+mod cubemap;
+mod color;
@@ -3,6 +3,7 @@ pub mod camera;
 pub mod camera_expt;
 
 pub mod io;
+pub mod pipeline;
 pub mod error;
 
 #[allow(non_snake_case)]
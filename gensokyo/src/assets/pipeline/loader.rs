@@ -0,0 +1,287 @@
+
+use toml;
+use ash::vk;
+
+use gsvk::pipeline::graphics::GfxPipelineConfig;
+use gsvk::pipeline::pass::GsRenderPass;
+use gsvk::pipeline::shader::{ GsShaderCI, VertexInputDescription };
+use gsvk::pipeline::target::GsPipelineStage;
+use gsvk::pipeline::state::rasterizer::GsRasterizerState;
+use gsvk::pipeline::state::depth_stencil::GsDepthStencilState;
+use gsvk::pipeline::state::blend::GsBlendState;
+use gsvk::pipeline::state::viewport::{ ViewportStateType, ViewportInfo, ScissorInfo };
+use gsvk::pipeline::state::dynamic::DynamicableValue;
+
+use gsvk::types::vkDim2D;
+
+use crate::assets::pipeline::table::DescTable;
+use crate::assets::pipeline::states;
+use crate::assets::error::{ AssetsError, PipelineError };
+use crate::error::{ GsResult, GsError };
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::fs;
+
+/// Load `GfxPipelineConfig` from pipeline description files, so that the pipeline states can be tweaked without recompiling.
+///
+/// A description file is written in TOML, like the following example:
+///
+/// ```toml
+/// # the name of vertex input registered by `PipelineLoader::register_vertex_input`.
+/// vertex_input = "mesh"
+/// # the index of the subpass where the pipeline is used, which is 0 if missing.
+/// subpass = 0
+/// dynamic_states = ["Viewport", "Scissor"]
+///
+/// # the shader paths are relative to the description file. `.spv` files are loaded as SPIR-V, others are compiled from source.
+/// [[shaders]]
+/// stage = "Vertex"
+/// path  = "mesh.vert.glsl"
+///
+/// [[shaders]]
+/// stage = "Fragment"
+/// path  = "mesh.frag.glsl"
+/// main  = "main"
+///
+/// [input_assembly]
+/// topology = "TriangleList"
+///
+/// [rasterizer]
+/// prefab = "Common"
+/// cull_mode  = "Back"
+/// front_face = "CounterClockwise"
+/// depth_bias = { constant = 1.25, clamp = 0.0, slope = 1.75 }
+///
+/// [multisample]
+/// sample_count = 4
+///
+/// [depth_stencil]
+/// prefab = "EnableDepth"
+/// depth_compare = "LessOrEqual"
+///
+/// [[blend.attachments]]
+/// enable = true
+/// color  = ["Add", "SrcAlpha", "OneMinusSrcAlpha"]
+/// alpha  = ["Add", "One", "Zero"]
+/// ```
+///
+/// The states whose table is missing keep the defaults of `GfxPipelineConfig::new`.
+/// The viewport and scissor are dynamic if `dynamic_states` is missing. Otherwise only the ones listed in `dynamic_states` are dynamic,
+/// and the fixed ones cover the dimension given to `PipelineLoader::new`.
+/// `[tessellation]` with `patch_control_points` is required when tessellation shaders are used.
+pub struct PipelineLoader {
+
+    dimension: vkDim2D,
+    vertex_inputs: HashMap<String, VertexInputDescription>,
+}
+
+impl PipelineLoader {
+
+    /// `dimension` is the size of the fixed viewport and scissor.
+    pub fn new(dimension: vkDim2D) -> PipelineLoader {
+
+        PipelineLoader {
+            dimension,
+            vertex_inputs: HashMap::new(),
+        }
+    }
+
+    /// Register the vertex input description, which is referred by `vertex_input = "name"` in description files.
+    pub fn register_vertex_input(&mut self, name: impl AsRef<str>, input: VertexInputDescription) {
+        self.vertex_inputs.insert(name.as_ref().to_string(), input);
+    }
+
+    /// Parse the pipeline description file at `path` to the config of a graphics pipeline used in `render_pass`.
+    ///
+    /// Descriptor sets and push constants still need to be set on the returned config.
+    pub fn load(&self, path: impl AsRef<Path>, render_pass: GsRenderPass) -> GsResult<GfxPipelineConfig> {
+
+        self.load_description(path.as_ref(), render_pass)
+            .map_err(|e| GsError::assets(AssetsError::Pipeline(e)))
+    }
+
+    fn load_description(&self, path: &Path, render_pass: GsRenderPass) -> Result<GfxPipelineConfig, PipelineError> {
+
+        let content = fs::read_to_string(path)
+            .map_err(PipelineError::Io)?;
+        let value = content.parse::<toml::Value>()
+            .map_err(|error| PipelineError::Toml { path: path.to_path_buf(), error })?;
+        let root_table = value.as_table()
+            .ok_or_else(|| PipelineError::invalid(path, "(root)", "The root of description must be a table."))?;
+
+        let root = DescTable::root(root_table, path);
+        root.check_keys(&["vertex_input", "subpass", "dynamic_states", "shaders", "input_assembly", "rasterizer", "multisample", "depth_stencil", "blend", "tessellation"])?;
+
+        let input_name = root.require_str("vertex_input")?;
+        let input = self.vertex_inputs.get(input_name)
+            .ok_or_else(|| {
+                let mut registered: Vec<&String> = self.vertex_inputs.keys().collect();
+                registered.sort();
+                root.error("vertex_input", format!("Vertex input \"{}\" is not registered. Registered vertex inputs: {:?}.", input_name, registered))
+            })?.clone();
+
+        let shader_dir = path.parent().unwrap_or(Path::new(""));
+        let (shaders, is_tessellated) = parse_shaders(&root, shader_dir)?;
+
+        let mut config = GfxPipelineConfig::new(shaders, input, render_pass, self.dimension);
+
+        if let Some(subpass) = root.integer("subpass")? {
+            if subpass < 0 {
+                return Err(root.error("subpass", format!("Expect a non-negative subpass index, but found {}.", subpass)))
+            }
+            config.reset_subpass(subpass as _);
+        }
+
+        if let Some(table) = root.table("input_assembly")? {
+            config.reset_input_assembly(states::parse_input_assembly(&table)?);
+        }
+        if let Some(table) = root.table("multisample")? {
+            config.reset_multisample(states::parse_multisample(&table)?);
+        }
+
+        match root.table("tessellation")? {
+            | Some(table) => {
+                if is_tessellated == false {
+                    return Err(root.error("tessellation", "Tessellation state is declared, but no tessellation shader is used."))
+                }
+                config.reset_tessellation(states::parse_tessellation(&table)?);
+            },
+            | None => {
+                if is_tessellated {
+                    return Err(root.error("tessellation", "Tessellation shaders require `[tessellation]` table with `patch_control_points`."))
+                }
+            },
+        }
+
+        let mut rasterizer = match root.table("rasterizer")? {
+            | Some(table) => Some(states::parse_rasterizer(&table)?),
+            | None => None,
+        };
+        let mut depth_stencil = match root.table("depth_stencil")? {
+            | Some(table) => Some(states::parse_depth_stencil(&table)?),
+            | None => None,
+        };
+        let mut blend = match root.table("blend")? {
+            | Some(table) => Some(states::parse_blend(&table)?),
+            | None => None,
+        };
+
+        // The dynamic states are declared on the state they belong to, and `GfxPipelineConfig` adds them to the pipeline when the state is reset.
+        let dynamic_states = root.enumerations("dynamic_states", states::DYNAMIC_STATES)?;
+        let mut is_dynamic_viewport = false;
+        let mut is_dynamic_scissor  = false;
+
+        for (i, &dynamic_state) in dynamic_states.iter().enumerate() {
+
+            if dynamic_states[..i].contains(&dynamic_state) {
+                return Err(root.error_at("dynamic_states", i, "Duplicated dynamic state."))
+            }
+
+            match dynamic_state {
+                | vk::DynamicState::VIEWPORT => is_dynamic_viewport = true,
+                | vk::DynamicState::SCISSOR  => is_dynamic_scissor  = true,
+                | vk::DynamicState::LINE_WIDTH => {
+                    rasterizer.get_or_insert_with(GsRasterizerState::default).set_line_width(DynamicableValue::Dynamic);
+                },
+                | vk::DynamicState::DEPTH_BIAS => {
+                    rasterizer.get_or_insert_with(GsRasterizerState::default).set_depth_bias(DynamicableValue::Dynamic);
+                },
+                | vk::DynamicState::BLEND_CONSTANTS => {
+                    blend.get_or_insert_with(GsBlendState::default).set_blend_constants(DynamicableValue::Dynamic);
+                },
+                | vk::DynamicState::DEPTH_BOUNDS => {
+                    depth_stencil.get_or_insert_with(GsDepthStencilState::default).depth.set_depth_bound(DynamicableValue::Dynamic);
+                },
+                | vk::DynamicState::STENCIL_COMPARE_MASK => {
+                    depth_stencil.get_or_insert_with(GsDepthStencilState::default).stencil.set_compare_mask(DynamicableValue::Dynamic);
+                },
+                | vk::DynamicState::STENCIL_WRITE_MASK => {
+                    depth_stencil.get_or_insert_with(GsDepthStencilState::default).stencil.set_write_mask(DynamicableValue::Dynamic);
+                },
+                | vk::DynamicState::STENCIL_REFERENCE => {
+                    depth_stencil.get_or_insert_with(GsDepthStencilState::default).stencil.set_reference(DynamicableValue::Dynamic);
+                },
+                | _ => unreachable!(),
+            }
+        }
+
+        // without `dynamic_states`, the viewport and scissor keep dynamic as the default of `GfxPipelineConfig`.
+        match (is_dynamic_viewport, is_dynamic_scissor) {
+            | (false, false) => {
+                if root.contains("dynamic_states") {
+                    config.reset_fixed_viewport();
+                }
+            },
+            | (true, true) => {
+                config.reset_viewport(ViewportStateType::Dynamic { count: 1 });
+            },
+            | (true, false) => {
+                config.reset_viewport(ViewportStateType::DynamicViewportFixedScissor { scissors: vec![ScissorInfo::from(self.dimension)] });
+            },
+            | (false, true) => {
+                config.reset_viewport(ViewportStateType::FixedViewportDynamicScissor { viewports: vec![ViewportInfo::from(self.dimension)] });
+            },
+        }
+
+        if let Some(state) = rasterizer {
+            config.reset_rasterizer(state);
+        }
+        if let Some(state) = depth_stencil {
+            config.reset_depth_stencil(state);
+        }
+        if let Some(state) = blend {
+            config.reset_blend(state);
+        }
+
+        Ok(config)
+    }
+}
+
+/// Parse the `[[shaders]]` array, and return the shaders with whether any tessellation shader is used.
+fn parse_shaders(root: &DescTable, shader_dir: &Path) -> Result<(Vec<GsShaderCI>, bool), PipelineError> {
+
+    let shader_tables = root.tables("shaders")?;
+    if shader_tables.is_empty() {
+        return Err(root.error("shaders", "At least one `[[shaders]]` entry is required."))
+    }
+
+    let mut shaders = Vec::with_capacity(shader_tables.len());
+    let mut used_stages: Vec<GsPipelineStage> = vec![];
+
+    for table in shader_tables.iter() {
+        table.check_keys(&["stage", "path", "main"])?;
+
+        let stage = table.enumeration("stage", states::SHADER_STAGES)?
+            .ok_or_else(|| table.error("stage", "Missing required string value."))?;
+        if used_stages.contains(&stage) {
+            return Err(table.error("stage", "Each stage can only contain one shader."))
+        }
+        used_stages.push(stage);
+
+        let relative_path = table.require_str("path")?;
+        let shader_path = shader_dir.join(relative_path);
+        if shader_path.is_file() == false {
+            return Err(table.error("path", format!("Shader file {:?} does not exist.", shader_path)))
+        }
+
+        let main_func = table.str("main")?;
+        let is_spirv = shader_path.extension().map_or(false, |ext| ext == "spv");
+
+        let shader = if is_spirv {
+            GsShaderCI::from_spirv(stage, shader_path, main_func)
+        } else {
+            GsShaderCI::from_source(stage, &shader_path, main_func, relative_path)
+        };
+        shaders.push(shader);
+    }
+
+    if used_stages.contains(&GsPipelineStage::VERTEX) == false {
+        return Err(root.error("shaders", "Graphics pipeline requires a shader of \"Vertex\" stage."))
+    }
+
+    let is_tessellated = used_stages.contains(&GsPipelineStage::TESSELLATION_CONTROL)
+        || used_stages.contains(&GsPipelineStage::TESSELLATION_EVALUATION);
+
+    Ok((shaders, is_tessellated))
+}
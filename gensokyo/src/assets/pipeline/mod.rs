@@ -0,0 +1,6 @@
+
+pub use self::loader::PipelineLoader;
+
+mod loader;
+mod table;
+mod states;
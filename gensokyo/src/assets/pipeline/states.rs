@@ -0,0 +1,286 @@
+
+use ash::vk;
+
+use gsvk::pipeline::state::input_assembly::GsInputAssemblyState;
+use gsvk::pipeline::state::rasterizer::{ GsRasterizerState, RasterizerPrefab, DepthBiasInfo };
+use gsvk::pipeline::state::multisample::{ GsMultisampleState, MultisamplePrefab };
+use gsvk::pipeline::state::depth_stencil::{ GsDepthStencilState, GsDepthStencilPrefab, DepthTest };
+use gsvk::pipeline::state::blend::{ GsBlendState, GsBlendPrefab, BlendAttachment };
+use gsvk::pipeline::state::tessellation::GsTessellationState;
+use gsvk::pipeline::state::dynamic::DynamicableValue;
+use gsvk::pipeline::target::GsPipelineStage;
+
+use crate::assets::pipeline::table::DescTable;
+use crate::assets::error::PipelineError;
+
+pub(super) const SHADER_STAGES: &[(&str, GsPipelineStage)] = &[
+    ("Vertex"                , GsPipelineStage::VERTEX),
+    ("TessellationControl"   , GsPipelineStage::TESSELLATION_CONTROL),
+    ("TessellationEvaluation", GsPipelineStage::TESSELLATION_EVALUATION),
+    ("Geometry"              , GsPipelineStage::GEOMETRY),
+    ("Fragment"              , GsPipelineStage::FRAGMENT),
+];
+
+pub(super) const DYNAMIC_STATES: &[(&str, vk::DynamicState)] = &[
+    ("Viewport"          , vk::DynamicState::VIEWPORT),
+    ("Scissor"           , vk::DynamicState::SCISSOR),
+    ("LineWidth"         , vk::DynamicState::LINE_WIDTH),
+    ("DepthBias"         , vk::DynamicState::DEPTH_BIAS),
+    ("BlendConstants"    , vk::DynamicState::BLEND_CONSTANTS),
+    ("DepthBounds"       , vk::DynamicState::DEPTH_BOUNDS),
+    ("StencilCompareMask", vk::DynamicState::STENCIL_COMPARE_MASK),
+    ("StencilWriteMask"  , vk::DynamicState::STENCIL_WRITE_MASK),
+    ("StencilReference"  , vk::DynamicState::STENCIL_REFERENCE),
+];
+
+const TOPOLOGIES: &[(&str, vk::PrimitiveTopology)] = &[
+    ("PointList"    , vk::PrimitiveTopology::POINT_LIST),
+    ("LineList"     , vk::PrimitiveTopology::LINE_LIST),
+    ("LineStrip"    , vk::PrimitiveTopology::LINE_STRIP),
+    ("TriangleList" , vk::PrimitiveTopology::TRIANGLE_LIST),
+    ("TriangleStrip", vk::PrimitiveTopology::TRIANGLE_STRIP),
+    ("TriangleFan"  , vk::PrimitiveTopology::TRIANGLE_FAN),
+    ("PatchList"    , vk::PrimitiveTopology::PATCH_LIST),
+];
+
+const RASTERIZER_PREFABS: &[(&str, RasterizerPrefab)] = &[
+    ("Common", RasterizerPrefab::Common),
+];
+
+const POLYGON_MODES: &[(&str, vk::PolygonMode)] = &[
+    ("Fill" , vk::PolygonMode::FILL),
+    ("Line" , vk::PolygonMode::LINE),
+    ("Point", vk::PolygonMode::POINT),
+];
+
+const CULL_MODES: &[(&str, vk::CullModeFlags)] = &[
+    ("None"        , vk::CullModeFlags::NONE),
+    ("Front"       , vk::CullModeFlags::FRONT),
+    ("Back"        , vk::CullModeFlags::BACK),
+    ("FrontAndBack", vk::CullModeFlags::FRONT_AND_BACK),
+];
+
+const FRONT_FACES: &[(&str, vk::FrontFace)] = &[
+    ("Clockwise"       , vk::FrontFace::CLOCKWISE),
+    ("CounterClockwise", vk::FrontFace::COUNTER_CLOCKWISE),
+];
+
+const DEPTH_STENCIL_PREFABS: &[(&str, GsDepthStencilPrefab)] = &[
+    ("Disable"           , GsDepthStencilPrefab::Disable),
+    ("EnableDepth"       , GsDepthStencilPrefab::EnableDepth),
+    ("EnableStencil"     , GsDepthStencilPrefab::EnableStencil),
+    ("EnableDepthStencil", GsDepthStencilPrefab::EnableDepthStencil),
+];
+
+const COMPARE_OPS: &[(&str, vk::CompareOp)] = &[
+    ("Never"         , vk::CompareOp::NEVER),
+    ("Less"          , vk::CompareOp::LESS),
+    ("Equal"         , vk::CompareOp::EQUAL),
+    ("LessOrEqual"   , vk::CompareOp::LESS_OR_EQUAL),
+    ("Greater"       , vk::CompareOp::GREATER),
+    ("NotEqual"      , vk::CompareOp::NOT_EQUAL),
+    ("GreaterOrEqual", vk::CompareOp::GREATER_OR_EQUAL),
+    ("Always"        , vk::CompareOp::ALWAYS),
+];
+
+const BLEND_PREFABS: &[(&str, GsBlendPrefab)] = &[
+    ("Default", GsBlendPrefab::Default),
+    ("Unset"  , GsBlendPrefab::Unset),
+];
+
+const BLEND_OPS: &[(&str, vk::BlendOp)] = &[
+    ("Add"            , vk::BlendOp::ADD),
+    ("Subtract"       , vk::BlendOp::SUBTRACT),
+    ("ReverseSubtract", vk::BlendOp::REVERSE_SUBTRACT),
+    ("Min"            , vk::BlendOp::MIN),
+    ("Max"            , vk::BlendOp::MAX),
+];
+
+const BLEND_FACTORS: &[(&str, vk::BlendFactor)] = &[
+    ("Zero"                 , vk::BlendFactor::ZERO),
+    ("One"                  , vk::BlendFactor::ONE),
+    ("SrcColor"             , vk::BlendFactor::SRC_COLOR),
+    ("OneMinusSrcColor"     , vk::BlendFactor::ONE_MINUS_SRC_COLOR),
+    ("DstColor"             , vk::BlendFactor::DST_COLOR),
+    ("OneMinusDstColor"     , vk::BlendFactor::ONE_MINUS_DST_COLOR),
+    ("SrcAlpha"             , vk::BlendFactor::SRC_ALPHA),
+    ("OneMinusSrcAlpha"     , vk::BlendFactor::ONE_MINUS_SRC_ALPHA),
+    ("DstAlpha"             , vk::BlendFactor::DST_ALPHA),
+    ("OneMinusDstAlpha"     , vk::BlendFactor::ONE_MINUS_DST_ALPHA),
+    ("ConstantColor"        , vk::BlendFactor::CONSTANT_COLOR),
+    ("OneMinusConstantColor", vk::BlendFactor::ONE_MINUS_CONSTANT_COLOR),
+    ("ConstantAlpha"        , vk::BlendFactor::CONSTANT_ALPHA),
+    ("OneMinusConstantAlpha", vk::BlendFactor::ONE_MINUS_CONSTANT_ALPHA),
+    ("SrcAlphaSaturate"     , vk::BlendFactor::SRC_ALPHA_SATURATE),
+];
+
+pub(super) fn parse_input_assembly(table: &DescTable) -> Result<GsInputAssemblyState, PipelineError> {
+
+    table.check_keys(&["topology", "primitive_restart"])?;
+
+    let topology = table.enumeration("topology", TOPOLOGIES)?
+        .unwrap_or(vk::PrimitiveTopology::TRIANGLE_LIST);
+    let primitive_restart = table.bool("primitive_restart")?.unwrap_or(false);
+
+    Ok(GsInputAssemblyState::setup(topology, primitive_restart))
+}
+
+pub(super) fn parse_rasterizer(table: &DescTable) -> Result<GsRasterizerState, PipelineError> {
+
+    table.check_keys(&["prefab", "polygon_mode", "cull_mode", "front_face", "line_width", "depth_clamp", "depth_bias"])?;
+
+    let prefab = table.enumeration("prefab", RASTERIZER_PREFABS)?
+        .unwrap_or(RasterizerPrefab::Common);
+    let mut state = GsRasterizerState::setup(prefab);
+
+    if let Some(mode) = table.enumeration("polygon_mode", POLYGON_MODES)? {
+        state.set_polygon_mode(mode);
+    }
+    if let Some(mode) = table.enumeration("cull_mode", CULL_MODES)? {
+        state.set_cull_mode(mode);
+    }
+    if let Some(face) = table.enumeration("front_face", FRONT_FACES)? {
+        state.set_front_face(face);
+    }
+    if let Some(width) = table.float("line_width")? {
+        state.set_line_width(DynamicableValue::Fixed { value: width as _ });
+    }
+    if let Some(enable) = table.bool("depth_clamp")? {
+        state.set_depth_clamp_enable(enable);
+    }
+
+    if let Some(bias) = table.table("depth_bias")? {
+        bias.check_keys(&["constant", "clamp", "slope"])?;
+
+        let info = DepthBiasInfo::setup(
+            bias.float("constant")?.unwrap_or(0.0) as _,
+            bias.float("clamp")?.unwrap_or(0.0) as _,
+            bias.float("slope")?.unwrap_or(0.0) as _,
+        );
+        state.set_depth_bias(DynamicableValue::Fixed { value: info });
+    }
+
+    Ok(state)
+}
+
+pub(super) fn parse_multisample(table: &DescTable) -> Result<GsMultisampleState, PipelineError> {
+
+    table.check_keys(&["sample_count"])?;
+
+    let prefab = match table.integer("sample_count")? {
+        | None | Some(1) => MultisamplePrefab::Disable,
+        | Some(2)  => MultisamplePrefab::Multisample(vk::SampleCountFlags::TYPE_2),
+        | Some(4)  => MultisamplePrefab::Multisample(vk::SampleCountFlags::TYPE_4),
+        | Some(8)  => MultisamplePrefab::Multisample(vk::SampleCountFlags::TYPE_8),
+        | Some(16) => MultisamplePrefab::Multisample(vk::SampleCountFlags::TYPE_16),
+        | Some(32) => MultisamplePrefab::Multisample(vk::SampleCountFlags::TYPE_32),
+        | Some(64) => MultisamplePrefab::Multisample(vk::SampleCountFlags::TYPE_64),
+        | Some(count) => {
+            return Err(table.error("sample_count", format!("Sample count must be a power of two between 1 and 64, but found {}.", count)))
+        },
+    };
+
+    Ok(GsMultisampleState::setup(prefab))
+}
+
+pub(super) fn parse_depth_stencil(table: &DescTable) -> Result<GsDepthStencilState, PipelineError> {
+
+    table.check_keys(&["prefab", "depth_write", "depth_compare"])?;
+
+    let prefab = table.enumeration("prefab", DEPTH_STENCIL_PREFABS)?
+        .unwrap_or(GsDepthStencilPrefab::EnableDepth);
+    let mut state = GsDepthStencilState::setup(prefab);
+
+    if table.contains("depth_write") || table.contains("depth_compare") {
+
+        match prefab {
+            | GsDepthStencilPrefab::EnableDepth
+            | GsDepthStencilPrefab::EnableDepthStencil => {},
+            | GsDepthStencilPrefab::Disable
+            | GsDepthStencilPrefab::EnableStencil => {
+                return Err(table.error("prefab", "`depth_write` and `depth_compare` require a prefab with depth test enabled."))
+            },
+        }
+
+        let write_enable = table.bool("depth_write")?.unwrap_or(true);
+        let compare_op = table.enumeration("depth_compare", COMPARE_OPS)?
+            .unwrap_or(vk::CompareOp::LESS);
+        state.set_depth(DepthTest::enable(write_enable, compare_op, false));
+    }
+
+    Ok(state)
+}
+
+pub(super) fn parse_blend(table: &DescTable) -> Result<GsBlendState, PipelineError> {
+
+    table.check_keys(&["prefab", "blend_constants", "attachments"])?;
+
+    let attachments = table.tables("attachments")?;
+
+    // the `Default` prefab already contains one attachment, so start from empty when attachments are listed.
+    let default_prefab = if attachments.is_empty() { GsBlendPrefab::Default } else { GsBlendPrefab::Unset };
+    let prefab = table.enumeration("prefab", BLEND_PREFABS)?
+        .unwrap_or(default_prefab);
+    let mut state = GsBlendState::setup(prefab);
+
+    for attachment_table in attachments.iter() {
+        attachment_table.check_keys(&["enable", "color", "alpha"])?;
+
+        let mut attachment = BlendAttachment::init(attachment_table.bool("enable")?.unwrap_or(false));
+        if let Some((op, src, dst)) = parse_blend_equation(attachment_table, "color")? {
+            attachment.with_color_blend(op, src, dst);
+        }
+        if let Some((op, src, dst)) = parse_blend_equation(attachment_table, "alpha")? {
+            attachment.with_alpha_blend(op, src, dst);
+        }
+
+        state.add_attachment(attachment);
+    }
+
+    if table.contains("blend_constants") {
+        let raw_constants = table.floats("blend_constants")?;
+        if raw_constants.len() != 4 {
+            return Err(table.error("blend_constants", format!("Expect 4 components, but found {}.", raw_constants.len())))
+        }
+
+        let mut constants = [0.0; 4];
+        for (constant, raw) in constants.iter_mut().zip(raw_constants.into_iter()) {
+            *constant = raw as _;
+        }
+        state.set_blend_constants(DynamicableValue::Fixed { value: constants });
+    }
+
+    Ok(state)
+}
+
+/// Parse the blend equation written as `[op, src_factor, dst_factor]`, such as `["Add", "SrcAlpha", "OneMinusSrcAlpha"]`.
+fn parse_blend_equation(table: &DescTable, key: &str) -> Result<Option<(vk::BlendOp, vk::BlendFactor, vk::BlendFactor)>, PipelineError> {
+
+    if table.contains(key) == false {
+        return Ok(None)
+    }
+
+    let raw = table.strs(key)?;
+    if raw.len() != 3 {
+        return Err(table.error(key, format!("Expect [op, src_factor, dst_factor], but found {} element(s).", raw.len())))
+    }
+
+    let op  = table.option_at(key, 0, raw[0], BLEND_OPS)?;
+    let src = table.option_at(key, 1, raw[1], BLEND_FACTORS)?;
+    let dst = table.option_at(key, 2, raw[2], BLEND_FACTORS)?;
+
+    Ok(Some((op, src, dst)))
+}
+
+pub(super) fn parse_tessellation(table: &DescTable) -> Result<GsTessellationState, PipelineError> {
+
+    table.check_keys(&["patch_control_points"])?;
+
+    let points_count = table.integer("patch_control_points")?
+        .ok_or_else(|| table.error("patch_control_points", "Missing required integer value."))?;
+    if points_count <= 0 {
+        return Err(table.error("patch_control_points", format!("Expect a positive number, but found {}.", points_count)))
+    }
+
+    Ok(GsTessellationState::setup(points_count as _))
+}
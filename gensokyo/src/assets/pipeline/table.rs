@@ -0,0 +1,177 @@
+
+use toml;
+
+use crate::assets::error::PipelineError;
+
+use std::path::Path;
+
+/// A view of a table in pipeline description file, which remembers its key path for error reporting.
+pub(super) struct DescTable<'a> {
+
+    table: &'a toml::value::Table,
+    /// the full key path of this table, empty for the root table.
+    path: String,
+    /// the description file this table comes from.
+    file: &'a Path,
+}
+
+impl<'a> DescTable<'a> {
+
+    pub fn root(table: &'a toml::value::Table, file: &'a Path) -> DescTable<'a> {
+        DescTable { table, path: String::new(), file }
+    }
+
+    /// Generate an error at `key` of this table.
+    pub fn error(&self, key: &str, description: impl AsRef<str>) -> PipelineError {
+        PipelineError::invalid(self.file, self.key_path(key), description)
+    }
+
+    /// Reject the keys which are not in `allowed`, so that typos in description file do not pass silently.
+    pub fn check_keys(&self, allowed: &[&str]) -> Result<(), PipelineError> {
+
+        for key in self.table.keys() {
+            if allowed.contains(&key.as_str()) == false {
+                return Err(self.error(key, format!("Unknown key. Expect one of {:?}.", allowed)))
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.table.contains_key(key)
+    }
+
+    pub fn str(&self, key: &str) -> Result<Option<&'a str>, PipelineError> {
+        self.typed(key, "string", toml::Value::as_str)
+    }
+
+    pub fn bool(&self, key: &str) -> Result<Option<bool>, PipelineError> {
+        self.typed(key, "boolean", toml::Value::as_bool)
+    }
+
+    pub fn integer(&self, key: &str) -> Result<Option<i64>, PipelineError> {
+        self.typed(key, "integer", toml::Value::as_integer)
+    }
+
+    /// Integer values are also accepted as float.
+    pub fn float(&self, key: &str) -> Result<Option<f64>, PipelineError> {
+        self.typed(key, "float", |v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+    }
+
+    pub fn require_str(&self, key: &str) -> Result<&'a str, PipelineError> {
+        self.str(key)?
+            .ok_or_else(|| self.error(key, "Missing required string value."))
+    }
+
+    pub fn table(&self, key: &str) -> Result<Option<DescTable<'a>>, PipelineError> {
+
+        let table = self.typed(key, "table", toml::Value::as_table)?;
+        Ok(table.map(|table| self.child(table, self.key_path(key))))
+    }
+
+    /// Return the elements of an array of tables, such as `[[shaders]]`.
+    pub fn tables(&self, key: &str) -> Result<Vec<DescTable<'a>>, PipelineError> {
+
+        let tables = self.elements(key, "table", toml::Value::as_table)?;
+        let children = tables.into_iter().enumerate()
+            .map(|(i, table)| self.child(table, format!("{}[{}]", self.key_path(key), i)))
+            .collect();
+
+        Ok(children)
+    }
+
+    /// Return the elements of an array of strings.
+    pub fn strs(&self, key: &str) -> Result<Vec<&'a str>, PipelineError> {
+        self.elements(key, "string", toml::Value::as_str)
+    }
+
+    /// Return the elements of an array of floats. Integer elements are also accepted.
+    pub fn floats(&self, key: &str) -> Result<Vec<f64>, PipelineError> {
+        self.elements(key, "float", |v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+    }
+
+    /// Read a string value at `key` and map it through `options`.
+    pub fn enumeration<T: Copy>(&self, key: &str, options: &[(&str, T)]) -> Result<Option<T>, PipelineError> {
+
+        match self.str(key)? {
+            | Some(raw) => parse_option(raw, options)
+                .map(Some)
+                .ok_or_else(|| self.error(key, unknown_option(raw, options))),
+            | None => Ok(None),
+        }
+    }
+
+    /// Read an array of string values at `key` and map each of them through `options`.
+    pub fn enumerations<T: Copy>(&self, key: &str, options: &[(&str, T)]) -> Result<Vec<T>, PipelineError> {
+
+        let mut values = vec![];
+        for (i, raw) in self.strs(key)?.into_iter().enumerate() {
+            values.push(self.option_at(key, i, raw, options)?);
+        }
+
+        Ok(values)
+    }
+
+    /// Map `raw`, the element at `index` of the array at `key`, through `options`.
+    pub fn option_at<T: Copy>(&self, key: &str, index: usize, raw: &str, options: &[(&str, T)]) -> Result<T, PipelineError> {
+        parse_option(raw, options)
+            .ok_or_else(|| self.error_at(key, index, unknown_option(raw, options)))
+    }
+
+    /// Generate an error at the element `index` of the array at `key`.
+    pub fn error_at(&self, key: &str, index: usize, description: impl AsRef<str>) -> PipelineError {
+        PipelineError::invalid(self.file, format!("{}[{}]", self.key_path(key), index), description)
+    }
+
+    fn elements<T>(&self, key: &str, expect: &str, cast: impl Fn(&'a toml::Value) -> Option<T>) -> Result<Vec<T>, PipelineError> {
+
+        let array = match self.typed(key, "array", toml::Value::as_array)? {
+            | Some(array) => array,
+            | None => return Ok(vec![]),
+        };
+
+        let mut elements = Vec::with_capacity(array.len());
+        for (i, element) in array.iter().enumerate() {
+            let value = cast(element)
+                .ok_or_else(|| self.error_at(key, i, format!("Expect {}, but found {}.", expect, element.type_str())))?;
+            elements.push(value);
+        }
+
+        Ok(elements)
+    }
+
+    fn typed<T>(&self, key: &str, expect: &str, cast: impl Fn(&'a toml::Value) -> Option<T>) -> Result<Option<T>, PipelineError> {
+
+        match self.table.get(key) {
+            | Some(value) => cast(value)
+                .map(Some)
+                .ok_or_else(|| self.error(key, format!("Expect {}, but found {}.", expect, value.type_str()))),
+            | None => Ok(None),
+        }
+    }
+
+    fn child(&self, table: &'a toml::value::Table, path: String) -> DescTable<'a> {
+        DescTable { table, path, file: self.file }
+    }
+
+    fn key_path(&self, key: &str) -> String {
+        if self.path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", self.path, key)
+        }
+    }
+}
+
+fn parse_option<T: Copy>(raw: &str, options: &[(&str, T)]) -> Option<T> {
+    options.iter()
+        .find(|(name, _)| *name == raw)
+        .map(|(_, value)| *value)
+}
+
+fn unknown_option<T>(raw: &str, options: &[(&str, T)]) -> String {
+
+    let names: Vec<&str> = options.iter().map(|(name, _)| *name).collect();
+    format!("Unknown value \"{}\". Expect one of {:?}.", raw, names)
+}
@@ -1,5 +1,6 @@
 
 use toml;
+use ash::vk_make_version;
 use serde_derive::Deserialize;
 
 use gsvk::core::config::CoreConfig;
@@ -11,7 +12,7 @@ use crate::config::core::device::DeviceConfigMirror;
 use crate::config::core::physical::PhysicalConfigMirror;
 use crate::config::core::swapchain::SwapchainConfigMirror;
 
-use crate::error::GsResult;
+use crate::error::{ GsResult, GsError };
 
 #[derive(Deserialize, Default)]
 pub(crate) struct CoreConfigMirror {
@@ -37,6 +38,11 @@ impl ConfigMirror for CoreConfigMirror {
             swapchain  : self.swapchain.into_config()?,
         };
 
+        // the features of VK_EXT_descriptor_indexing are queried through Vulkan 1.1 api.
+        if config.physical.features.require_descriptor_indexing && config.instance.api_version < vk_make_version!(1, 1, 0) {
+            return Err(GsError::config("core.instance.version.api(VK_EXT_descriptor_indexing requires 1.1 or higher)"))
+        }
+
         Ok(config)
     }
 
@@ -70,6 +70,9 @@ impl ConfigMirror for PhysicalConfigMirror {
             vk_string_to_physical_feature(raw_feature, &mut require_features);
         }
 
+        // the features of descriptor indexing are opt-in by requiring its extension.
+        let require_descriptor_indexing = require_extensions.contains(&DeviceExtensionType::DescriptorIndexing);
+
         let mut require_device_types = Vec::with_capacity(self.devices.len());
         for raw_device_type in self.devices.iter() {
             require_device_types.push(vk_raw2device_type(raw_device_type)?);
@@ -84,7 +87,7 @@ impl ConfigMirror for PhysicalConfigMirror {
         let config = PhysicalConfig {
             extension    : PhysicalExtensionConfig   { require_extensions },
             queue_family : PhysicalQueueFamilyConfig { require_capabilities },
-            features     : PhysicalFeatureConfig     { require_features },
+            features     : PhysicalFeatureConfig     { require_features, require_descriptor_indexing },
             properties   : PhysicalPropertiesConfig  { require_device_types },
             formats      : PhysicalFormatsConfig     { query_formats },
         };
@@ -181,7 +184,11 @@ impl ConfigMirror for PhysicalConfigMirror {
 fn vk_raw2device_extension(raw: &String) -> GsResult<DeviceExtensionType> {
 
     let extension_type = match raw.as_str() {
-        | "VK_KHR_swapchain" => DeviceExtensionType::Swapchain,
+        | "VK_KHR_swapchain"           => DeviceExtensionType::Swapchain,
+        | "VK_EXT_index_type_uint8"    => DeviceExtensionType::IndexTypeUint8,
+        | "VK_KHR_maintenance2"        => DeviceExtensionType::Maintenance2,
+        | "VK_KHR_maintenance3"        => DeviceExtensionType::Maintenance3,
+        | "VK_EXT_descriptor_indexing" => DeviceExtensionType::DescriptorIndexing,
         | _ => return Err(GsError::config(raw)),
     };
 
@@ -101,6 +101,7 @@ pub(super) fn manifest_toml() -> Value {
 //        flip_horizontal = false
 //        force_rgba      = true
 //        byte_per_pixel  = 4
+//        color_space     = "auto"
 //    }
 //}
 
@@ -144,7 +145,7 @@ pub(super) fn manifest_toml() -> Value {
 //    [core.physical]
 //    device_types       = ["CPU", "IntegratedGPU", "DiscreteGPU", "VirtualGPU"]
 //    features           = [] // not cover here.
-//    extensions         = ["VK_KHR_swapchain"]
+//    extensions         = ["VK_KHR_swapchain", "VK_EXT_index_type_uint8", "VK_EXT_descriptor_indexing"] // VK_EXT_descriptor_indexing also enables the bindless texture features, and requires Vulkan 1.1.
 //    queue_capabilities = ["Graphics", "Compute", "Transfer", "SparseBinding", "Protected"]
 //    query_formats      = ["B8G8R8A8_UNORM"]
 //
@@ -189,4 +190,5 @@ pub(super) fn manifest_toml() -> Value {
 //    flip_horizontal = false
 //    force_rgba      = true
 //    byte_per_pixel  = 4
+//    color_space     = "auto"
 //}
@@ -2,7 +2,7 @@
 use toml;
 use serde_derive::Deserialize;
 
-use crate::assets::io::ImageLoadConfig;
+use crate::assets::io::{ ImageLoadConfig, ImageColorSpace };
 use crate::config::engine::ConfigMirror;
 use crate::error::{ GsResult, GsError };
 
@@ -13,6 +13,7 @@ pub(crate) struct ImageLoadConfigMirror {
     flip_horizontal: bool,
     byte_per_pixel : u32,
     force_rgba     : bool,
+    color_space    : String,
 }
 
 impl Default for ImageLoadConfigMirror {
@@ -23,6 +24,7 @@ impl Default for ImageLoadConfigMirror {
             flip_horizontal: false,
             force_rgba     : true,
             byte_per_pixel : 4,
+            color_space    : String::from("auto"),
         }
     }
 }
@@ -32,11 +34,19 @@ impl ConfigMirror for ImageLoadConfigMirror {
 
     fn into_config(self) -> GsResult<Self::ConfigType> {
 
+        let color_space = match self.color_space.as_str() {
+            | "auto"   => None,
+            | "srgb"   => Some(ImageColorSpace::Srgb),
+            | "linear" => Some(ImageColorSpace::Linear),
+            | _ => return Err(GsError::config("resources.image_load.color_space")),
+        };
+
         let config = ImageLoadConfig {
             flip_vertical  : self.flip_vertical,
             flip_horizontal: self.flip_horizontal,
             byte_per_pixel : self.byte_per_pixel,
             force_rgba     : self.force_rgba,
+            color_space,
         };
 
         Ok(config)
@@ -64,6 +74,11 @@ impl ConfigMirror for ImageLoadConfigMirror {
                 .ok_or(GsError::config("resources.image_load.force_rgba"))?;
         }
 
+        if let Some(v) = toml.get("color_space") {
+            self.color_space = v.as_str()
+                .ok_or(GsError::config("resources.image_load.color_space"))?.to_owned();
+        }
+
         Ok(())
     }
 }
@@ -9,6 +9,7 @@ use gsvk::descriptor::allocator::GsDescriptorAllocator;
 
 use crate::assets::glTF::importer::GsglTFImporter;
 use crate::assets::io::ImageLoader;
+use crate::assets::pipeline::PipelineLoader;
 
 use crate::initialize::initializer::AssetInitializer;
 use crate::initialize::traits::{ FromInitializer, FromInitializerP1 };
@@ -45,6 +46,13 @@ impl FromInitializer for ImageLoader {
     }
 }
 
+impl FromInitializer for PipelineLoader {
+
+    fn new(initializer: &AssetInitializer) -> PipelineLoader {
+        PipelineLoader::new(initializer.screen_dimension())
+    }
+}
+
 impl FromInitializer for GsglTFImporter {
 
     fn new(initializer: &AssetInitializer) -> GsglTFImporter {
@@ -2,7 +2,7 @@
 use crate::error::GsResult;
 
 use gsvk::pipeline::graphics::GfxPipelineConfig;
-use gsvk::pipeline::graphics::{ GfxPipelineBuilder, GfxMultiPipelineBuilder, GfxPipelineSetBuilder };
+use gsvk::pipeline::graphics::{ GfxPipelineBuilder, GfxMultiPipelineBuilder, GfxPipelineSetBuilder, PipelineLibrary };
 use gsvk::pipeline::pass::{ GsRenderPass, RenderPassBuilder, RenderAttachmentCI, Present };
 
 use crate::initialize::initializer::AssetInitializer;
@@ -29,6 +29,13 @@ impl TryFromInitializerP1<GfxPipelineConfig> for GfxPipelineSetBuilder {
     }
 }
 
+impl TryFromInitializerP1<GsRenderPass> for PipelineLibrary {
+
+    fn new(initializer: &AssetInitializer, render_pass: GsRenderPass) -> GsResult<PipelineLibrary> {
+        Ok(PipelineLibrary::create(&initializer.device, render_pass)?)
+    }
+}
+
 impl FromInitializer for RenderAttachmentCI<Present> {
 
     fn new(initializer: &AssetInitializer) -> RenderAttachmentCI<Present> {
@@ -9,7 +9,8 @@ pub use crate::initialize::traits::{ FromInitializerP2, TryFromInitializerP2 };
 
 pub use crate::input::{ ActionNerve, SceneAction, GsKeycode };
 
-pub use crate::assets::io::ImageLoader;
+pub use crate::assets::io::{ ImageLoader, HdrTexelFormat, ImageColorSpace, TextureUsage };
+pub use crate::assets::pipeline::PipelineLoader;
 
 pub use crate::assets::camera::{ GsCameraFactory, GsCameraAbstract };
 pub use crate::assets::camera::{ GsStageCamera, GsFlightCamera };
@@ -20,5 +21,5 @@ pub use crate::assets::glTF::importer::GsglTFImporter;
 pub use crate::assets::glTF::model::{ GsglTFEntity, GsglTFRenderParams };
 
 
-pub use crate::assets::error::{ AssetsError, GltfError };
+pub use crate::assets::error::{ AssetsError, GltfError, TextureError, PipelineError };
 pub use crate::error::{ GsResult, GsError };
@@ -50,10 +50,12 @@ impl ChainResource {
         AssetInitializer::create(&vulkan.device, &self.swapchain, config)
     }
 
-    pub fn acquire_next_image(&self) -> GsResult<AcquireImageInfo> {
+    pub fn acquire_next_image(&self, device: &GsDevice) -> GsResult<AcquireImageInfo> {
 
         let fence_to_wait = &self.sync_fences[self.current_frame];
         fence_to_wait.wait(TimePeriod::Infinite.vulkan_time())?;
+        // the frame using this fence has finished, so release the resources no longer used by any frame in flight.
+        device.logic.frame_complete(self.frame_in_flights);
 
         let image_to_acquire = &self.image_awaits[self.current_frame];
 
@@ -91,17 +93,19 @@ impl ChainResource {
     pub fn reload(&mut self, vulkan: &VulkanContext, config: &SwapchainConfig) -> GsResult<()> {
 
         let new_chain = vulkan.new_chain(config, Some(&self.swapchain), &self.window)?;
-        self.discard(&vulkan.device);
+        self.discard();
 
         self.swapchain = new_chain;
+        // rebuild the framebuffers, depth attachments and fixed-viewport pipelines made for the old swapchain.
+        self.swapchain.resize_dependents(&vulkan.device)?;
         self.recreate_syncs(&vulkan.device)?;
 
         Ok(())
     }
 
-    pub fn discard(&self, device: &GsDevice) {
+    pub fn discard(&self) {
 
-        self.swapchain.discard(device);
+        self.swapchain.discard();
         // image_awaits and sync_fences will be drop in its drop func,
     }
 
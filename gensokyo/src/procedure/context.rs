@@ -16,7 +16,7 @@ use gsvk::core::physical::PhysicalInspector;
 use gsvk::core::swapchain::{ GsChain, GsSwapchain, SwapchainConfig };
 use gsvk::types::vkDim2D;
 
-use std::rc::Rc;
+use std::sync::Arc;
 use std::path::PathBuf;
 
 pub struct ProgramContext {
@@ -110,7 +110,7 @@ impl VulkanContext {
 
         let env = VulkanContext {
             instance, debugger, surface,
-            device: Rc::new(virtual_device),
+            device: Arc::new(virtual_device),
         };
         Ok(env)
     }
@@ -123,7 +123,7 @@ impl VulkanContext {
 
         let chain = GsSwapchain::new(&self.device, config, &self.surface)?
             .build(&self.instance, old_chain, &window_dimension)?;
-        Ok(Rc::new(chain))
+        Ok(Arc::new(chain))
     }
 
     /// use destroy function, so that the order of deinitialization can be customizable.
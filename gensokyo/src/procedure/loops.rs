@@ -39,11 +39,15 @@ impl<Routine> RoutineFlow<Routine>
         self.main_loop(window_context, &vulkan_context, config)?;
 
         self.routine.closure(device)?;
-        self.wait_device_idle(device)?;
-        self.chain.discard(device);
 
-        // free the program specific resource.
-        drop(self);
+        let RoutineFlow { chain, routine } = self;
+        // free the program specific resource. Their handles are pushed to the deferred destruction queue of device.
+        drop(routine);
+        // wait until device finishes all the works, and destroy the pending handles.
+        device.logic.wait_idle()?;
+
+        chain.discard();
+        drop(chain);
         // and then free vulkan environment resource.
         vulkan_context.discard();
 
@@ -107,7 +111,7 @@ impl<Routine> RoutineFlow<Routine>
 
     fn draw_frame(&mut self, device: &GsDevice, delta_time: f32) -> GsResult<()> {
 
-        let acquire_result = self.chain.acquire_next_image()?;
+        let acquire_result = self.chain.acquire_next_image(device)?;
 
         let image_ready_to_present = self.routine.draw(&device,
             acquire_result.device_ready, acquire_result.image_acquire_finished,
@@ -27,6 +27,10 @@ pub trait GraphicsRoutine {
         Ok(())
     }
 
+    /// Called after the swapchain is recreated, such as the window being resized.
+    ///
+    /// The framebuffers and depth attachments of render passes built for swapchain, and the pipelines with fixed viewport in them, have been rebuilt before this call.
+    /// So usually only the commands recorded with the old framebuffers need to be recorded again here.
     fn reload_res(&mut self, initializer: AssetInitializer) -> GsResult<()>;
 
     // input